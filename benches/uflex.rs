@@ -0,0 +1,75 @@
+//! Throughput of decoding and encoding FCB3 (U_FLEX version 3) payloads.
+//!
+//! Run using `cargo bench --bench uflex`.
+//!
+//! For reference, the figures measured when the codec moved from one `bool` per bit (`&[bool]` and
+//! `Vec<bool>`) to packed bits (`BitSlice` and `BitVec`):
+//!
+//! | benchmark                        | `bool` per bit | packed bits |
+//! |----------------------------------|----------------|-------------|
+//! | decode/open_ticket               | 4.34 µs        | 2.00 µs     |
+//! | decode/open_ticket_16_documents  | 29.85 µs       | 14.84 µs    |
+//! | encode/open_ticket               | 3.38 µs        | 0.96 µs     |
+//! | encode/open_ticket_16_documents  | 29.62 µs       | 6.86 µs     |
+
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use uic_ticket_decode::asn1_uper::{BitSlice, BitVec};
+use uic_ticket_decode::uflex_3::UicRailTicketData;
+
+
+/// The U_FLEX payload of a specimen open ticket with one traveler and one transport document.
+const OPEN_TICKET: &[u8] = include_bytes!("../tests/data/open_ticket.uper");
+
+/// The number of transport documents in the larger payload.
+const DOCUMENT_COUNT: usize = 16;
+
+
+fn decode(payload: &[u8]) -> UicRailTicketData {
+    let (_rest, data) = UicRailTicketData::try_from_uper(BitSlice::new(payload))
+        .expect("failed to decode payload");
+    data
+}
+
+fn encode(data: &UicRailTicketData) -> Vec<u8> {
+    let mut bits = BitVec::new();
+    data.write_uper(&mut bits)
+        .expect("failed to encode payload");
+    bits.into_bytes()
+}
+
+/// Returns the payloads to benchmark: the open ticket and a copy of it with the transport document
+/// repeated, which is closer in size to the payloads of multi-leg tickets.
+fn payloads() -> Vec<(&'static str, Vec<u8>)> {
+    let mut data = decode(OPEN_TICKET);
+    let document = data.transport_document[0].clone();
+    data.transport_document = vec![document; DOCUMENT_COUNT];
+    vec![
+        ("open_ticket", OPEN_TICKET.to_vec()),
+        ("open_ticket_16_documents", encode(&data)),
+    ]
+}
+
+
+fn bench_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    for (name, payload) in payloads() {
+        group.throughput(Throughput::Bytes(payload.len() as u64));
+        group.bench_function(name, |b| b.iter(|| decode(black_box(&payload))));
+    }
+    group.finish();
+}
+
+fn bench_encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+    for (name, payload) in payloads() {
+        let data = decode(&payload);
+        group.throughput(Throughput::Bytes(payload.len() as u64));
+        group.bench_function(name, |b| b.iter(|| encode(black_box(&data))));
+    }
+    group.finish();
+}
+
+
+criterion_group!(benches, bench_decode, bench_encode);
+criterion_main!(benches);
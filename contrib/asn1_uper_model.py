@@ -1,6 +1,6 @@
 import argparse
 import jinja2
-from typing import Any, Optional, Union
+from typing import Any, Optional
 import asn1tools
 import regex
 
@@ -16,100 +16,25 @@ PASCAL_DIGITS_RE = regex.compile("^([\\p{N}]+)")
 
 
 TEMPLATE = """
+{%- macro type_name_attr(type_name) -%}
+{%- if type_name|pascal|rust_identifier != type_name %}
+#[uper(name = {{ type_name|rust_string }})]
+{%- endif %}
+{%- endmacro -%}
+
 {%- macro rustify_sequence(type_name, type_def) -%}
-#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+{{- type_name_attr(type_name) }}
 pub struct {{ type_name|pascal|rust_identifier }} {
-    {%- for member in type_def.members if member is not none %}
-    {% if member.type == "SEQUENCE OF" %}#[serde(default)] {% endif %}pub {{ member.name|snake|rust_identifier }}: {{ member|rust_type(type_name) }},
+    {%- for member in type_def|root_members %}
+    {{ member|uper_member_attr(type_def.members) }}{% if member.type == "SEQUENCE OF" %}#[serde(default)] {% endif %}pub {{ member.name|snake|rust_identifier }}: {{ member|rust_type(type_name) }},
     {%- endfor %}
-}
-impl {{ type_name|pascal|rust_identifier }} {
-    pub fn try_from_uper<'a>(rest: &'a [bool]) -> Result<(&'a [bool], Self), nom::Err<crate::asn1_uper::DecodingError<'a>>> {
-        {%- set ns = namespace(optional_index=0) %}
-        {%- if type_def|sequence_is_extensible %}
-        let (rest, is_extended) = crate::asn1_uper::decode_bool(rest)?;
-        if is_extended {
-            panic!("cannot currently handle extensibility");
-        }
-        {%- endif %}
-        {%- if type_def.members|count_optional > 0 %}
-        let (rest, optional_bits) = crate::asn1_uper::decode_bools(rest, {{ type_def.members|count_optional }})?;
-        {%- endif %}
-        {%- for member in type_def.members if member is not none %}
-            {%- if member.get("optional", false) %}
-                {%- if member.type == "SEQUENCE OF" %}
-                    {#- do an empty sequence instead #}
-        let (rest, {{ member.name|snake|rust_identifier }}) = if optional_bits[{{ ns.optional_index }}] {
-            {{ member|rust_deserialize_call(type_name) }}
-        } else {
-            (rest, Vec::new())
-        };
-                {%- else %}
-        let (rest, {{ member.name|snake|rust_identifier }}) = if optional_bits[{{ ns.optional_index }}] {
-            let (rest, value) = {{ member|rust_deserialize_call(type_name) }};
-            (rest, Some(value))
-        } else {
-            (rest, None)
-        };
-                {%- endif %}
-                {%- set ns.optional_index = ns.optional_index + 1 %}
-            {%- elif "default" in member %}
-        let (rest, {{ member.name|snake|rust_identifier }}) = if optional_bits[{{ ns.optional_index }}] {
-            {{ member|rust_deserialize_call(type_name) }}
-        } else {
-            let default_value = {{ member|rust_default_value }};
-            (rest, default_value)
-        };
-                {%- set ns.optional_index = ns.optional_index + 1 %}
-            {%- else %}
-        let (rest, {{ member.name|snake|rust_identifier }}) = {{ member|rust_deserialize_call(type_name) }};
-            {%- endif %}
-        {%- endfor %}
-        let sequence = Self {
-            {%- for member in type_def.members if member is not none %}
-            {{ member.name|snake|rust_identifier }},
-            {%- endfor %}
-        };
-        Ok((rest, sequence))
-    }
-
-    pub fn write_uper(&self, uper_buf: &mut Vec<bool>) -> Result<(), crate::asn1_uper::EncodingError> {
-        {%- if type_def|sequence_is_extensible %}
-        crate::asn1_uper::encode_bool(uper_buf, false);
-        {%- endif %}
-        {%- for member in type_def.members if member is not none %}
-            {%- if member.get("optional", false) %}
-                {%- if member.type == "SEQUENCE OF" %}
-        crate::asn1_uper::encode_bool(uper_buf, self.{{ member.name|snake|rust_identifier }}.len() > 0);
-                {%- else %}
-        crate::asn1_uper::encode_bool(uper_buf, self.{{ member.name|snake|rust_identifier }}.is_some());
-                {%- endif %}
-            {%- elif "default" in member %}
-        crate::asn1_uper::encode_bool(uper_buf, self.{{ member.name|snake|rust_identifier }} != {{ member|rust_default_value }});
-            {%- endif %}
-        {%- endfor %}
-        {%- for member in type_def.members if member is not none %}
-            {%- if member.get("optional", false) %}
-                {%- if member.type == "SEQUENCE OF" %}
-        if self.{{ member.name|snake|rust_identifier }}.len() > 0 {
-            {{ member|rust_serialize_call(type_name) }};
-        }
-                {%- else %}
-        if let Some(opt_val) = &self.{{ member.name|snake|rust_identifier }} {
-            {%- set opt_member = member|dict_replacing(name="opt_val") %}
-            {{ opt_member|rust_serialize_call(type_name, self_prefix=None, deref=True) }};
-        }
-                {%- endif %}
-            {%- elif "default" in member %}
-        if self.{{ member.name|snake|rust_identifier }} != {{ member|rust_default_value }} {
-            {{ member|rust_serialize_call(type_name) }};
-        }
-            {%- else %}
-        {{ member|rust_serialize_call(type_name) }};
-            {%- endif %}
-        {%- endfor %}
-        Ok(())
-    }
+    {%- for member in type_def|extension_additions %}
+    {{ member|uper_member_attr(type_def.members, extension=True) }}#[serde(default)] pub {{ member.name|snake|rust_identifier }}: {{ member|rust_type(type_name) }},
+    {%- endfor %}
+    {%- if type_def|sequence_is_extensible %}
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+    {%- endif %}
 }
 {#- and now, the inline choice definitions #}
 {%- for member in type_def.members if member is not none and member.type == "CHOICE" %}
@@ -118,114 +43,41 @@ impl {{ type_name|pascal|rust_identifier }} {
 {%- endmacro -%}
 
 {%- macro rustify_enumerated(type_name, type_def) -%}
-#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+{{- type_name_attr(type_name) }}
 #[repr(u8)]
 pub enum {{ type_name|pascal|rust_identifier }} {
-    {%- for kvp in type_def["values"] if kvp is not none %}
+    {%- for kvp in type_def["values"]|root_alternatives %}
     {{ kvp[0]|pascal|rust_identifier }} = {{ kvp[1] }},
     {%- endfor %}
-}
-impl {{ type_name|pascal|rust_identifier }} {
-    pub fn try_from_uper<'a>(rest: &'a [bool]) -> Result<(&'a [bool], Self), nom::Err<crate::asn1_uper::DecodingError<'a>>> {
-        {%- if type_def|enum_is_extensible %}
-        let (rest, is_extended) = crate::asn1_uper::decode_bool(rest)?;
-        if is_extended {
-            panic!("cannot currently handle extensibility");
-        }
-        {%- endif %}
-        {%- if type_def|enum_base_option_count > 1 %}
-        let (rest, value_index) = crate::asn1_uper::decode_integer(rest, &crate::asn1_uper::WholeNumberConstraint::Constrained { min: crate::asn1_uper::Integer::from_short(0), max: crate::asn1_uper::Integer::from_short({{ type_def|enum_base_option_count - 1 }}) })?;
-        let enum_value = match value_index.try_to_usize().expect("failed to decode enumerated value to usize") {
-            {#- the data is encoded as the index, not as the value! #}
-            {%- for kvp in type_def["values"] if kvp is not none %}
-            {{ loop.index0 }} => Self::{{ kvp[0]|pascal|rust_identifier }},
-            {%- endfor %}
-            other => panic!("unexpected {{ type_name|pascal|rust_identifier }} value {}", other),
-        };
-        Ok((rest, enum_value))
-        {%- else %}
-            {#- an enumeration with a single option is never explicitly encoded #}
-            {%- for kvp in type_def["values"] if kvp is not none %}
-        Ok((rest, Self::{{ kvp[0]|pascal|rust_identifier }}))
-            {%- endfor %}
-        {%- endif %}
-    }
-
-    pub fn write_uper(&self, uper_buf: &mut Vec<bool>) -> Result<(), crate::asn1_uper::EncodingError> {
-        {%- if type_def|enum_is_extensible %}
-        crate::asn1_uper::encode_bool(uper_buf, false);
-        {%- endif %}
-        {#- an enumeration with a single option is never explicitly encoded #}
-        {%- if type_def|enum_base_option_count > 1 %}
-        let integer_value = match self {
-            {#- the data is encoded as the index, not as the value! #}
-            {%- for kvp in type_def["values"] if kvp is not none %}
-            Self::{{ kvp[0]|pascal|rust_identifier }} => crate::asn1_uper::Integer::from_short({{ loop.index0 }}),
-            {%- endfor %}
-        };
-        crate::asn1_uper::encode_integer(uper_buf, &crate::asn1_uper::WholeNumberConstraint::Constrained { min: crate::asn1_uper::Integer::from_short(0), max: crate::asn1_uper::Integer::from_short({{ type_def|enum_base_option_count - 1 }}) }, &integer_value)?;
-        {%- endif %}
-        Ok(())
-    }
+    {%- for kvp in type_def["values"]|extension_alternatives %}
+    #[uper(extension)] {{ kvp[0]|pascal|rust_identifier }} = {{ kvp[1] }},
+    {%- endfor %}
+    {%- if type_def|enum_is_extensible %}
+    /// A value added by an extension that is unknown to this version of the schema, along with
+    /// its extension index.
+    #[uper(unknown)]
+    Unknown(usize),
+    {%- endif %}
 }
 {%- endmacro -%}
 
 {%- macro rustify_choice(type_name, type_def) -%}
-#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+{{- type_name_attr(type_name) }}
 pub enum {{ type_name|pascal|rust_identifier }} {
-    {%- for member in type_def.members if member is not none %}
-    {{ member.name|pascal|rust_identifier }}({{ member.type|pascal|rust_identifier }}),
+    {%- for member in type_def.members|root_alternatives %}
+    {{ member|uper_alternative_attr }}{{ member.name|pascal|rust_identifier }}({{ member|rust_type(type_name) }}),
     {%- endfor %}
-}
-impl {{ type_name|pascal|rust_identifier }} {
-    pub fn try_from_uper<'a>(rest: &'a [bool]) -> Result<(&'a [bool], Self), nom::Err<crate::asn1_uper::DecodingError<'a>>> {
-        {%- if type_def|choice_is_extensible %}
-        let (rest, is_extended) = crate::asn1_uper::decode_bool(rest)?;
-        if is_extended {
-            panic!("cannot currently handle extensibility");
-        }
-        {%- endif %}
-        {%- if type_def|choice_base_option_count > 1 %}
-        let (mut rest, value_index) = crate::asn1_uper::decode_integer(rest, &crate::asn1_uper::WholeNumberConstraint::Constrained { min: crate::asn1_uper::Integer::from_short(0), max: crate::asn1_uper::Integer::from_short({{ type_def|choice_base_option_count }}) })?;
-        let choice_value = match value_index.try_to_usize().expect("failed to decode enumerated value to usize") {
-            {#- the data is encoded as the index, not as the value! #}
-            {%- for member in type_def.members if member is not none %}
-            {{ loop.index0 }} => {
-                let (new_rest, inner_value) = {{ member.type|rust_deserialize_call(type_name) }};
-                rest = new_rest;
-                Self::{{ member.name|pascal|rust_identifier }}(inner_value)
-            },
-            {%- endfor %}
-            other => panic!("unexpected {{ type_name|pascal|rust_identifier }} value {}", other),
-        };
-        Ok((rest, choice_value))
-        {%- else %}
-            {#- a choice with a single option is never explicitly encoded #}
-            {%- for member in type_def.members if member is not none %}
-        let (rest, inner_value) = {{ member.type|rust_deserialize_call(type_name) }};
-        Ok((rest, Self::{{ member.name|pascal|rust_identifier }}(inner_value)))
-            {%- endfor %}
-        {%- endif %}
-    }
-
-    pub fn write_uper(&self, uper_buf: &mut Vec<bool>) -> Result<(), crate::asn1_uper::EncodingError> {
-        {%- if type_def|choice_is_extensible %}
-        crate::asn1_uper::encode_bool(uper_buf, false);
-        {%- endif %}
-        {#- a choice with a single option is never explicitly encoded #}
-        {%- if type_def|choice_base_option_count > 1 %}
-        match self {
-            {%- for member in type_def.members if member is not none %}
-            Self::{{ member.name|pascal|rust_identifier }}(inner_value) => {
-                let index = crate::asn1_uper::Integer::from_short({{ loop.index0 }});
-                crate::asn1_uper::encode_integer(uper_buf, &crate::asn1_uper::WholeNumberConstraint::Constrained { min: crate::asn1_uper::Integer::from_short(0), max: crate::asn1_uper::Integer::from_short({{ type_def|choice_base_option_count }}) }, &index)?;
-                {{ {"type": member.type, "name": "inner_value"}|rust_serialize_call(self_prefix=None) }};
-            },
-            {%- endfor %}
-        };
-        {%- endif %}
-        Ok(())
-    }
+    {%- for member in type_def.members|extension_alternatives %}
+    {{ member|uper_alternative_attr(extension=True) }}{{ member.name|pascal|rust_identifier }}({{ member|rust_type(type_name) }}),
+    {%- endfor %}
+    {%- if type_def|choice_is_extensible %}
+    /// An alternative added by an extension that is unknown to this version of the schema, along
+    /// with its extension index and its encoded value.
+    #[uper(unknown)]
+    Unknown { index: usize, contents: Vec<u8> },
+    {%- endif %}
 }
 {%- endmacro -%}
 
@@ -286,13 +138,6 @@ def rust_identifier(name: str) -> str:
         return name + "_"
     return name
 
-def dict_replacing(dictionary, **kvps):
-    ret = dict(dictionary)
-    for key, value in kvps.items():
-        ret[key] = value
-    return ret
-
-
 def to_rust_type(type_def: dict[str, Any], parent_type: Optional[str] = None) -> str:
     type_name = type_def["type"]
     if type_name == "SEQUENCE OF":
@@ -310,6 +155,12 @@ def to_rust_type(type_def: dict[str, Any], parent_type: Optional[str] = None) ->
         inner_type = "String"
     elif type_name == "BOOLEAN":
         inner_type = "bool"
+    elif type_name == "BIT STRING":
+        inner_type = "crate::asn1_uper::BitVec"
+    elif type_name == "OBJECT IDENTIFIER":
+        inner_type = "crate::asn1_uper::ObjectIdentifier"
+    elif type_name == "NULL":
+        inner_type = "crate::asn1_uper::Null"
     else:
         inner_type = to_pascal_case(type_name)
 
@@ -319,123 +170,165 @@ def to_rust_type(type_def: dict[str, Any], parent_type: Optional[str] = None) ->
         return inner_type
 
 
-def string_size_constraint(type_def: dict[str, Any]) -> str:
+def size_attrs(type_def: dict[str, Any]) -> list[str]:
     size = type_def.get("size", None)
-    if size is not None:
-        if isinstance(size[0], tuple):
-            (min_size, max_size) = size[0]
-        else:
-            min_size = max_size = size[0]
-        return f"Constrained {{ min: crate::asn1_uper::Integer::from_short({min_size}), max: crate::asn1_uper::Integer::from_short({max_size}) }}"
-    return "Unconstrained"
+    if size is None:
+        return []
+    if isinstance(size[0], tuple):
+        (min_size, max_size) = size[0]
+    else:
+        min_size = max_size = size[0]
+    if max_size == "MAX":
+        # X.691 § 11.9.4.2: without an upper bound, the length is encoded as if unconstrained
+        return []
+    return [f"size(min = {min_size}, max = {max_size})"]
 
 
-def rust_deserialize_call(member: Union[dict[str, Any], str], parent_type: Optional[str] = None) -> str:
-    if isinstance(member, str):
-        member = {"type": member}
-    type_name = member["type"]
+def value_attrs(type_def: dict[str, Any]) -> list[str]:
+    # the attributes describing the encoding of a value (see the uic-ticket-decode-derive crate)
+    type_name = type_def["type"]
     if type_name == "INTEGER":
-        constraints = member.get("restricted-to", None)
-        if constraints is not None:
+        constraints = type_def.get("restricted-to", None)
+        if constraints is None:
+            return []
+        if isinstance(constraints[0], tuple):
             (int_min, int_max) = constraints[0]
-            constraint_string = f"Constrained {{ min: crate::asn1_uper::Integer::from_short({int_min}), max: crate::asn1_uper::Integer::from_short({int_max}) }}"
-        # TODO: semi-constrained?
         else:
-            constraint_string = "Unconstrained"
-        return f"crate::asn1_uper::decode_integer(rest, &crate::asn1_uper::WholeNumberConstraint::{constraint_string})?"
+            int_min = int_max = constraints[0]
+        if int_min == "MIN":
+            # X.691 § 12.2.4: an upper bound alone does not constrain the encoding
+            return []
+        if int_max == "MAX":
+            # X.691 § 12.2.3
+            return [f"min = {int_min}"]
+        return [f"min = {int_min}", f"max = {int_max}"]
     elif type_name == "OCTET STRING":
-        size_constraint = string_size_constraint(member)
-        return f"crate::asn1_uper::decode_octet_string(rest, &crate::asn1_uper::WholeNumberConstraint::{size_constraint})?"
-    elif type_name == "BOOLEAN":
-        return "crate::asn1_uper::decode_bool(rest)?"
-    elif type_name == "UTF8String":
-        size_constraint = string_size_constraint(member)
-        lines = ["{"]
-        lines.append(f"    let (rest, octet_string) = crate::asn1_uper::decode_octet_string(rest, &crate::asn1_uper::WholeNumberConstraint::{size_constraint})?;")
-        lines.append("    crate::asn1_uper::octet_string_to_utf8(rest, octet_string)?")
-        lines.append("}")
-        return "\n".join(lines)
-    elif type_name == "IA5String":
-        size_constraint = string_size_constraint(member)
-        lines = ["{"]
-        lines.append(f"    let (rest, octet_string) = crate::asn1_uper::decode_ia5_string(rest, &crate::asn1_uper::WholeNumberConstraint::{size_constraint})?;")
-        lines.append("    crate::asn1_uper::octet_string_to_utf8(rest, octet_string)?")
-        lines.append("}")
-        return "\n".join(lines)
+        return size_attrs(type_def)
     elif type_name == "SEQUENCE OF":
-        # TODO: handle length constraints
-        lines = ["{"]
-        lines.append("    let (mut rest, length_integer) = crate::asn1_uper::decode_length(rest, &crate::asn1_uper::WholeNumberConstraint::Unconstrained)?;")
-        lines.append("    let length_usize = length_integer.try_to_usize()")
-        lines.append('        .expect("failed to convert length to usize");')
-        lines.append("    let mut buf = Vec::with_capacity(length_usize);")
-        lines.append("    for _ in 0..length_usize {")
-        lines.append("        let (new_rest, member) = " + rust_deserialize_call(member["element"], parent_type) + ";")
-        lines.append("        buf.push(member);")
-        lines.append("        rest = new_rest;")
-        lines.append("    }")
-        lines.append("    (rest, buf)")
-        lines.append("}")
-        return "\n".join(lines)
-    elif type_name == "CHOICE":
-        # inline choice -- constructed name
-        choice_name = to_pascal_case(parent_type) + to_pascal_case(member["name"])
-        return f"{choice_name}::try_from_uper(rest)?"
+        element_attrs = value_attrs(type_def["element"])
+        if element_attrs:
+            return size_attrs(type_def) + [f"element({', '.join(element_attrs)})"]
+        return size_attrs(type_def)
+    elif type_name == "IA5String":
+        return ["ia5"] + size_attrs(type_def)
+    elif type_name == "UTF8String":
+        return ["utf8"] + size_attrs(type_def)
+    elif type_name == "BIT STRING":
+        # X.691 § 16.2 and § 16.3: trailing zero bits are dropped if the bits are named
+        return size_attrs(type_def) + (["named_bits"] if type_def.get("named-bits") else [])
     else:
-        return f"{to_pascal_case(type_name)}::try_from_uper(rest)?"
+        return []
+
+
+def uper_attr(attrs: list[str]) -> str:
+    if not attrs:
+        return ""
+    return f"#[uper({', '.join(attrs)})] "
+
+
+def uper_member_attr(member: dict[str, Any], siblings: list[Any], extension: bool = False) -> str:
+    attrs = []
+    if default_field_name(rust_identifier(to_snake_case(member["name"]))) != member["name"]:
+        attrs.append(f"name = {rust_string(member['name'])}")
+    if extension:
+        attrs.append("extension")
+    elif member.get("optional", False) and member["type"] == "SEQUENCE OF":
+        # optional members of other types are wrapped in Option
+        attrs.append("optional")
+    if "default" in member:
+        attrs.append(f"default = {rust_default_value(member)}")
+    num_counterpart = exclusive_num_counterpart(member, siblings)
+    if num_counterpart is not None:
+        attrs.append(f"exclusive_with = {rust_identifier(to_snake_case(num_counterpart))}")
+    return uper_attr(attrs + value_attrs(member))
+
+
+def exclusive_num_counterpart(member: dict[str, Any], siblings: list[Any]) -> Optional[str]:
+    # UIC schemas give many values either as a number (fooNum) or as a string (fooIA5), never both
+    if not member["name"].endswith("IA5"):
+        return None
+    num_name = member["name"][:-len("IA5")] + "Num"
+    for sibling in siblings:
+        if isinstance(sibling, dict) and sibling["name"] == num_name:
+            return num_name
+    return None
+
+
+def uper_alternative_attr(member: dict[str, Any], extension: bool = False) -> str:
+    attrs = []
+    pascal_name = to_pascal_case(member["name"])
+    if pascal_name[:1].lower() + pascal_name[1:] != member["name"]:
+        attrs.append(f"name = {rust_string(member['name'])}")
+    if extension:
+        attrs.append("extension")
+    return uper_attr(attrs + value_attrs(member))
+
+
+def default_field_name(rust_name: str) -> str:
+    # the ASN.1 name the derive macros assume for a field if none is given
+    pieces = rust_name.rstrip("_").split("_")
+    return pieces[0] + "".join(p[:1].upper() + p[1:] for p in pieces[1:])
+
+
+def rust_string(value: str) -> str:
+    escaped = value.replace("\\", "\\\\").replace('"', '\\"')
+    return f'"{escaped}"'
+
+
+def sequence_root_members(type_def: dict[str, Any]) -> list[dict[str, Any]]:
+    # members up to the extension marker (None)
+    ret = []
+    for member in type_def["members"]:
+        if member is None:
+            break
+        ret.append(member)
+    return ret
 
 
-def rust_serialize_call(member: Union[dict[str, Any], str], parent_type: Optional[str] = None, self_prefix: bool = True, deref: bool = False) -> str:
-    if isinstance(member, str):
-        member = {"type": member}
-    rust_member_name = rust_identifier(to_snake_case(member["name"]))
-    self_prefix_text = "self." if self_prefix else ""
-    type_name = member["type"]
-    if type_name == "INTEGER":
-        constraints = member.get("restricted-to", None)
-        if constraints is not None:
-            (int_min, int_max) = constraints[0]
-            constraint_string = f"Constrained {{ min: crate::asn1_uper::Integer::from_short({int_min}), max: crate::asn1_uper::Integer::from_short({int_max}) }}"
-        # TODO: semi-constrained?
-        else:
-            constraint_string = "Unconstrained"
-        return f"crate::asn1_uper::encode_integer(uper_buf, &crate::asn1_uper::WholeNumberConstraint::{constraint_string}, &{self_prefix_text}{rust_member_name})?"
-    elif type_name == "OCTET STRING":
-        size_constraint = string_size_constraint(member)
-        return f"crate::asn1_uper::encode_octet_string(uper_buf, &crate::asn1_uper::WholeNumberConstraint::{size_constraint}, &{self_prefix_text}{rust_member_name})?"
-    elif type_name == "BOOLEAN":
-        star = "*" if deref else ""
-        return f"crate::asn1_uper::encode_bool(uper_buf, {star}{self_prefix_text}{rust_member_name})"
-    elif type_name == "UTF8String":
-        size_constraint = string_size_constraint(member)
-        return f"crate::asn1_uper::encode_octet_string(uper_buf, &crate::asn1_uper::WholeNumberConstraint::{size_constraint}, {self_prefix_text}{rust_member_name}.as_bytes())?"
-    elif type_name == "IA5String":
-        size_constraint = string_size_constraint(member)
-        return f"crate::asn1_uper::encode_ia5_string(uper_buf, &crate::asn1_uper::WholeNumberConstraint::{size_constraint}, &{self_prefix_text}{rust_member_name})?"
-    elif type_name == "SEQUENCE OF":
-        # TODO: handle length constraints
-        member_element = dict(member["element"])
-        member_element["name"] = "item"
-        lines = ["{"]
-        lines.append(f"    crate::asn1_uper::encode_length(uper_buf, &crate::asn1_uper::WholeNumberConstraint::Unconstrained, {self_prefix_text}{rust_member_name}.len())?;")
-        lines.append(f"    for item in &{self_prefix_text}{rust_member_name} {{")
-        lines.append("        " + rust_serialize_call(member_element, parent_type, self_prefix=False) + ";")
-        lines.append("    }")
-        lines.append("}")
-        return "\n".join(lines)
-    else:
-        # includes CHOICE
-        return f"{self_prefix_text}{rust_member_name}.write_uper(uper_buf)?"
+def sequence_extension_additions(type_def: dict[str, Any]) -> list[dict[str, Any]]:
+    # members after the extension marker (None)
+    if None not in type_def["members"]:
+        return []
+    marker_index = type_def["members"].index(None)
+    ret = []
+    for member in type_def["members"][marker_index+1:]:
+        if member is None:
+            continue
+        if isinstance(member, list):
+            raise ValueError(f"extension addition groups are not supported (in {type_def})")
+        # extension additions are always optional from the decoder's point of view
+        addition = dict(member)
+        addition["optional"] = True
+        addition.pop("default", None)
+        ret.append(addition)
+    return ret
+
+
+def root_alternatives(entries: list[Any]) -> list[Any]:
+    # ENUMERATED values or CHOICE alternatives up to the extension marker (None)
+    ret = []
+    for entry in entries:
+        if entry is None:
+            break
+        ret.append(entry)
+    return ret
 
 
-def rust_default_value(member: Union[dict[str, Any], str]) -> str:
-    if isinstance(member, str):
-        member = {"type": member}
+def extension_alternatives(entries: list[Any]) -> list[Any]:
+    # ENUMERATED values or CHOICE alternatives after the extension marker (None)
+    if None not in entries:
+        return []
+    marker_index = entries.index(None)
+    return [entry for entry in entries[marker_index+1:] if entry is not None]
+
+
+def rust_default_value(member: dict[str, Any]) -> str:
+    # the value of the default attribute; the derive macros turn literals into Integer and String
     type_name = member["type"]
     if type_name == "INTEGER":
-        return f"crate::asn1_uper::Integer::from_short({member['default']})"
+        return str(member["default"])
     elif type_name in ("UTF8String", "IA5String"):
-        return f'"{member["default"]}".to_owned()'
+        return rust_string(member["default"])
     else:
         # assume it's an enum
         return f"{to_pascal_case(type_name)}::{to_pascal_case(member['default'])}"
@@ -444,22 +337,19 @@ def rust_default_value(member: Union[dict[str, Any], str]) -> str:
 def rustify_type(type_name: str, type_def: dict[str, Any]) -> str:
     env = jinja2.Environment(undefined=jinja2.StrictUndefined)
     env.filters["pascal"] = to_pascal_case
-    env.filters["camel"] = to_camel_case
-    env.filters["kebab"] = to_kebab_case
     env.filters["snake"] = to_snake_case
     env.filters["rust_type"] = to_rust_type
+    env.filters["rust_identifier"] = rust_identifier
+    env.filters["rust_string"] = rust_string
     env.filters["sequence_is_extensible"] = lambda type_def: None in type_def["members"]
+    env.filters["root_members"] = sequence_root_members
+    env.filters["extension_additions"] = sequence_extension_additions
     env.filters["enum_is_extensible"] = lambda type_def: None in type_def["values"]
     env.filters["choice_is_extensible"] = lambda type_def: None in type_def["members"]
-    env.filters["count_optional"] = lambda entries: sum(1 for e in entries if e is not None and (e.get("optional", False) or "default" in e))
-    env.filters["enum_base_option_count"] = lambda type_def: sum(1 for e in type_def["values"] if e is not None)
-    env.filters["choice_base_option_count"] = lambda type_def: sum(1 for e in type_def["members"] if e is not None)
-    env.filters["rust_deserialize_call"] = rust_deserialize_call
-    env.filters["rust_serialize_call"] = rust_serialize_call
-    env.filters["rust_identifier"] = rust_identifier
-    env.filters["rust_default_value"] = rust_default_value
-    env.filters["repr"] = repr
-    env.filters["dict_replacing"] = dict_replacing
+    env.filters["root_alternatives"] = root_alternatives
+    env.filters["extension_alternatives"] = extension_alternatives
+    env.filters["uper_member_attr"] = uper_member_attr
+    env.filters["uper_alternative_attr"] = uper_alternative_attr
 
     tpl = env.from_string(TEMPLATE)
     return tpl.render(type_name=type_name, type_def=type_def)
@@ -489,7 +379,9 @@ def main():
         args.rust_dest.write("// This file has been generated by asn1_uper_model.py.\n")
         args.rust_dest.write("// Manual changes are likely to disappear without a trace.\n")
         args.rust_dest.write("\n\n")
-        args.rust_dest.write("use serde::{Deserialize, Serialize};")
+        args.rust_dest.write("use serde::{Deserialize, Serialize};\n")
+        args.rust_dest.write("\n")
+        args.rust_dest.write("use crate::asn1_uper::{UperDecode, UperEncode, UperVisit};")
         args.rust_dest.write("\n\n")
 
         for type_name, type_def in asn1_def["types"].items():
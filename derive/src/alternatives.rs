@@ -0,0 +1,504 @@
+//! Derivation for enums, which correspond to ASN.1 ENUMERATED types if all their variants are unit
+//! variants and to ASN.1 CHOICE types otherwise.
+
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataEnum, Fields, Ident, Variant};
+
+use crate::asn1_name_of_variant;
+use crate::attr::MemberAttrs;
+use crate::value::{index_constraint, ValueKind};
+
+
+struct Alternative {
+    ident: Ident,
+    name: String,
+
+    /// The type of the value of a CHOICE alternative; `None` for ENUMERATED values.
+    kind: Option<ValueKind>,
+}
+impl Alternative {
+    /// An expression decoding the value of the CHOICE alternative from `rest`, returning a
+    /// `ParseResult`.
+    fn traced_decode_expr(&self) -> TokenStream {
+        let name = &self.name;
+        let decode_expr = self.kind.as_ref()
+            .expect("decoding the value of an ENUMERATED alternative")
+            .decode_expr();
+        quote! {
+            crate::asn1_uper::trace::field(#name, rest, |rest| #decode_expr)
+        }
+    }
+
+    fn encode_stmt(&self) -> TokenStream {
+        self.kind.as_ref()
+            .expect("encoding the value of an ENUMERATED alternative")
+            .encode_stmt(&quote! { inner_value })
+    }
+}
+
+
+/// The variants of an enum, sorted into the root alternatives and the extension additions.
+pub(crate) struct Alternatives {
+    is_choice: bool,
+    root: Vec<Alternative>,
+    additions: Vec<Alternative>,
+    unknown: Option<Ident>,
+}
+impl Alternatives {
+    pub fn new(data: &DataEnum) -> syn::Result<Self> {
+        let variants = data.variants.iter()
+            .map(|variant| Ok((variant, MemberAttrs::parse(&variant.attrs)?)))
+            .collect::<syn::Result<Vec<_>>>()?;
+        let is_choice = variants.iter()
+            .any(|(variant, attrs)| !attrs.unknown && !matches!(variant.fields, Fields::Unit));
+
+        let mut root = Vec::new();
+        let mut additions = Vec::new();
+        let mut unknown = None;
+        for (variant, attrs) in variants {
+            if attrs.unknown {
+                check_unknown_variant(variant, is_choice)?;
+                unknown = Some(variant.ident.clone());
+                continue;
+            }
+
+            let kind = if is_choice {
+                let Fields::Unnamed(fields) = &variant.fields else {
+                    return Err(syn::Error::new_spanned(variant, "a CHOICE alternative must have exactly one unnamed field"));
+                };
+                let Some(field) = fields.unnamed.first().filter(|_| fields.unnamed.len() == 1) else {
+                    return Err(syn::Error::new_spanned(variant, "a CHOICE alternative must have exactly one unnamed field"));
+                };
+                Some(ValueKind::new(&field.ty, &attrs.value)?)
+            } else {
+                None
+            };
+            let alternative = Alternative {
+                ident: variant.ident.clone(),
+                name: attrs.name.unwrap_or_else(|| asn1_name_of_variant(&variant.ident)),
+                kind,
+            };
+            if attrs.extension {
+                additions.push(alternative);
+            } else {
+                root.push(alternative);
+            }
+        }
+
+        if root.is_empty() {
+            return Err(syn::Error::new_spanned(&data.variants, "at least one root alternative is required"));
+        }
+        if unknown.is_none() && !additions.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &data.variants,
+                "extension additions require a variant marked #[uper(unknown)] for unknown extensions",
+            ));
+        }
+        Ok(Self { is_choice, root, additions, unknown })
+    }
+
+    pub fn decode_body(&self) -> TokenStream {
+        if self.is_choice {
+            self.decode_choice_body()
+        } else {
+            self.decode_enumerated_body()
+        }
+    }
+
+    pub fn encode_body(&self) -> TokenStream {
+        if self.is_choice {
+            self.encode_choice_body()
+        } else {
+            self.encode_enumerated_body()
+        }
+    }
+
+    pub fn validate_body(&self) -> TokenStream {
+        let unused = quote! {
+            let _ = (path, violations);
+        };
+        if !self.is_choice {
+            return unused;
+        }
+
+        let mut arms = TokenStream::new();
+        let mut any_validated = false;
+        for alternative in self.root.iter().chain(self.additions.iter()) {
+            let ident = &alternative.ident;
+            let name = &alternative.name;
+            let validate_stmts = alternative.kind.as_ref()
+                .expect("validating the value of an ENUMERATED alternative")
+                .validate_stmts(&quote! { inner_value });
+            arms.extend(match validate_stmts {
+                Some(validate_stmts) => {
+                    any_validated = true;
+                    quote! {
+                        Self::#ident(inner_value) => {
+                            path.push(crate::asn1_uper::trace::PathSegment::Member(#name));
+                            #validate_stmts
+                            path.pop();
+                        },
+                    }
+                },
+                None => quote! {
+                    Self::#ident(_) => {},
+                },
+            });
+        }
+        if !any_validated {
+            return unused;
+        }
+        if let Some(unknown) = &self.unknown {
+            arms.extend(quote! {
+                Self::#unknown { .. } => {},
+            });
+        }
+        quote! {
+            match self {
+                #arms
+            }
+        }
+    }
+
+    pub fn visit_body(&self, mutable: bool) -> TokenStream {
+        if !self.is_choice {
+            return if mutable {
+                quote! {
+                    visitor.visit_mut(path, crate::asn1_uper::visit::ValueMut::Enumerated(self));
+                }
+            } else {
+                let visit_unknown_stmt = self.unknown.as_ref().map(|unknown| quote! {
+                    if let Self::#unknown(index) = self {
+                        visitor.visit_unknown(path, crate::asn1_uper::visit::Unknown::Enumerated(*index));
+                    }
+                });
+                quote! {
+                    visitor.visit(path, crate::asn1_uper::visit::Value::Enumerated(crate::asn1_uper::visit::Enumerated::name(self)));
+                    #visit_unknown_stmt
+                }
+            };
+        }
+
+        let mut arms = TokenStream::new();
+        for alternative in self.root.iter().chain(self.additions.iter()) {
+            let ident = &alternative.ident;
+            let name = &alternative.name;
+            let visit_stmt = alternative.kind.as_ref()
+                .expect("visiting the value of an ENUMERATED alternative")
+                .visit_stmt(&quote! { inner_value }, mutable);
+            arms.extend(quote! {
+                Self::#ident(inner_value) => {
+                    path.push(crate::asn1_uper::trace::PathSegment::Member(#name));
+                    #visit_stmt
+                    path.pop();
+                },
+            });
+        }
+        if let Some(unknown) = &self.unknown {
+            // the contents of unknown alternatives are opaque
+            arms.extend(if mutable {
+                quote! {
+                    Self::#unknown { .. } => {},
+                }
+            } else {
+                quote! {
+                    Self::#unknown { index, contents } => {
+                        visitor.visit_unknown(path, crate::asn1_uper::visit::Unknown::Alternative { index: *index, contents });
+                    },
+                }
+            });
+        }
+        quote! {
+            match self {
+                #arms
+            }
+        }
+    }
+
+    /// The implementation of `Enumerated` for an ENUMERATED type; `None` for a CHOICE type.
+    pub fn enumerated_impl(&self, ident: &Ident) -> Option<TokenStream> {
+        if self.is_choice {
+            return None;
+        }
+
+        let alternatives: Vec<&Alternative> = self.root.iter().chain(self.additions.iter()).collect();
+        let idents: Vec<&Ident> = alternatives.iter().map(|alternative| &alternative.ident).collect();
+        let names: Vec<&String> = alternatives.iter().map(|alternative| &alternative.name).collect();
+        let unknown_arm = self.unknown.as_ref().map(|unknown| quote! {
+            Self::#unknown(_) => None,
+        });
+        Some(quote! {
+            impl crate::asn1_uper::visit::Enumerated for #ident {
+                fn name(&self) -> Option<&'static str> {
+                    match self {
+                        #( Self::#idents => Some(#names), )*
+                        #unknown_arm
+                    }
+                }
+
+                fn set_name(&mut self, name: &str) -> bool {
+                    *self = match name {
+                        #( #names => Self::#idents, )*
+                        _ => return false,
+                    };
+                    true
+                }
+            }
+        })
+    }
+
+    fn decode_enumerated_body(&self) -> TokenStream {
+        let mut body = TokenStream::new();
+        if let Some(unknown) = &self.unknown {
+            let enum_value = if self.additions.is_empty() {
+                quote! { Self::#unknown(extension_index) }
+            } else {
+                let indexes = 0..self.additions.len();
+                let idents = self.additions.iter().map(|alternative| &alternative.ident);
+                quote! {
+                    match extension_index {
+                        #( #indexes => Self::#idents, )*
+                        other => Self::#unknown(other),
+                    }
+                }
+            };
+            body.extend(quote! {
+                let (rest, is_extended) = crate::asn1_uper::decode_bool(rest)?;
+                if is_extended {
+                    let (rest, extension_index) = crate::asn1_uper::decode_extension_index(rest)?;
+                    let enum_value = #enum_value;
+                    return Ok((rest, enum_value));
+                }
+            });
+        }
+
+        if self.root.len() == 1 {
+            // an enumeration with a single value is never explicitly encoded
+            let ident = &self.root[0].ident;
+            body.extend(quote! {
+                Ok((rest, Self::#ident))
+            });
+        } else {
+            // the data is encoded as the index, not as the value!
+            let constraint = index_constraint(self.root.len());
+            let indexes = 0..self.root.len();
+            let idents = self.root.iter().map(|alternative| &alternative.ident);
+            body.extend(quote! {
+                let (rest, value_index) = crate::asn1_uper::decode_integer(rest, &#constraint)?;
+                let enum_value = match value_index.try_to_usize() {
+                    #( Some(#indexes) => Self::#idents, )*
+                    _ => return Err(nom::Err::Error(crate::asn1_uper::DecodingError::new(rest, crate::asn1_uper::ErrorKind::InvalidIndex { index: value_index }))),
+                };
+                Ok((rest, enum_value))
+            });
+        }
+        body
+    }
+
+    fn encode_enumerated_body(&self) -> TokenStream {
+        let mut body = TokenStream::new();
+        if let Some(unknown) = &self.unknown {
+            let known_index_check = self.known_index_check();
+            let indexes = 0..self.additions.len();
+            let idents = self.additions.iter().map(|alternative| &alternative.ident);
+            body.extend(quote! {
+                let extension_index = match self {
+                    #( Self::#idents => Some(#indexes), )*
+                    Self::#unknown(index) => {
+                        #known_index_check
+                        Some(*index)
+                    },
+                    _ => None,
+                };
+                if let Some(extension_index) = extension_index {
+                    crate::asn1_uper::encode_bool(uper_buf, true);
+                    return crate::asn1_uper::encode_extension_index(uper_buf, extension_index);
+                }
+                crate::asn1_uper::encode_bool(uper_buf, false);
+            });
+        }
+
+        // an enumeration with a single value is never explicitly encoded
+        if self.root.len() > 1 {
+            let constraint = index_constraint(self.root.len());
+            let indexes = (0..self.root.len()).map(|index| index as i128);
+            let idents = self.root.iter().map(|alternative| &alternative.ident);
+            let unreachable_arm = if self.unknown.is_some() {
+                quote! { _ => unreachable!("extension values have been encoded above"), }
+            } else {
+                quote! {}
+            };
+            body.extend(quote! {
+                let integer_value = match self {
+                    #( Self::#idents => crate::asn1_uper::Integer::from_short(#indexes), )*
+                    #unreachable_arm
+                };
+                crate::asn1_uper::encode_integer(uper_buf, &#constraint, &integer_value)?;
+            });
+        }
+        body.extend(quote! {
+            Ok(())
+        });
+        body
+    }
+
+    fn decode_choice_body(&self) -> TokenStream {
+        let mut body = TokenStream::new();
+        if let Some(unknown) = &self.unknown {
+            let choice_value = if self.additions.is_empty() {
+                quote! { Self::#unknown { index: extension_index, contents } }
+            } else {
+                let indexes = 0..self.additions.len();
+                let idents = self.additions.iter().map(|alternative| &alternative.ident);
+                let decode_exprs = self.additions.iter().map(|alternative| alternative.traced_decode_expr());
+                quote! {
+                    match extension_index {
+                        #( #indexes => Self::#idents(crate::asn1_uper::decode_open_type_contents(rest, &contents, |rest| #decode_exprs)?), )*
+                        index => Self::#unknown { index, contents },
+                    }
+                }
+            };
+            body.extend(quote! {
+                let (rest, is_extended) = crate::asn1_uper::decode_bool(rest)?;
+                if is_extended {
+                    let (rest, extension_index) = crate::asn1_uper::decode_extension_index(rest)?;
+                    let (rest, contents) = crate::asn1_uper::decode_open_type(rest)?;
+                    let choice_value = #choice_value;
+                    return Ok((rest, choice_value));
+                }
+            });
+        }
+
+        if self.root.len() == 1 {
+            // a choice with a single alternative is never explicitly encoded
+            let ident = &self.root[0].ident;
+            let decode_expr = self.root[0].traced_decode_expr();
+            body.extend(quote! {
+                let (rest, inner_value) = #decode_expr?;
+                Ok((rest, Self::#ident(inner_value)))
+            });
+        } else {
+            // the data is encoded as the index, not as the value!
+            let constraint = index_constraint(self.root.len());
+            let indexes = 0..self.root.len();
+            let idents = self.root.iter().map(|alternative| &alternative.ident);
+            let decode_exprs = self.root.iter().map(|alternative| alternative.traced_decode_expr());
+            body.extend(quote! {
+                let (rest, value_index) = crate::asn1_uper::decode_integer(rest, &#constraint)?;
+                match value_index.try_to_usize() {
+                    #(
+                        Some(#indexes) => {
+                            let (rest, inner_value) = #decode_exprs?;
+                            Ok((rest, Self::#idents(inner_value)))
+                        },
+                    )*
+                    _ => Err(nom::Err::Error(crate::asn1_uper::DecodingError::new(rest, crate::asn1_uper::ErrorKind::InvalidIndex { index: value_index }))),
+                }
+            });
+        }
+        body
+    }
+
+    /// A statement rejecting an unknown value whose extension index belongs to a known extension
+    /// addition, as it would be decoded as that addition; `None` if there are no known additions.
+    fn known_index_check(&self) -> Option<TokenStream> {
+        if self.additions.is_empty() {
+            return None;
+        }
+        let addition_count = self.additions.len();
+        Some(quote! {
+            if *index < #addition_count {
+                return Err(crate::asn1_uper::EncodingError::UnknownValueHasKnownIndex { index: *index, known_count: #addition_count });
+            }
+        })
+    }
+
+    fn encode_choice_body(&self) -> TokenStream {
+        let mut arms = TokenStream::new();
+        for (index, alternative) in self.root.iter().enumerate() {
+            let ident = &alternative.ident;
+            let extension_bit = if self.unknown.is_some() {
+                quote! { crate::asn1_uper::encode_bool(uper_buf, false); }
+            } else {
+                quote! {}
+            };
+            // a choice with a single alternative is never explicitly encoded
+            let index_stmt = if self.root.len() > 1 {
+                let constraint = index_constraint(self.root.len());
+                let index = index as i128;
+                quote! {
+                    let index = crate::asn1_uper::Integer::from_short(#index);
+                    crate::asn1_uper::encode_integer(uper_buf, &#constraint, &index)?;
+                }
+            } else {
+                quote! {}
+            };
+            let encode_stmt = alternative.encode_stmt();
+            arms.extend(quote! {
+                Self::#ident(inner_value) => {
+                    #extension_bit
+                    #index_stmt
+                    #encode_stmt
+                },
+            });
+        }
+        for (index, alternative) in self.additions.iter().enumerate() {
+            let ident = &alternative.ident;
+            let encode_stmt = alternative.encode_stmt();
+            arms.extend(quote! {
+                Self::#ident(inner_value) => {
+                    crate::asn1_uper::encode_bool(uper_buf, true);
+                    crate::asn1_uper::encode_extension_index(uper_buf, #index)?;
+                    let contents = crate::asn1_uper::encode_open_type_contents(|uper_buf| {
+                        #encode_stmt
+                        Ok(())
+                    })?;
+                    crate::asn1_uper::encode_open_type(uper_buf, &contents)?;
+                },
+            });
+        }
+        if let Some(unknown) = &self.unknown {
+            let known_index_check = self.known_index_check();
+            arms.extend(quote! {
+                Self::#unknown { index, contents } => {
+                    #known_index_check
+                    crate::asn1_uper::encode_bool(uper_buf, true);
+                    crate::asn1_uper::encode_extension_index(uper_buf, *index)?;
+                    crate::asn1_uper::encode_open_type(uper_buf, contents)?;
+                },
+            });
+        }
+        quote! {
+            match self {
+                #arms
+            }
+            Ok(())
+        }
+    }
+}
+
+
+/// Checks that the variant storing unknown extensions has the expected shape: `Unknown(usize)` for
+/// ENUMERATED types and `Unknown { index: usize, contents: Vec<u8> }` for CHOICE types.
+fn check_unknown_variant(variant: &Variant, is_choice: bool) -> syn::Result<()> {
+    let shape_ok = match &variant.fields {
+        Fields::Unnamed(fields) => !is_choice && fields.unnamed.len() == 1,
+        Fields::Named(fields) => {
+            let names: Vec<String> = fields.named.iter()
+                .filter_map(|field| field.ident.as_ref())
+                .map(|ident| ident.to_string())
+                .collect();
+            is_choice && names == ["index", "contents"]
+        },
+        Fields::Unit => false,
+    };
+    if shape_ok {
+        Ok(())
+    } else if is_choice {
+        Err(syn::Error::new_spanned(variant, "the unknown alternative of a CHOICE must be `Unknown { index: usize, contents: Vec<u8> }`"))
+    } else {
+        Err(syn::Error::new_spanned(variant, "the unknown value of an ENUMERATED type must be `Unknown(usize)`"))
+    }
+}
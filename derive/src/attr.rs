@@ -0,0 +1,169 @@
+//! Parsing of the `#[uper(...)]` attributes.
+
+
+use syn::{Attribute, Expr, Ident, LitInt, LitStr, Token};
+use syn::meta::ParseNestedMeta;
+
+
+/// The `#[uper(...)]` attributes of a type.
+#[derive(Default)]
+pub(crate) struct TypeAttrs {
+    /// The ASN.1 name of the type, if it differs from the Rust name.
+    pub name: Option<String>,
+}
+impl TypeAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut ret = Self::default();
+        for attr in uper_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    ret.name = Some(name.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown type attribute"))
+                }
+            })?;
+        }
+        Ok(ret)
+    }
+}
+
+
+/// The lower and upper bound of an integer value or of a size.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Bounds {
+    pub min: Option<i128>,
+    pub max: Option<i128>,
+}
+
+
+/// The kind of character string stored in a `String`.
+#[derive(Clone, Copy)]
+pub(crate) enum StringKind {
+    Ia5,
+    Utf8,
+}
+
+
+/// The attributes describing how a value is encoded.
+///
+/// These apply to a field or alternative, or to the elements of a SEQUENCE OF.
+#[derive(Clone, Default)]
+pub(crate) struct ValueAttrs {
+    /// The range of an INTEGER.
+    pub range: Bounds,
+
+    /// The SIZE constraint of a string or SEQUENCE OF.
+    pub size: Bounds,
+
+    /// The kind of character string.
+    pub string: Option<StringKind>,
+
+    /// Whether the BIT STRING has named bits.
+    pub named_bits: bool,
+
+    /// The attributes of the elements of a SEQUENCE OF.
+    pub element: Option<Box<ValueAttrs>>,
+}
+
+
+/// The `#[uper(...)]` attributes of a field of a SEQUENCE or an alternative of an ENUMERATED or
+/// CHOICE type.
+#[derive(Default)]
+pub(crate) struct MemberAttrs {
+    /// The ASN.1 name of the member, if it differs from the one derived from the Rust name.
+    pub name: Option<String>,
+
+    /// Whether the member is OPTIONAL; only needed for SEQUENCE OF members, as `Option` fields are
+    /// always optional.
+    pub optional: bool,
+
+    /// The DEFAULT value of the member.
+    pub default: Option<Expr>,
+
+    /// Whether the member has been added after the extension marker.
+    pub extension: bool,
+
+    /// Whether the member stores unknown extensions.
+    pub unknown: bool,
+
+    /// The field of another member which must not be present at the same time as this member.
+    pub exclusive_with: Option<Ident>,
+
+    pub value: ValueAttrs,
+}
+impl MemberAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut ret = Self::default();
+        for attr in uper_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    ret.name = Some(name.value());
+                } else if meta.path.is_ident("optional") {
+                    ret.optional = true;
+                } else if meta.path.is_ident("default") {
+                    ret.default = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("extension") {
+                    ret.extension = true;
+                } else if meta.path.is_ident("unknown") {
+                    ret.unknown = true;
+                } else if meta.path.is_ident("exclusive_with") {
+                    ret.exclusive_with = Some(meta.value()?.parse()?);
+                } else {
+                    parse_value_meta(&mut ret.value, &meta)?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(ret)
+    }
+}
+
+
+fn uper_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("uper"))
+}
+
+
+fn parse_value_meta(value: &mut ValueAttrs, meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.path.is_ident("min") {
+        value.range.min = Some(parse_bound(meta)?);
+    } else if meta.path.is_ident("max") {
+        value.range.max = Some(parse_bound(meta)?);
+    } else if meta.path.is_ident("size") {
+        meta.parse_nested_meta(|size_meta| {
+            if size_meta.path.is_ident("min") {
+                value.size.min = Some(parse_bound(&size_meta)?);
+            } else if size_meta.path.is_ident("max") {
+                value.size.max = Some(parse_bound(&size_meta)?);
+            } else {
+                return Err(size_meta.error("expected `min` or `max`"));
+            }
+            Ok(())
+        })?;
+    } else if meta.path.is_ident("ia5") {
+        value.string = Some(StringKind::Ia5);
+    } else if meta.path.is_ident("utf8") {
+        value.string = Some(StringKind::Utf8);
+    } else if meta.path.is_ident("named_bits") {
+        value.named_bits = true;
+    } else if meta.path.is_ident("element") {
+        let mut element = ValueAttrs::default();
+        meta.parse_nested_meta(|element_meta| parse_value_meta(&mut element, &element_meta))?;
+        value.element = Some(Box::new(element));
+    } else {
+        return Err(meta.error("unknown attribute"));
+    }
+    Ok(())
+}
+
+
+fn parse_bound(meta: &ParseNestedMeta) -> syn::Result<i128> {
+    let input = meta.value()?;
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    let literal: LitInt = input.parse()?;
+    let value: i128 = literal.base10_parse()?;
+    Ok(if negative { -value } else { value })
+}
@@ -0,0 +1,254 @@
+//! Derive macros for types that are decoded from and encoded into ASN.1 PER.
+//!
+//! `#[derive(UperDecode)]` implements `try_from_uper` and `try_from_per` on a type;
+//! `#[derive(UperEncode)]` implements `write_uper`, `write_per`, `check_canonical_uper` and
+//! `validate`; `#[derive(UperVisit)]` implements the `Visit` trait, which passes each primitive
+//! value within a value to a visitor. The generated code refers to `crate::asn1_uper` and is
+//! therefore meant for types within the `uic-ticket-decode` crate.
+//!
+//! The ASN.1 type corresponding to a Rust type is:
+//!
+//! * SEQUENCE for a struct with named fields,
+//! * ENUMERATED for an enum whose variants are unit variants,
+//! * CHOICE for an enum whose variants each have one unnamed field.
+//!
+//! The ASN.1 type of a field or CHOICE alternative is derived from its Rust type:
+//!
+//! | Rust type          | ASN.1 type                                               |
+//! |--------------------|----------------------------------------------------------|
+//! | `Integer`          | INTEGER                                                  |
+//! | `bool`             | BOOLEAN                                                  |
+//! | `Vec<u8>`          | OCTET STRING                                             |
+//! | `String`           | IA5String or UTF8String, selected by `ia5` or `utf8`     |
+//! | `BitVec`           | BIT STRING                                               |
+//! | `ObjectIdentifier` | OBJECT IDENTIFIER                                        |
+//! | `Null`             | NULL                                                     |
+//! | `Vec<T>`           | SEQUENCE OF                                              |
+//! | `Option<T>`        | an OPTIONAL member of type `T`                           |
+//! | any other type     | a type implementing `try_from_uper` and `write_uper`     |
+//!
+//! Further details are given in `#[uper(...)]` attributes:
+//!
+//! * `name = "..."` (on types, fields and variants): the ASN.1 name, used in error messages and
+//!   field maps. Defaults to the Rust name of the type, the field name in camel case or the variant
+//!   name in camel case.
+//! * `min = ...`, `max = ...`: the range of an INTEGER. Without `min`, the integer is unconstrained;
+//!   without `max`, it is semi-constrained.
+//! * `size(min = ..., max = ...)`: the SIZE constraint of a string, BIT STRING or SEQUENCE OF.
+//! * `ia5`, `utf8`: the kind of character string.
+//! * `named_bits`: the BIT STRING has named bits, so trailing zero bits are not encoded.
+//! * `element(...)`: the attributes of the elements of a SEQUENCE OF.
+//! * `optional`: the SEQUENCE OF member is OPTIONAL and encoded as absent if it is empty.
+//! * `default = ...`: the DEFAULT value of the member. Integer and string literals are converted to
+//!   `Integer` and `String`; other expressions are taken verbatim.
+//! * `extension`: the member or alternative is an extension addition. It must be an `Option` or a
+//!   `Vec` if it is a member.
+//! * `unknown`: the member or alternative keeps unknown extensions, which makes the type
+//!   extensible. In a SEQUENCE, this is a field of type `UnknownExtensions`; in an ENUMERATED type,
+//!   a variant `Unknown(usize)`; in a CHOICE type, a variant
+//!   `Unknown { index: usize, contents: Vec<u8> }`.
+//! * `exclusive_with = field`: the member must not be present at the same time as the given member;
+//!   checked by `validate`.
+//!
+//! ```ignore
+//! #[derive(UperDecode, UperEncode)]
+//! pub struct TravelerType {
+//!     #[uper(name = "firstName", utf8)]
+//!     pub first_name: Option<String>,
+//!     #[uper(min = 1, max = 31)]
+//!     pub date_of_birth: Option<Integer>,
+//!     #[uper(default = CodeTableType::StationUic)]
+//!     pub station_code_table: CodeTableType,
+//!     #[uper(optional, element(ia5))]
+//!     pub aliases: Vec<String>,
+//!     #[uper(unknown)]
+//!     pub unknown_extensions: UnknownExtensions,
+//! }
+//! ```
+
+
+mod alternatives;
+mod attr;
+mod sequence;
+mod value;
+
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Ident};
+
+use crate::alternatives::Alternatives;
+use crate::attr::TypeAttrs;
+use crate::sequence::Sequence;
+
+
+/// Implements `try_from_uper` and `try_from_per` on a struct or enum.
+#[proc_macro_derive(UperDecode, attributes(uper))]
+pub fn derive_uper_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_decode(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+
+/// Implements `write_uper`, `write_per`, `check_canonical_uper` and `validate` on a struct or enum.
+///
+/// `check_canonical_uper` also requires [`UperDecode`](derive@UperDecode).
+#[proc_macro_derive(UperEncode, attributes(uper))]
+pub fn derive_uper_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_encode(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+
+/// Implements `Visit` on a struct or enum, as well as `Enumerated` on an enum corresponding to an
+/// ENUMERATED type.
+#[proc_macro_derive(UperVisit, attributes(uper))]
+pub fn derive_uper_visit(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_visit(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+
+fn derive_decode(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let type_attrs = TypeAttrs::parse(&input.attrs)?;
+    let ident = &input.ident;
+    let type_name = type_attrs.name.unwrap_or_else(|| ident.to_string());
+    let body = match &input.data {
+        Data::Struct(data) => Sequence::new(data)?.decode_body(),
+        Data::Enum(data) => Alternatives::new(data)?.decode_body(),
+        Data::Union(_) => return Err(syn::Error::new_spanned(input, "unions are not supported")),
+    };
+
+    Ok(quote! {
+        impl #ident {
+            pub fn try_from_uper<'a>(rest: crate::asn1_uper::BitSlice<'a>) -> Result<(crate::asn1_uper::BitSlice<'a>, Self), nom::Err<crate::asn1_uper::DecodingError<'a>>> {
+                Self::decode_uper_contents(rest)
+                    .map_err(|e| e.map(|e| e.in_type(#type_name)))
+            }
+
+            fn decode_uper_contents<'a>(rest: crate::asn1_uper::BitSlice<'a>) -> Result<(crate::asn1_uper::BitSlice<'a>, Self), nom::Err<crate::asn1_uper::DecodingError<'a>>> {
+                #body
+            }
+
+            pub fn try_from_per<'a>(rest: crate::asn1_uper::BitSlice<'a>, variant: crate::asn1_uper::PerVariant) -> Result<(crate::asn1_uper::BitSlice<'a>, Self), nom::Err<crate::asn1_uper::DecodingError<'a>>> {
+                crate::asn1_uper::with_variant(variant, || Self::try_from_uper(rest))
+            }
+        }
+    })
+}
+
+
+fn derive_encode(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (body, validate_body) = match &input.data {
+        Data::Struct(data) => {
+            let sequence = Sequence::new(data)?;
+            (sequence.encode_body(), sequence.validate_body())
+        },
+        Data::Enum(data) => {
+            let alternatives = Alternatives::new(data)?;
+            (alternatives.encode_body(), alternatives.validate_body())
+        },
+        Data::Union(_) => return Err(syn::Error::new_spanned(input, "unions are not supported")),
+    };
+
+    Ok(quote! {
+        impl #ident {
+            pub fn write_uper(&self, uper_buf: &mut crate::asn1_uper::BitVec) -> Result<(), crate::asn1_uper::EncodingError> {
+                #body
+            }
+
+            pub fn write_per(&self, uper_buf: &mut crate::asn1_uper::BitVec, variant: crate::asn1_uper::PerVariant) -> Result<(), crate::asn1_uper::EncodingError> {
+                crate::asn1_uper::with_variant(variant, || self.write_uper(uper_buf))
+            }
+
+            pub fn check_canonical_uper<'a>(bits: crate::asn1_uper::BitSlice<'a>) -> Result<crate::asn1_uper::canonical::CanonicalReport, crate::asn1_uper::canonical::CanonicalCheckError<'a>> {
+                crate::asn1_uper::canonical::check_canonical(bits, Self::try_from_uper, Self::write_uper)
+            }
+
+            pub fn validate(&self) -> Result<(), Vec<crate::asn1_uper::validate::ConstraintViolation>> {
+                let mut violations = Vec::new();
+                self.validate_at(&mut Vec::new(), &mut violations);
+                if violations.is_empty() {
+                    Ok(())
+                } else {
+                    Err(violations)
+                }
+            }
+
+            pub fn validate_at(&self, path: &mut Vec<crate::asn1_uper::trace::PathSegment>, violations: &mut Vec<crate::asn1_uper::validate::ConstraintViolation>) {
+                #validate_body
+            }
+        }
+    })
+}
+
+
+fn derive_visit(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (visit_body, visit_mut_body, enumerated_impl) = match &input.data {
+        Data::Struct(data) => {
+            let sequence = Sequence::new(data)?;
+            (sequence.visit_body(false), sequence.visit_body(true), None)
+        },
+        Data::Enum(data) => {
+            let alternatives = Alternatives::new(data)?;
+            (alternatives.visit_body(false), alternatives.visit_body(true), alternatives.enumerated_impl(ident))
+        },
+        Data::Union(_) => return Err(syn::Error::new_spanned(input, "unions are not supported")),
+    };
+
+    Ok(quote! {
+        impl crate::asn1_uper::visit::Visit for #ident {
+            fn visit_at(&self, path: &mut Vec<crate::asn1_uper::trace::PathSegment>, visitor: &mut dyn crate::asn1_uper::visit::Visitor) {
+                #visit_body
+            }
+
+            fn visit_mut_at(&mut self, path: &mut Vec<crate::asn1_uper::trace::PathSegment>, visitor: &mut dyn crate::asn1_uper::visit::VisitorMut) {
+                #visit_mut_body
+            }
+        }
+
+        #enumerated_impl
+    })
+}
+
+
+/// The default ASN.1 name of a field: its name in camel case, e.g. `carrier_num` becomes
+/// `carrierNum` and `type_` becomes `type`.
+pub(crate) fn asn1_name_of_field(ident: &Ident) -> String {
+    let name = ident.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name).trim_end_matches('_');
+    let mut ret = String::with_capacity(name.len());
+    for (i, piece) in name.split('_').enumerate() {
+        let mut chars = piece.chars();
+        if let Some(first) = chars.next() {
+            if i == 0 {
+                ret.push(first);
+            } else {
+                ret.extend(first.to_uppercase());
+            }
+            ret.extend(chars);
+        }
+    }
+    ret
+}
+
+
+/// The default ASN.1 name of a variant: its name in camel case, e.g. `OpenTicket` becomes
+/// `openTicket`.
+pub(crate) fn asn1_name_of_variant(ident: &Ident) -> String {
+    let name = ident.to_string();
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => name,
+    }
+}
@@ -0,0 +1,472 @@
+//! Derivation for structs, which correspond to ASN.1 SEQUENCE types.
+
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataStruct, Expr, ExprLit, ExprUnary, Fields, Ident, Lit, UnOp};
+
+use crate::asn1_name_of_field;
+use crate::attr::MemberAttrs;
+use crate::value::{option_inner, ValueKind};
+
+
+/// How the presence of a member is encoded.
+enum Presence {
+    /// The member is always present.
+    Required,
+
+    /// The member is an `Option` that is `None` if the member is absent.
+    Optional,
+
+    /// The member is a `Vec` that is empty if the member is absent.
+    OptionalEmpty,
+
+    /// The member takes the given value if it is absent.
+    Default(Expr),
+}
+
+
+struct Member {
+    ident: Ident,
+    name: String,
+    presence: Presence,
+    kind: ValueKind,
+
+    /// The field of another member which must not be present at the same time as this member.
+    exclusive_with: Option<Ident>,
+}
+impl Member {
+    /// An expression checking whether an optional member is present; `None` if the member is always
+    /// present.
+    fn is_present_expr(&self) -> Option<TokenStream> {
+        let ident = &self.ident;
+        match self.presence {
+            Presence::Optional => Some(quote! { self.#ident.is_some() }),
+            Presence::OptionalEmpty => Some(quote! { !self.#ident.is_empty() }),
+            Presence::Required | Presence::Default(_) => None,
+        }
+    }
+
+    /// An expression decoding the member from `rest`, returning a `ParseResult`.
+    fn traced_decode_expr(&self) -> TokenStream {
+        let name = &self.name;
+        let decode_expr = self.kind.decode_expr();
+        quote! {
+            crate::asn1_uper::trace::field(#name, rest, |rest| #decode_expr)
+        }
+    }
+}
+
+
+/// The members of a struct, sorted into the root members and the extension additions.
+pub(crate) struct Sequence {
+    root: Vec<Member>,
+    additions: Vec<Member>,
+    unknown: Option<Ident>,
+}
+impl Sequence {
+    pub fn new(data: &DataStruct) -> syn::Result<Self> {
+        let Fields::Named(fields) = &data.fields else {
+            return Err(syn::Error::new_spanned(&data.fields, "only structs with named fields are supported"));
+        };
+
+        let mut root = Vec::new();
+        let mut additions = Vec::new();
+        let mut unknown = None;
+        for field in &fields.named {
+            let attrs = MemberAttrs::parse(&field.attrs)?;
+            let ident = field.ident.clone().expect("named field without a name");
+            if attrs.unknown {
+                unknown = Some(ident);
+                continue;
+            }
+
+            let (presence, value_type) = if let Some(inner_type) = option_inner(&field.ty) {
+                (Presence::Optional, inner_type)
+            } else if attrs.optional || attrs.extension {
+                if !matches!(ValueKind::new(&field.ty, &attrs.value)?, ValueKind::SequenceOf { .. } | ValueKind::OctetString(_)) {
+                    return Err(syn::Error::new_spanned(&field.ty, "an optional member must be an Option or a Vec"));
+                }
+                (Presence::OptionalEmpty, &field.ty)
+            } else if let Some(default) = &attrs.default {
+                (Presence::Default(default.clone()), &field.ty)
+            } else {
+                (Presence::Required, &field.ty)
+            };
+            let member = Member {
+                name: attrs.name.unwrap_or_else(|| asn1_name_of_field(&ident)),
+                ident,
+                presence,
+                kind: ValueKind::new(value_type, &attrs.value)?,
+                exclusive_with: attrs.exclusive_with,
+            };
+            if attrs.extension {
+                additions.push(member);
+            } else {
+                root.push(member);
+            }
+        }
+
+        if unknown.is_none() && !additions.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &data.fields,
+                "extension additions require a field marked #[uper(unknown)] for unknown extensions",
+            ));
+        }
+        let sequence = Self { root, additions, unknown };
+        for member in sequence.members() {
+            let Some(other_ident) = &member.exclusive_with else { continue };
+            let other = sequence.members()
+                .find(|other| &other.ident == other_ident)
+                .ok_or_else(|| syn::Error::new_spanned(other_ident, "no such member"))?;
+            if member.is_present_expr().is_none() || other.is_present_expr().is_none() {
+                return Err(syn::Error::new_spanned(other_ident, "mutually exclusive members must both be optional"));
+            }
+        }
+        Ok(sequence)
+    }
+
+    fn members(&self) -> impl Iterator<Item = &Member> {
+        self.root.iter().chain(self.additions.iter())
+    }
+
+    pub fn decode_body(&self) -> TokenStream {
+        let mut body = TokenStream::new();
+        if self.unknown.is_some() {
+            body.extend(quote! {
+                let (rest, is_extended) = crate::asn1_uper::decode_bool(rest)?;
+            });
+        }
+        let optional_count = self.root.iter()
+            .filter(|member| !matches!(member.presence, Presence::Required))
+            .count();
+        if optional_count > 0 {
+            body.extend(quote! {
+                let (rest, optional_bits) = crate::asn1_uper::decode_bools(rest, #optional_count)?;
+            });
+        }
+
+        let mut optional_index: usize = 0;
+        for member in &self.root {
+            let ident = &member.ident;
+            let decode_expr = member.traced_decode_expr();
+            let (present, absent) = match &member.presence {
+                Presence::Required => {
+                    body.extend(quote! {
+                        let (rest, #ident) = #decode_expr?;
+                    });
+                    continue;
+                },
+                Presence::Optional => (
+                    quote! {
+                        let (rest, value) = #decode_expr?;
+                        (rest, Some(value))
+                    },
+                    quote! { (rest, None) },
+                ),
+                Presence::OptionalEmpty => (quote! { #decode_expr? }, quote! { (rest, Vec::new()) }),
+                Presence::Default(default) => {
+                    let default = default_value(default);
+                    (quote! { #decode_expr? }, quote! { (rest, #default) })
+                },
+            };
+            body.extend(quote! {
+                let (rest, #ident) = if optional_bits.bit(#optional_index) {
+                    #present
+                } else {
+                    #absent
+                };
+            });
+            optional_index += 1;
+        }
+
+        let mut field_idents: Vec<&Ident> = self.root.iter()
+            .map(|member| &member.ident)
+            .collect();
+        if let Some(unknown) = &self.unknown {
+            body.extend(quote! {
+                let (rest, extension_additions) = if is_extended {
+                    crate::asn1_uper::decode_extension_additions(rest)?
+                } else {
+                    (rest, Vec::new())
+                };
+            });
+            for (index, member) in self.additions.iter().enumerate() {
+                let ident = &member.ident;
+                let decode_expr = member.traced_decode_expr();
+                let (present, absent) = match member.presence {
+                    Presence::OptionalEmpty => (quote! { value }, quote! { Vec::new() }),
+                    _ => (quote! { Some(value) }, quote! { None }),
+                };
+                body.extend(quote! {
+                    let #ident = match extension_additions.get(#index) {
+                        Some(Some(contents)) => {
+                            let value = crate::asn1_uper::decode_open_type_contents(rest, contents, |rest| #decode_expr)?;
+                            #present
+                        },
+                        _ => #absent,
+                    };
+                });
+                field_idents.push(ident);
+            }
+            let addition_count = self.additions.len();
+            body.extend(quote! {
+                let #unknown = crate::asn1_uper::UnknownExtensions::from_additions(extension_additions, #addition_count);
+            });
+            field_idents.push(unknown);
+        }
+
+        body.extend(quote! {
+            let sequence = Self {
+                #( #field_idents, )*
+            };
+            Ok((rest, sequence))
+        });
+        body
+    }
+
+    pub fn encode_body(&self) -> TokenStream {
+        let mut body = TokenStream::new();
+        if let Some(unknown) = &self.unknown {
+            body.extend(quote! {
+                let mut extension_additions = Vec::new();
+            });
+            for member in &self.additions {
+                let ident = &member.ident;
+                let encode_stmt = member.kind.encode_stmt(&quote! { value });
+                let contents = quote! {
+                    Some(crate::asn1_uper::encode_open_type_contents(|uper_buf| {
+                        #encode_stmt
+                        Ok(())
+                    })?)
+                };
+                body.extend(match member.presence {
+                    Presence::OptionalEmpty => quote! {
+                        extension_additions.push(if !self.#ident.is_empty() {
+                            let value = &self.#ident;
+                            #contents
+                        } else {
+                            None
+                        });
+                    },
+                    _ => quote! {
+                        extension_additions.push(if let Some(value) = &self.#ident {
+                            #contents
+                        } else {
+                            None
+                        });
+                    },
+                });
+            }
+            body.extend(quote! {
+                self.#unknown.append_to(&mut extension_additions);
+                let is_extended = !self.#unknown.is_empty() || extension_additions.iter().any(|addition| addition.is_some());
+                crate::asn1_uper::encode_bool(uper_buf, is_extended);
+            });
+        }
+
+        for member in &self.root {
+            let ident = &member.ident;
+            body.extend(match &member.presence {
+                Presence::Required => quote! {},
+                Presence::Optional => quote! {
+                    crate::asn1_uper::encode_bool(uper_buf, self.#ident.is_some());
+                },
+                Presence::OptionalEmpty => quote! {
+                    crate::asn1_uper::encode_bool(uper_buf, !self.#ident.is_empty());
+                },
+                Presence::Default(default) => {
+                    let is_default = is_default_expr(&quote! { self.#ident }, default);
+                    quote! {
+                        crate::asn1_uper::encode_bool(uper_buf, !(#is_default));
+                    }
+                },
+            });
+        }
+        for member in &self.root {
+            let ident = &member.ident;
+            let encode_stmt = member.kind.encode_stmt(&quote! { value });
+            body.extend(match &member.presence {
+                Presence::Required => quote! {
+                    let value = &self.#ident;
+                    #encode_stmt
+                },
+                Presence::Optional => quote! {
+                    if let Some(value) = &self.#ident {
+                        #encode_stmt
+                    }
+                },
+                Presence::OptionalEmpty => quote! {
+                    if !self.#ident.is_empty() {
+                        let value = &self.#ident;
+                        #encode_stmt
+                    }
+                },
+                Presence::Default(default) => {
+                    let is_default = is_default_expr(&quote! { self.#ident }, default);
+                    quote! {
+                        if !(#is_default) {
+                            let value = &self.#ident;
+                            #encode_stmt
+                        }
+                    }
+                },
+            });
+        }
+
+        if self.unknown.is_some() {
+            body.extend(quote! {
+                if is_extended {
+                    crate::asn1_uper::encode_extension_additions(uper_buf, &extension_additions)?;
+                }
+            });
+        }
+        body.extend(quote! {
+            Ok(())
+        });
+        body
+    }
+
+    pub fn validate_body(&self) -> TokenStream {
+        let mut body = TokenStream::new();
+        for member in self.members() {
+            let ident = &member.ident;
+            let name = &member.name;
+            if let Some(other_ident) = &member.exclusive_with {
+                let other = self.members()
+                    .find(|other| &other.ident == other_ident)
+                    .expect("mutually exclusive member has been checked");
+                let other_name = &other.name;
+                let is_present = member.is_present_expr();
+                let other_is_present = other.is_present_expr();
+                body.extend(quote! {
+                    if #is_present && #other_is_present {
+                        path.push(crate::asn1_uper::trace::PathSegment::Member(#name));
+                        violations.push(crate::asn1_uper::validate::ConstraintViolation::new(
+                            path,
+                            crate::asn1_uper::validate::ViolationKind::MutuallyExclusive { other: #other_name },
+                        ));
+                        path.pop();
+                    }
+                });
+            }
+
+            let Some(validate_stmts) = member.kind.validate_stmts(&quote! { value }) else { continue };
+            let validate_stmts = match &member.presence {
+                Presence::Required | Presence::Default(_) => quote! {
+                    let value = &self.#ident;
+                    #validate_stmts
+                },
+                Presence::Optional => quote! {
+                    if let Some(value) = &self.#ident {
+                        #validate_stmts
+                    }
+                },
+                Presence::OptionalEmpty => quote! {
+                    if !self.#ident.is_empty() {
+                        let value = &self.#ident;
+                        #validate_stmts
+                    }
+                },
+            };
+            body.extend(quote! {
+                path.push(crate::asn1_uper::trace::PathSegment::Member(#name));
+                #validate_stmts
+                path.pop();
+            });
+        }
+        if body.is_empty() {
+            body.extend(quote! {
+                let _ = (path, violations);
+            });
+        }
+        body
+    }
+
+    pub fn visit_body(&self, mutable: bool) -> TokenStream {
+        let visit_unknown_stmt = match &self.unknown {
+            Some(unknown) if !mutable => {
+                let addition_count = self.additions.len();
+                Some(quote! {
+                    for (offset, addition) in self.#unknown.0.iter().enumerate() {
+                        if let Some(contents) = addition {
+                            visitor.visit_unknown(path, crate::asn1_uper::visit::Unknown::Addition {
+                                index: #addition_count + offset,
+                                contents,
+                            });
+                        }
+                    }
+                })
+            },
+            _ => None,
+        };
+        if self.root.is_empty() && self.additions.is_empty() && visit_unknown_stmt.is_none() {
+            return quote! {
+                let _ = (path, visitor);
+            };
+        }
+
+        let reference = if mutable { quote! { &mut } } else { quote! { & } };
+        let mut body = TokenStream::new();
+        for member in self.members() {
+            let ident = &member.ident;
+            let name = &member.name;
+            let visit_stmt = member.kind.visit_stmt(&quote! { value }, mutable);
+            let visit_stmt = match &member.presence {
+                Presence::Required | Presence::Default(_) => quote! {
+                    let value = #reference self.#ident;
+                    #visit_stmt
+                },
+                Presence::Optional => quote! {
+                    if let Some(value) = #reference self.#ident {
+                        #visit_stmt
+                    }
+                },
+                Presence::OptionalEmpty => quote! {
+                    if !self.#ident.is_empty() {
+                        let value = #reference self.#ident;
+                        #visit_stmt
+                    }
+                },
+            };
+            body.extend(quote! {
+                path.push(crate::asn1_uper::trace::PathSegment::Member(#name));
+                #visit_stmt
+                path.pop();
+            });
+        }
+        body.extend(visit_unknown_stmt);
+        body
+    }
+}
+
+
+/// The value of a DEFAULT attribute: integer literals become an `Integer` and string literals a
+/// `String`; any other expression is taken verbatim.
+fn default_value(default: &Expr) -> TokenStream {
+    match default {
+        Expr::Lit(ExprLit { lit: Lit::Int(_), .. })
+        | Expr::Unary(ExprUnary { op: UnOp::Neg(_), .. }) => quote! {
+            crate::asn1_uper::Integer::from_short(#default)
+        },
+        Expr::Lit(ExprLit { lit: Lit::Str(_), .. }) => quote! {
+            String::from(#default)
+        },
+        _ => quote! { #default },
+    }
+}
+
+
+/// An expression checking whether `value` equals the value of a DEFAULT attribute, comparing
+/// strings without allocating.
+fn is_default_expr(value: &TokenStream, default: &Expr) -> TokenStream {
+    match default {
+        Expr::Lit(ExprLit { lit: Lit::Str(_), .. }) => quote! {
+            #value.as_str() == #default
+        },
+        _ => {
+            let default = default_value(default);
+            quote! { #value == #default }
+        },
+    }
+}
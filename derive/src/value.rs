@@ -0,0 +1,306 @@
+//! Decoding and encoding of single values, depending on their ASN.1 type.
+
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{GenericArgument, PathArguments, Type};
+
+use crate::attr::{Bounds, StringKind, ValueAttrs};
+
+
+/// The ASN.1 type of a value.
+pub(crate) enum ValueKind {
+    Integer(TokenStream),
+    Boolean,
+    OctetString(TokenStream),
+    Ia5String(TokenStream),
+    Utf8String(TokenStream),
+    BitString { size: TokenStream, named_bits: bool },
+    ObjectIdentifier,
+    Null,
+    SequenceOf { size: TokenStream, element: Box<ValueKind> },
+
+    /// A type with its own `try_from_uper` and `write_uper` functions.
+    Other(Type),
+}
+impl ValueKind {
+    /// Derives the ASN.1 type from the Rust type and the attributes.
+    pub fn new(ty: &Type, attrs: &ValueAttrs) -> syn::Result<Self> {
+        let (type_name, argument) = match last_segment(ty) {
+            Some(segment) => segment,
+            None => return Ok(Self::Other(ty.clone())),
+        };
+        let kind = match type_name.as_str() {
+            "Integer" => Self::Integer(range_constraint(attrs.range)),
+            "bool" => Self::Boolean,
+            "String" => match attrs.string {
+                Some(StringKind::Ia5) => Self::Ia5String(size_constraint(attrs.size)),
+                Some(StringKind::Utf8) => Self::Utf8String(size_constraint(attrs.size)),
+                None => return Err(syn::Error::new_spanned(ty, "a String requires #[uper(ia5)] or #[uper(utf8)]")),
+            },
+            "BitVec" => Self::BitString { size: size_constraint(attrs.size), named_bits: attrs.named_bits },
+            "ObjectIdentifier" => Self::ObjectIdentifier,
+            "Null" => Self::Null,
+            "Vec" => {
+                let element_type = argument
+                    .ok_or_else(|| syn::Error::new_spanned(ty, "expected the element type of the Vec"))?;
+                if last_segment(element_type).is_some_and(|(name, _)| name == "u8") {
+                    Self::OctetString(size_constraint(attrs.size))
+                } else {
+                    let element_attrs = attrs.element.as_deref().cloned().unwrap_or_default();
+                    Self::SequenceOf {
+                        size: size_constraint(attrs.size),
+                        element: Box::new(Self::new(element_type, &element_attrs)?),
+                    }
+                }
+            },
+            _ => Self::Other(ty.clone()),
+        };
+        Ok(kind)
+    }
+
+    /// An expression decoding the value from `rest`, returning a `ParseResult`.
+    pub fn decode_expr(&self) -> TokenStream {
+        match self {
+            Self::Integer(constraint) => quote! {
+                crate::asn1_uper::decode_integer(rest, &#constraint)
+            },
+            Self::Boolean => quote! {
+                crate::asn1_uper::decode_bool(rest)
+            },
+            Self::OctetString(size) => quote! {
+                crate::asn1_uper::decode_octet_string(rest, &#size)
+            },
+            Self::Ia5String(size) => quote! {
+                {
+                    let (rest, octet_string) = crate::asn1_uper::decode_ia5_string(rest, &#size)?;
+                    crate::asn1_uper::octet_string_to_utf8(rest, octet_string)
+                }
+            },
+            Self::Utf8String(size) => quote! {
+                {
+                    let (rest, octet_string) = crate::asn1_uper::decode_octet_string(rest, &#size)?;
+                    crate::asn1_uper::octet_string_to_utf8(rest, octet_string)
+                }
+            },
+            Self::BitString { size, .. } => quote! {
+                crate::asn1_uper::decode_bit_string(rest, &#size)
+            },
+            Self::ObjectIdentifier => quote! {
+                crate::asn1_uper::decode_object_identifier(rest)
+            },
+            Self::Null => quote! {
+                crate::asn1_uper::decode_null(rest)
+            },
+            Self::SequenceOf { size, element } => {
+                let element_expr = element.decode_expr();
+                quote! {
+                    crate::asn1_uper::decode_fragmented(rest, &#size, |rest| #element_expr)
+                }
+            },
+            Self::Other(ty) => quote! {
+                <#ty>::try_from_uper(rest)
+            },
+        }
+    }
+
+    /// A statement encoding the value referenced by `value` into `uper_buf`.
+    pub fn encode_stmt(&self, value: &TokenStream) -> TokenStream {
+        match self {
+            Self::Integer(constraint) => quote! {
+                crate::asn1_uper::encode_integer(uper_buf, &#constraint, #value)?;
+            },
+            Self::Boolean => quote! {
+                crate::asn1_uper::encode_bool(uper_buf, *#value);
+            },
+            Self::OctetString(size) => quote! {
+                crate::asn1_uper::encode_octet_string(uper_buf, &#size, #value)?;
+            },
+            Self::Ia5String(size) => quote! {
+                crate::asn1_uper::encode_ia5_string(uper_buf, &#size, #value)?;
+            },
+            Self::Utf8String(size) => quote! {
+                crate::asn1_uper::encode_octet_string(uper_buf, &#size, #value.as_bytes())?;
+            },
+            Self::BitString { size, named_bits: false } => quote! {
+                crate::asn1_uper::encode_bit_string(uper_buf, &#size, #value)?;
+            },
+            Self::BitString { size, named_bits: true } => quote! {
+                crate::asn1_uper::encode_named_bit_string(uper_buf, &#size, #value)?;
+            },
+            Self::ObjectIdentifier => quote! {
+                crate::asn1_uper::encode_object_identifier(uper_buf, #value)?;
+            },
+            Self::Null => quote! {
+                crate::asn1_uper::encode_null(uper_buf, #value);
+            },
+            Self::SequenceOf { size, element } => {
+                let element_stmt = element.encode_stmt(&quote! { item });
+                quote! {
+                    crate::asn1_uper::encode_fragmented(uper_buf, &#size, #value, |uper_buf, item| {
+                        #element_stmt
+                        Ok(())
+                    })?;
+                }
+            },
+            Self::Other(_) => quote! {
+                #value.write_uper(uper_buf)?;
+            },
+        }
+    }
+
+    /// Statements checking the value referenced by `value` against its constraints, appending each
+    /// violation to `violations`. The path to the value is in `path`. `None` if the value cannot
+    /// violate any constraints.
+    pub fn validate_stmts(&self, value: &TokenStream) -> Option<TokenStream> {
+        let stmts = match self {
+            Self::Integer(constraint) => quote! {
+                crate::asn1_uper::validate::check_integer(path, violations, &#constraint, #value);
+            },
+            Self::Boolean | Self::ObjectIdentifier | Self::Null => return None,
+            Self::OctetString(size) | Self::Utf8String(size) => quote! {
+                crate::asn1_uper::validate::check_length(path, violations, &#size, #value.len());
+            },
+            Self::Ia5String(size) => quote! {
+                crate::asn1_uper::validate::check_length(path, violations, &#size, #value.len());
+                crate::asn1_uper::validate::check_ia5(path, violations, #value);
+            },
+            Self::BitString { size, named_bits: false } => quote! {
+                crate::asn1_uper::validate::check_length(path, violations, &#size, #value.len());
+            },
+            Self::BitString { size, named_bits: true } => quote! {
+                crate::asn1_uper::validate::check_length(path, violations, &#size, crate::asn1_uper::named_bit_string_length(&#size, #value));
+            },
+            Self::SequenceOf { size, element } => {
+                let element_stmts = element.validate_stmts(&quote! { item }).map(|element_stmts| quote! {
+                    for (index, item) in #value.iter().enumerate() {
+                        path.push(crate::asn1_uper::trace::PathSegment::Index(index));
+                        #element_stmts
+                        path.pop();
+                    }
+                });
+                quote! {
+                    crate::asn1_uper::validate::check_length(path, violations, &#size, #value.len());
+                    #element_stmts
+                }
+            },
+            Self::Other(_) => quote! {
+                #value.validate_at(path, violations);
+            },
+        };
+        Some(stmts)
+    }
+
+    /// A statement passing the value referenced by `value` to `visitor`, which is a `Visitor` or,
+    /// if `mutable` is set, a `VisitorMut`. The path to the value is in `path`.
+    pub fn visit_stmt(&self, value: &TokenStream, mutable: bool) -> TokenStream {
+        let (value_type, visit_fn) = if mutable {
+            (quote! { crate::asn1_uper::visit::ValueMut }, quote! { visit_mut })
+        } else {
+            (quote! { crate::asn1_uper::visit::Value }, quote! { visit })
+        };
+        let primitive = match self {
+            Self::Integer(_) => quote! { Integer(#value) },
+            Self::Boolean if mutable => quote! { Boolean(#value) },
+            Self::Boolean => quote! { Boolean(*#value) },
+            Self::OctetString(_) => quote! { OctetString(#value) },
+            Self::Ia5String(_) | Self::Utf8String(_) => quote! { String(#value) },
+            Self::BitString { .. } => quote! { BitString(#value) },
+            Self::ObjectIdentifier => quote! { ObjectIdentifier(#value) },
+            Self::Null => return quote! {
+                let _ = #value;
+                visitor.#visit_fn(path, #value_type::Null);
+            },
+            Self::SequenceOf { element, .. } => {
+                let iter_fn = if mutable { quote! { iter_mut } } else { quote! { iter } };
+                let element_stmt = element.visit_stmt(&quote! { item }, mutable);
+                return quote! {
+                    for (index, item) in #value.#iter_fn().enumerate() {
+                        path.push(crate::asn1_uper::trace::PathSegment::Index(index));
+                        #element_stmt
+                        path.pop();
+                    }
+                };
+            },
+            Self::Other(_) => {
+                let visit_at_fn = if mutable { quote! { visit_mut_at } } else { quote! { visit_at } };
+                return quote! {
+                    crate::asn1_uper::visit::Visit::#visit_at_fn(#value, path, visitor);
+                };
+            },
+        };
+        quote! {
+            visitor.#visit_fn(path, #value_type::#primitive);
+        }
+    }
+}
+
+
+/// The name of the last segment of a path type, along with its first generic type argument.
+fn last_segment(ty: &Type) -> Option<(String, Option<&Type>)> {
+    let Type::Path(type_path) = ty else { return None };
+    let segment = type_path.path.segments.last()?;
+    let argument = match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => arguments.args.iter()
+            .find_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }),
+        _ => None,
+    };
+    Some((segment.ident.to_string(), argument))
+}
+
+
+/// If the type is an `Option`, the type of its value.
+pub(crate) fn option_inner(ty: &Type) -> Option<&Type> {
+    match last_segment(ty) {
+        Some((name, argument)) if name == "Option" => argument,
+        _ => None,
+    }
+}
+
+
+/// The constraint of an INTEGER with the given bounds.
+///
+/// An upper bound without a lower bound does not constrain the encoding (X.691 § 12.2.4).
+fn range_constraint(bounds: Bounds) -> TokenStream {
+    match (bounds.min, bounds.max) {
+        (Some(min), Some(max)) => constrained(min, max),
+        (Some(min), None) => quote! {
+            crate::asn1_uper::WholeNumberConstraint::SemiConstrained { min: crate::asn1_uper::Integer::from_short(#min) }
+        },
+        (None, _) => quote! {
+            crate::asn1_uper::WholeNumberConstraint::Unconstrained
+        },
+    }
+}
+
+
+/// The constraint of the length of a value with the given SIZE bounds.
+///
+/// Without an upper bound, the length is encoded as if unconstrained (X.691 § 11.9.4.2).
+fn size_constraint(bounds: Bounds) -> TokenStream {
+    match bounds.max {
+        Some(max) => constrained(bounds.min.unwrap_or(0), max),
+        None => quote! {
+            crate::asn1_uper::WholeNumberConstraint::Unconstrained
+        },
+    }
+}
+
+
+/// The constraint of an index between 0 and `count - 1`, used for ENUMERATED and CHOICE types.
+pub(crate) fn index_constraint(count: usize) -> TokenStream {
+    constrained(0, (count - 1) as i128)
+}
+
+
+fn constrained(min: i128, max: i128) -> TokenStream {
+    quote! {
+        crate::asn1_uper::WholeNumberConstraint::Constrained {
+            min: crate::asn1_uper::Integer::from_short(#min),
+            max: crate::asn1_uper::Integer::from_short(#max),
+        }
+    }
+}
@@ -0,0 +1,429 @@
+//! Packed bit strings.
+//!
+//! PER UNALIGNED encodings are not aligned to octet boundaries. The codec therefore reads from a
+//! [`BitSlice`], which refers to a range of bits within a slice of bytes, and writes into a
+//! [`BitVec`], which appends bits to a vector of bytes. In both cases, the bits of each byte are
+//! ordered from most to least significant.
+
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error as _;
+
+
+/// A read-only view of a sequence of bits stored packed in a slice of bytes.
+///
+/// Splitting the view is cheap, which makes it suitable as the input of the decoding functions.
+#[derive(Clone, Copy)]
+pub struct BitSlice<'a> {
+    bytes: &'a [u8],
+    start: usize,
+    end: usize,
+}
+impl<'a> BitSlice<'a> {
+    /// Creates a view of all the bits of the given bytes.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            start: 0,
+            end: bytes.len() * 8,
+        }
+    }
+
+    /// Creates a view containing no bits.
+    pub const fn empty() -> Self {
+        Self {
+            bytes: &[],
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// The number of bits in the view.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Whether the view contains no bits.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the bit at the given index.
+    ///
+    /// Panics if the index is out of range.
+    pub fn bit(&self, index: usize) -> bool {
+        assert!(index < self.len(), "bit index {} out of range for length {}", index, self.len());
+        let position = self.start + index;
+        self.bytes[position / 8] & (0b1000_0000 >> (position % 8)) != 0
+    }
+
+    /// Splits the view into the bits before and the bits starting at the given index.
+    ///
+    /// Panics if the index is greater than the length.
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        assert!(mid <= self.len(), "split index {} out of range for length {}", mid, self.len());
+        let split = self.start + mid;
+        (
+            Self { bytes: self.bytes, start: self.start, end: split },
+            Self { bytes: self.bytes, start: split, end: self.end },
+        )
+    }
+
+    /// Returns an iterator over the bits in the view.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + 'a {
+        let bytes = self.bytes;
+        (self.start..self.end)
+            .map(move |position| bytes[position / 8] & (0b1000_0000 >> (position % 8)) != 0)
+    }
+
+    /// Interprets the bits as an unsigned integer, most significant bit first.
+    ///
+    /// Panics if the view contains more than 64 bits.
+    pub fn read_u64(&self) -> u64 {
+        assert!(self.len() <= 64, "cannot read {} bits into a u64", self.len());
+        let mut value = 0u64;
+        let mut position = self.start;
+        while position < self.end {
+            // take as many bits as possible from the current byte
+            let bits_in_byte = 8 - position % 8;
+            let take = bits_in_byte.min(self.end - position);
+            let byte = u64::from(self.bytes[position / 8]);
+            let chunk = (byte >> (bits_in_byte - take)) & ((1 << take) - 1);
+            value = (value << take) | chunk;
+            position += take;
+        }
+        value
+    }
+
+    /// Returns the bits packed into bytes. If the number of bits is not divisible by 8, the final
+    /// byte is padded with zero bits.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        if self.start.is_multiple_of(8) {
+            // aligned; copy the bytes wholesale
+            let mut ret = self.bytes[self.start/8..self.end.div_ceil(8)].to_vec();
+            let trailing_bits = self.end % 8;
+            if let Some(last) = ret.last_mut() {
+                if trailing_bits != 0 {
+                    *last &= 0xFF << (8 - trailing_bits);
+                }
+            }
+            return ret;
+        }
+
+        let mut ret = Vec::with_capacity(self.len().div_ceil(8));
+        let mut rest = *self;
+        while rest.len() >= 64 {
+            let (chunk, new_rest) = rest.split_at(64);
+            ret.extend_from_slice(&chunk.read_u64().to_be_bytes());
+            rest = new_rest;
+        }
+        while !rest.is_empty() {
+            let take = rest.len().min(8);
+            let (chunk, new_rest) = rest.split_at(take);
+            ret.push((chunk.read_u64() << (8 - take)) as u8);
+            rest = new_rest;
+        }
+        ret
+    }
+
+    /// Returns the bits packed into bytes. If the number of bits is not divisible by 8, the first
+    /// byte is padded with `fill` bits.
+    pub(crate) fn to_right_aligned_bytes(self, fill: bool) -> Vec<u8> {
+        let padding = (8 - self.len() % 8) % 8;
+        let mut ret = BitVec::with_capacity(padding + self.len());
+        for _ in 0..padding {
+            ret.push(fill);
+        }
+        ret.extend_from_slice(self);
+        ret.into_bytes()
+    }
+
+    /// Copies the bits into a new bit vector.
+    pub fn to_bit_vec(&self) -> BitVec {
+        let mut ret = BitVec::with_capacity(self.len());
+        ret.extend_from_slice(*self);
+        ret
+    }
+
+    /// The number of bits from the start of the view up to the next octet boundary of the
+    /// underlying bytes; zero if the view starts at an octet boundary.
+    pub(crate) fn padding_to_octet(&self) -> usize {
+        (8 - self.start % 8) % 8
+    }
+
+    /// The address of the first bit of the view in memory, counted in bits.
+    ///
+    /// Views of the same buffer can be located relative to each other using this address.
+    pub(crate) fn bit_address(&self) -> usize {
+        (self.bytes.as_ptr() as usize).wrapping_mul(8).wrapping_add(self.start)
+    }
+
+    /// Returns the offset of the start of this view relative to the start of `outer`, or `None` if
+    /// this view is not a suffix of `outer`.
+    pub(crate) fn offset_within(&self, outer: &BitSlice<'_>) -> Option<usize> {
+        let start = self.bit_address();
+        let outer_start = outer.bit_address();
+        let is_suffix = start >= outer_start
+            && start.wrapping_add(self.len()) == outer_start.wrapping_add(outer.len());
+        if is_suffix {
+            Some(start - outer_start)
+        } else {
+            None
+        }
+    }
+}
+impl<'a> Default for BitSlice<'a> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+impl<'a> fmt::Debug for BitSlice<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BitSlice(")?;
+        for bit in self.iter() {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+        write!(f, ")")
+    }
+}
+impl<'a, 'b> PartialEq<BitSlice<'b>> for BitSlice<'a> {
+    fn eq(&self, other: &BitSlice<'b>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+impl<'a> Eq for BitSlice<'a> {
+}
+impl<'a> PartialOrd for BitSlice<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<'a> Ord for BitSlice<'a> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+impl<'a> Hash for BitSlice<'a> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for bit in self.iter() {
+            bit.hash(state);
+        }
+    }
+}
+
+
+/// A growable sequence of bits stored packed in a vector of bytes.
+///
+/// Bits are appended at the end, which makes it suitable as the output of the encoding functions.
+/// Unused bits of the final byte are always zero.
+#[derive(Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BitVec {
+    bytes: Vec<u8>,
+    len: usize,
+}
+impl BitVec {
+    /// Creates an empty bit vector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty bit vector with space for at least the given number of bits.
+    pub fn with_capacity(bit_capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(bit_capacity.div_ceil(8)),
+            len: 0,
+        }
+    }
+
+    /// The number of bits in the vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the vector contains no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a bit.
+    pub fn push(&mut self, bit: bool) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if bit {
+            let last = self.bytes.len() - 1;
+            self.bytes[last] |= 0b1000_0000 >> (self.len % 8);
+        }
+        self.len += 1;
+    }
+
+    /// Appends the lowest `count` bits of `value`, most significant bit first.
+    ///
+    /// Panics if `count` is greater than 64.
+    pub fn push_bits(&mut self, value: u64, count: usize) {
+        assert!(count <= 64, "cannot push {} bits from a u64", count);
+        let mut remaining = count;
+        while remaining > 0 {
+            // fill up the current byte as far as possible
+            let used_bits = self.len % 8;
+            if used_bits == 0 {
+                self.bytes.push(0);
+            }
+            let free_bits = 8 - used_bits;
+            let take = free_bits.min(remaining);
+            let chunk = ((value >> (remaining - take)) & ((1 << take) - 1)) as u8;
+            let last = self.bytes.len() - 1;
+            self.bytes[last] |= chunk << (free_bits - take);
+            self.len += take;
+            remaining -= take;
+        }
+    }
+
+    /// Appends the given bits.
+    pub fn extend_from_slice(&mut self, bits: BitSlice<'_>) {
+        if self.len.is_multiple_of(8) && bits.start.is_multiple_of(8) {
+            // aligned; copy the bytes wholesale
+            self.bytes.extend_from_slice(&bits.to_bytes());
+            self.len += bits.len();
+            return;
+        }
+
+        let mut rest = bits;
+        while !rest.is_empty() {
+            let take = rest.len().min(64);
+            let (chunk, new_rest) = rest.split_at(take);
+            self.push_bits(chunk.read_u64(), take);
+            rest = new_rest;
+        }
+    }
+
+    /// Returns a view of the bits in the vector.
+    pub fn as_slice(&self) -> BitSlice<'_> {
+        BitSlice {
+            bytes: &self.bytes,
+            start: 0,
+            end: self.len,
+        }
+    }
+
+    /// Returns an iterator over the bits in the vector.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        self.as_slice().iter()
+    }
+
+    /// Returns the bits packed into bytes. If the number of bits is not divisible by 8, the final
+    /// byte is padded with zero bits.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Converts the vector into the bits packed into bytes. If the number of bits is not divisible
+    /// by 8, the final byte is padded with zero bits.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+impl fmt::Debug for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BitVec(")?;
+        for bit in self.iter() {
+            f.write_str(if bit { "1" } else { "0" })?;
+        }
+        write!(f, ")")
+    }
+}
+impl Serialize for BitVec {
+    /// Serializes the bit vector as a string of `0` and `1` characters.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let as_string: String = self.iter()
+            .map(|bit| if bit { '1' } else { '0' })
+            .collect();
+        as_string.serialize(serializer)
+    }
+}
+impl<'de> Deserialize<'de> for BitVec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        string.chars()
+            .map(|c| match c {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                other => Err(D::Error::custom(format!("invalid character {:?} in bit string", other))),
+            })
+            .collect()
+    }
+}
+impl Extend<bool> for BitVec {
+    fn extend<T: IntoIterator<Item = bool>>(&mut self, iter: T) {
+        for bit in iter {
+            self.push(bit);
+        }
+    }
+}
+impl FromIterator<bool> for BitVec {
+    fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_slice() {
+        let bytes = [0b1011_0010, 0b0111_1100, 0xA5];
+        let bits = BitSlice::new(&bytes);
+        assert_eq!(bits.len(), 24);
+        assert!(bits.bit(0));
+        assert!(!bits.bit(1));
+        assert!(bits.bit(9));
+
+        let (head, tail) = bits.split_at(3);
+        assert_eq!(head.read_u64(), 0b101);
+        assert_eq!(tail.len(), 21);
+        let (middle, tail) = tail.split_at(10);
+        assert_eq!(middle.read_u64(), 0b10_0100_1111);
+        assert_eq!(tail.read_u64(), 0b100_1010_0101);
+        assert_eq!(middle.iter().collect::<Vec<bool>>(), [true, false, false, true, false, false, true, true, true, true]);
+
+        // unaligned bytes are shifted and the final byte is padded
+        assert_eq!(bits.split_at(3).1.to_bytes(), [0b1001_0011, 0b1110_0101, 0b0010_1000]);
+        assert_eq!(bits.split_at(8).1.split_at(4).0.to_bytes(), [0b0111_0000]);
+        assert_eq!(bits.split_at(3).0.to_right_aligned_bytes(true), [0b1111_1101]);
+
+        // equality depends on the bits, not on their position
+        let shifted = [0b0101_1001, 0b0000_0000];
+        assert_eq!(BitSlice::new(&shifted).split_at(1).1.split_at(8).0, bits.split_at(8).0);
+    }
+
+    #[test]
+    fn test_bit_vec() {
+        let mut bits = BitVec::new();
+        bits.push(true);
+        bits.push_bits(0b0110, 4);
+        bits.push_bits(0x1234_5678_9ABC_DEF0, 64);
+        bits.push(false);
+        assert_eq!(bits.len(), 70);
+        assert_eq!(bits.as_slice().split_at(5).0.read_u64(), 0b10110);
+        assert_eq!(bits.as_slice().split_at(5).1.split_at(64).0.read_u64(), 0x1234_5678_9ABC_DEF0);
+        assert_eq!(bits.as_bytes()[0], 0b1011_0000 | 0x1234_5678_9ABC_DEF0_u64.to_be_bytes()[0] >> 5);
+
+        let mut copy = BitVec::new();
+        copy.push(true);
+        copy.extend_from_slice(bits.as_slice().split_at(1).1);
+        assert_eq!(copy, bits);
+
+        let collected: BitVec = bits.iter().collect();
+        assert_eq!(collected, bits);
+        assert_eq!(collected.as_slice().to_bit_vec(), bits);
+    }
+}
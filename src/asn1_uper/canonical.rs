@@ -0,0 +1,357 @@
+//! Checking whether PER-encoded data is canonical.
+//!
+//! A value may decode successfully even if its encoding is not the one an encoder following
+//! X.691 would produce, e.g. if a member with a DEFAULT value is encoded explicitly, an empty
+//! optional SEQUENCE OF is marked as present, or a length determinant uses more bits than
+//! necessary. [`check_canonical`] decodes the value, re-encodes it and compares both encodings field
+//! by field.
+
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::asn1_uper::{BitSlice, BitVec, DecodingError, EncodingError, ParseResult};
+use crate::asn1_uper::trace::{path_to_string, trace_decode, PathSegment, TracedField};
+
+
+/// A field whose encoding differs from its canonical encoding.
+///
+/// The bits compared for each field are its own bits, i.e. those that are not part of any of its
+/// subfields, such as presence bitmaps and length determinants. Differences in the bits that are
+/// not attributed to any field (including the padding at the end of the bit stream) are reported
+/// with an empty path.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CanonicalDifference {
+    /// The path to the field, starting within the outermost type.
+    pub path: Vec<PathSegment>,
+
+    /// The offset within the original bit stream of the first bit that differs, or of the field
+    /// if the difference cannot be pinned to a single bit.
+    ///
+    /// `None` if the field is only present in the canonical encoding.
+    pub offset: Option<usize>,
+
+    /// The offset of the field within the canonical encoding.
+    ///
+    /// `None` if the field is only present in the original encoding, e.g. because it has the
+    /// DEFAULT value or is an empty list.
+    pub canonical_offset: Option<usize>,
+
+    /// The own bits of the field in the original encoding.
+    pub original_bits: Option<BitVec>,
+
+    /// The own bits of the field in the canonical encoding.
+    pub canonical_bits: Option<BitVec>,
+}
+impl CanonicalDifference {
+    /// Returns the path to the field in ASN.1 notation.
+    pub fn path_string(&self) -> String {
+        path_to_string(&self.path)
+    }
+}
+impl fmt::Display for CanonicalDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.offset {
+            Some(offset) => write!(f, "{:>6}", offset)?,
+            None => write!(f, "{:>6}", "-")?,
+        }
+        let path = if self.path.is_empty() {
+            "(top level)".to_owned()
+        } else {
+            self.path_string()
+        };
+        write!(f, " {}: ", path)?;
+        match (&self.original_bits, &self.canonical_bits) {
+            (Some(original), Some(canonical))
+                => write!(f, "encoded as [{}], canonical encoding is [{}]", render_bits(original), render_bits(canonical)),
+            (Some(_), None)
+                => write!(f, "encoded although the canonical encoding omits it"),
+            (None, Some(_))
+                => write!(f, "omitted although the canonical encoding contains it"),
+            (None, None)
+                => write!(f, "differs from the canonical encoding"),
+        }
+    }
+}
+
+fn render_bits(bits: &BitVec) -> String {
+    bits.iter().map(|b| if b { '1' } else { '0' }).collect()
+}
+
+
+/// The result of checking an encoding for canonicity.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CanonicalReport {
+    /// The canonical encoding of the decoded value.
+    ///
+    /// If the original bit stream consists of whole octets, the canonical encoding is padded to
+    /// whole octets as well.
+    pub canonical: BitVec,
+
+    /// The fields whose encoding differs from the canonical encoding, in the order of the original
+    /// bit stream.
+    pub differences: Vec<CanonicalDifference>,
+}
+impl CanonicalReport {
+    /// Whether the original encoding is canonical.
+    pub fn is_canonical(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+impl fmt::Display for CanonicalReport {
+    /// Renders the report with one line per difference.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_canonical() {
+            return writeln!(f, "encoding is canonical");
+        }
+        for difference in &self.differences {
+            writeln!(f, "{}", difference)?;
+        }
+        Ok(())
+    }
+}
+
+
+/// An error that may occur when checking an encoding for canonicity.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CanonicalCheckError<'a> {
+    /// The original encoding failed to decode.
+    Decoding(nom::Err<DecodingError<'a>>),
+
+    /// The decoded value failed to encode.
+    Encoding(EncodingError),
+
+    /// The canonical encoding failed to decode; contains the rendered decoding error.
+    Redecoding(String),
+
+    /// The canonical encoding decodes to a different value than the original encoding.
+    ValueMismatch,
+}
+impl<'a> fmt::Display for CanonicalCheckError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decoding(nom::Err::Incomplete(_))
+                => write!(f, "decoding failed: incomplete data"),
+            Self::Decoding(nom::Err::Error(e)) | Self::Decoding(nom::Err::Failure(e))
+                => write!(f, "decoding failed: {}", e),
+            Self::Encoding(e)
+                => write!(f, "re-encoding failed: {}", e),
+            Self::Redecoding(e)
+                => write!(f, "decoding the canonical encoding failed: {}", e),
+            Self::ValueMismatch
+                => write!(f, "the canonical encoding decodes to a different value"),
+        }
+    }
+}
+impl<'a> std::error::Error for CanonicalCheckError<'a> {
+}
+
+
+/// Decodes a value, re-encodes it canonically and reports every field whose original encoding
+/// differs from the canonical one.
+///
+/// `decode` and `encode` are usually the `try_from_uper` and `write_uper` functions of a generated
+/// type; the generated `check_canonical_uper` functions call this function accordingly.
+pub fn check_canonical<'a, T, D, E>(bits: BitSlice<'a>, decode: D, encode: E) -> Result<CanonicalReport, CanonicalCheckError<'a>>
+    where T: PartialEq, D: for<'b> Fn(BitSlice<'b>) -> ParseResult<'b, T>, E: FnOnce(&T, &mut BitVec) -> Result<(), EncodingError>
+{
+    let (original_map, result) = trace_decode(bits, &decode);
+    let (_rest, value) = result
+        .map_err(|e| CanonicalCheckError::Decoding(e.map(|e| e.at_offset_from(bits))))?;
+
+    let mut canonical = BitVec::new();
+    encode(&value, &mut canonical)
+        .map_err(CanonicalCheckError::Encoding)?;
+    if bits.len().is_multiple_of(8) {
+        while !canonical.len().is_multiple_of(8) {
+            canonical.push(false);
+        }
+    }
+
+    let (canonical_map, result) = trace_decode(canonical.as_slice(), &decode);
+    match result {
+        Ok((_rest, canonical_value)) => {
+            if canonical_value != value {
+                return Err(CanonicalCheckError::ValueMismatch);
+            }
+        },
+        Err(nom::Err::Incomplete(_)) => return Err(CanonicalCheckError::Redecoding("incomplete data".to_owned())),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e))
+            => return Err(CanonicalCheckError::Redecoding(e.at_offset_from(canonical.as_slice()).to_string())),
+    }
+
+    let original_fields = own_bits(bits, &original_map.fields);
+    let canonical_fields = own_bits(canonical.as_slice(), &canonical_map.fields);
+    let canonical_by_path: HashMap<&[PathSegment], &OwnBits> = canonical_fields.iter()
+        .map(|f| (f.path, f))
+        .collect();
+
+    let mut differences = Vec::new();
+    let mut matched_paths = Vec::new();
+    for original in &original_fields {
+        let Some(canonical) = canonical_by_path.get(original.path) else {
+            differences.push(CanonicalDifference {
+                path: original.path.to_vec(),
+                offset: Some(original.offset),
+                canonical_offset: None,
+                original_bits: Some(original.bits.clone()),
+                canonical_bits: None,
+            });
+            continue;
+        };
+        matched_paths.push(original.path);
+        if original.bits == canonical.bits {
+            continue;
+        }
+
+        let first_difference = original.bits.iter()
+            .zip(canonical.bits.iter())
+            .position(|(o, c)| o != c)
+            .unwrap_or_else(|| original.bits.len().min(canonical.bits.len()));
+        let offset = original.positions.get(first_difference)
+            .copied()
+            .unwrap_or(original.offset);
+        differences.push(CanonicalDifference {
+            path: original.path.to_vec(),
+            offset: Some(offset),
+            canonical_offset: Some(canonical.offset),
+            original_bits: Some(original.bits.clone()),
+            canonical_bits: Some(canonical.bits.clone()),
+        });
+    }
+    for canonical in &canonical_fields {
+        if matched_paths.contains(&canonical.path) {
+            continue;
+        }
+        differences.push(CanonicalDifference {
+            path: canonical.path.to_vec(),
+            offset: None,
+            canonical_offset: Some(canonical.offset),
+            original_bits: None,
+            canonical_bits: Some(canonical.bits.clone()),
+        });
+    }
+
+    Ok(CanonicalReport {
+        canonical,
+        differences,
+    })
+}
+
+
+/// The bits of a field that do not belong to any of its subfields.
+struct OwnBits<'p> {
+    path: &'p [PathSegment],
+    offset: usize,
+    positions: Vec<usize>,
+    bits: BitVec,
+}
+
+/// Collects the own bits of the whole bit stream (with an empty path) and of each traced field.
+fn own_bits<'p>(bits: BitSlice<'_>, fields: &'p [TracedField]) -> Vec<OwnBits<'p>> {
+    let mut ret = Vec::with_capacity(fields.len() + 1);
+    ret.push(collect_own_bits(bits, &[], 0, bits.len(), fields));
+    for (i, field) in fields.iter().enumerate() {
+        // fields are traced before their subfields
+        let descendant_count = fields[i+1..].iter()
+            .take_while(|f| f.path.starts_with(&field.path))
+            .count();
+        let descendants = &fields[i+1..i+1+descendant_count];
+        ret.push(collect_own_bits(bits, &field.path, field.offset, field.length, descendants));
+    }
+    ret
+}
+
+fn collect_own_bits<'p>(bits: BitSlice<'_>, path: &'p [PathSegment], offset: usize, length: usize, descendants: &[TracedField]) -> OwnBits<'p> {
+    let end = (offset + length).min(bits.len());
+    let mut children: Vec<(usize, usize)> = descendants.iter()
+        .filter(|d| d.path.len() == path.len() + 1)
+        .map(|d| (d.offset, d.offset + d.length))
+        .collect();
+    children.sort_unstable();
+
+    let mut positions = Vec::new();
+    let mut own = BitVec::new();
+    let mut children_iter = children.iter().peekable();
+    let mut position = offset;
+    while position < end {
+        while children_iter.peek().map(|(_start, child_end)| *child_end <= position).unwrap_or(false) {
+            children_iter.next();
+        }
+        if let Some((child_start, child_end)) = children_iter.peek() {
+            if *child_start <= position {
+                position = *child_end;
+                continue;
+            }
+        }
+        positions.push(position);
+        own.push(bits.bit(position));
+        position += 1;
+    }
+
+    OwnBits {
+        path,
+        offset,
+        positions,
+        bits: own,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uflex_3::{CodeTableType, ZoneType};
+
+    fn bits_from_str(s: &str) -> BitVec {
+        s.chars().filter(|c| *c != '_').map(|c| c == '1').collect()
+    }
+
+    #[test]
+    fn test_canonical() {
+        // not extended; city present; city: length 1, value 5
+        let bits = bits_from_str("0_00000001000_00000001_00000101");
+        let report = ZoneType::check_canonical_uper(bits.as_slice()).unwrap();
+        assert!(report.is_canonical());
+        assert_eq!(report.canonical, bits);
+        assert_eq!(report.to_string(), "encoding is canonical\n");
+    }
+
+    #[test]
+    fn test_not_canonical() {
+        // not extended; stationCodeTable, city and zoneId present;
+        // stationCodeTable: stationUic (the default); city: length 2, value 5; zoneId: length 0
+        let bits = bits_from_str("0_00100001100_000_00000010_00000000_00000101_00000000");
+        let report = ZoneType::check_canonical_uper(bits.as_slice()).unwrap();
+        assert_eq!(report.canonical, bits_from_str("0_00000001000_00000001_00000101"));
+
+        let summary: Vec<(String, Option<usize>, Option<usize>)> = report.differences.iter()
+            .map(|d| (d.path_string(), d.offset, d.canonical_offset))
+            .collect();
+        assert_eq!(summary, [
+            ("".to_owned(), Some(3), Some(0)),
+            ("stationCodeTable".to_owned(), Some(12), None),
+            ("city".to_owned(), Some(21), Some(12)),
+            ("zoneId".to_owned(), Some(39), None),
+        ]);
+        assert_eq!(report.differences[2].original_bits, Some(bits_from_str("00000010_00000000_00000101")));
+
+        let rendered = report.to_string();
+        assert!(rendered.contains("     3 (top level): encoded as [000100001100], canonical encoding is [000000001000]\n"));
+        assert!(rendered.contains("    12 stationCodeTable: encoded although the canonical encoding omits it\n"));
+
+        // the decoded value is the same
+        let (_rest, zone) = ZoneType::try_from_uper(bits.as_slice()).unwrap();
+        assert_eq!(zone.station_code_table, CodeTableType::StationUic);
+        assert!(zone.zone_id.is_empty());
+    }
+
+    #[test]
+    fn test_decoding_failure() {
+        let bits = bits_from_str("0_0000000100");
+        let err = ZoneType::check_canonical_uper(bits.as_slice()).unwrap_err();
+        assert!(matches!(err, CanonicalCheckError::Decoding(_)));
+        assert_eq!(err.to_string(), "decoding failed: end of file while decoding ZoneType at bit 1");
+    }
+}
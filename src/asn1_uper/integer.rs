@@ -9,11 +9,13 @@ use std::ops::{
     Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
-use num_bigint::{BigInt, TryFromBigIntError};
+use num_bigint::{BigInt, Sign, TryFromBigIntError};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde::de::Error as _;
 
+use crate::asn1_uper::BitSlice;
+
 
 pub type ShortInt = i128;
 
@@ -48,45 +50,40 @@ impl Integer {
 
     /// Creates an ASN.1 integer from the given usize value.
     pub fn from_usize(value: usize) -> Self {
-        Self::from_long(BigInt::from(value))
+        match ShortInt::try_from(value) {
+            Ok(short_value) => Self::from_short(short_value),
+            Err(_) => Self::from_long(BigInt::from(value)),
+        }
     }
 
     /// Creates an unsigned ASN.1 integer from the given slice of bits.
     ///
     /// Bits are assumed to be ordered from most to least significant. Note that this can only
     /// create unsigned integers.
-    pub fn from_bits_unsigned(bits: &[bool]) -> Self {
-        let one = Self::from_short(1);
-        let mut value = Self::from_short(0);
-        for &bit in bits {
-            value <<= 1;
-            if bit {
-                value += &one;
-            }
+    pub fn from_bits_unsigned(bits: BitSlice<'_>) -> Self {
+        if bits.len() <= 64 {
+            return Self::from_short(bits.read_u64().into());
         }
-        value
+        let bytes = bits.to_right_aligned_bytes(false);
+        Self::from_long(BigInt::from_bytes_be(Sign::Plus, &bytes))
     }
 
     /// Creates a signed ASN.1 integer from the given slice of bits.
     ///
     /// Bits are assumed to be ordered from most to least significant. The first bit is assumed to
     /// have the value `-2**n` instead of `2**n`, acting as a two's-complement sign bit.
-    pub fn from_bits_signed(bits: &[bool]) -> Self {
-        let one = Self::from_short(1);
-        let mut value = Self::from_short(0);
-        let mut first_bit = true;
-        for &bit in bits {
-            value <<= 1;
-            if bit {
-                if first_bit {
-                    value -= &one;
-                } else {
-                    value += &one;
-                }
-            }
-            first_bit = false;
+    pub fn from_bits_signed(bits: BitSlice<'_>) -> Self {
+        if bits.is_empty() {
+            return Self::from_short(0);
+        }
+        if bits.len() <= 64 {
+            // shift the sign bit to the top, then sign-extend it back down
+            let unused_bits = 64 - bits.len();
+            let value = ((bits.read_u64() << unused_bits) as i64) >> unused_bits;
+            return Self::from_short(value.into());
         }
-        value
+        let bytes = bits.to_right_aligned_bytes(bits.bit(0));
+        Self::from_long(BigInt::from_signed_bytes_be(&bytes))
     }
 
     /// Returns the inner integer as a BigInt value.
@@ -1,42 +1,136 @@
 //! ASN.1 PER UNALIGNED parser.
 //!
 //! Implementation of a parser for data encoded using ASN.1 Packed Encoding Rules in their UNALIGNED
-//! variant according to ITU-T X.691.
+//! variant according to ITU-T X.691. The ALIGNED variant can be selected using [`with_variant`].
 
 
+mod bits;
 mod integer;
+mod object_identifier;
+mod variant;
+pub mod canonical;
+pub mod trace;
+pub mod validate;
+pub mod visit;
 
 
 use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
+pub use self::bits::{BitSlice, BitVec};
 pub use self::integer::Integer;
+pub use self::object_identifier::{ObjectIdentifier, ParseObjectIdentifierError};
+pub use self::variant::{current_variant, with_variant, PerVariant};
+pub use uic_ticket_decode_derive::{UperDecode, UperEncode, UperVisit};
 
 
-pub type ParseResult<'a, T> = nom::IResult<&'a [bool], T, DecodingError<'a>>;
+pub type ParseResult<'a, T> = nom::IResult<BitSlice<'a>, T, DecodingError<'a>>;
 
 
 /// An error that may occur when decoding PER-encoded data.
+///
+/// As the error propagates outward, the generated decoding functions add the members and types
+/// that were being decoded, which allows the error to point out the field that failed.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct DecodingError<'a> {
-    bits: &'a [bool],
+    bits: BitSlice<'a>,
     kind: ErrorKind,
+    context: Option<Box<ErrorContext>>,
 }
 impl<'a> DecodingError<'a> {
     pub fn new(
-        bits: &'a [bool],
+        bits: BitSlice<'a>,
         kind: ErrorKind,
     ) -> Self {
         Self {
             bits,
             kind,
+            context: None,
         }
     }
+
+    /// The remaining bits at the position where the error occurred.
+    pub fn bits(&self) -> BitSlice<'a> { self.bits }
+
+    /// The type of error that occurred.
+    pub fn kind(&self) -> &ErrorKind { &self.kind }
+
+    /// The name of the outermost type that was being decoded when the error occurred, if known.
+    pub fn type_name(&self) -> Option<&'static str> {
+        self.context.as_ref().and_then(|c| c.type_name)
+    }
+
+    /// The path to the field that was being decoded when the error occurred, starting within the
+    /// outermost type.
+    pub fn path(&self) -> Vec<trace::PathSegment> {
+        let mut path = self.context.as_ref()
+            .map(|c| c.reversed_path.clone())
+            .unwrap_or_default();
+        path.reverse();
+        path
+    }
+
+    /// The offset of the bit at which the error occurred, if known.
+    ///
+    /// See [`DecodingError::at_offset_from`].
+    pub fn offset(&self) -> Option<usize> {
+        self.context.as_ref().and_then(|c| c.offset)
+    }
+
+    /// Records that the error occurred while decoding a value of the given type.
+    ///
+    /// As the error propagates outward, the type name is replaced, so the outermost type remains.
+    pub fn in_type(mut self, type_name: &'static str) -> Self {
+        self.context.get_or_insert_with(Default::default).type_name = Some(type_name);
+        self
+    }
+
+    /// Records that the error occurred while decoding the given field, within any fields recorded
+    /// previously.
+    pub fn in_field(mut self, segment: trace::PathSegment) -> Self {
+        self.context.get_or_insert_with(Default::default).reversed_path.push(segment);
+        self
+    }
+
+    /// Records the offset at which the error occurred, relative to the start of the given input.
+    ///
+    /// Does nothing if the remaining bits of the error are not a part of `input`. Errors within
+    /// open types are reported at the end of the open type.
+    pub fn at_offset_from(mut self, input: BitSlice<'_>) -> Self {
+        if let Some(offset) = self.bits.offset_within(&input) {
+            self.context.get_or_insert_with(Default::default).offset = Some(offset);
+        }
+        self
+    }
 }
 impl<'a> fmt::Display for DecodingError<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.kind.fmt(f)
+        self.kind.fmt(f)?;
+        if let Some(context) = &self.context {
+            let path = trace::path_to_string(context.reversed_path.iter().rev());
+            match (context.type_name, path.is_empty()) {
+                (Some(type_name), true) => write!(f, " while decoding {}", type_name)?,
+                (Some(type_name), false) if path.starts_with('[') => write!(f, " while decoding {}{}", type_name, path)?,
+                (Some(type_name), false) => write!(f, " while decoding {}.{}", type_name, path)?,
+                (None, false) => write!(f, " while decoding {}", path)?,
+                (None, true) => {},
+            }
+            if let Some(offset) = context.offset {
+                write!(f, " at bit {}", offset)?;
+            }
+        }
+        Ok(())
     }
 }
+
+/// The context in which a decoding error occurred.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct ErrorContext {
+    type_name: Option<&'static str>,
+    reversed_path: Vec<trace::PathSegment>,
+    offset: Option<usize>,
+}
 impl<'a> std::error::Error for DecodingError<'a> {
 }
 
@@ -56,6 +150,18 @@ pub enum EncodingError {
 
     #[non_exhaustive]
     Ia5ByteHasTopBitSet { string: String, byte_index: usize },
+
+    #[non_exhaustive]
+    IntegerOutOfRange { value: Integer, min: Option<Integer>, max: Option<Integer> },
+
+    #[non_exhaustive]
+    LengthOutOfRange { length: usize, min: Integer, max: Integer },
+
+    #[non_exhaustive]
+    LengthRequiresFragmentation { length: usize },
+
+    #[non_exhaustive]
+    UnknownValueHasKnownIndex { index: usize, known_count: usize },
 }
 impl fmt::Display for EncodingError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -68,6 +174,24 @@ impl fmt::Display for EncodingError {
                 => write!(f, "integer {} does not fit into {} bits", value, bits),
             Self::Ia5ByteHasTopBitSet { string, byte_index }
                 => write!(f, "IA5 string {:?} byte {} (0x{:02X}) has top bit set", string, byte_index, string.as_bytes()[*byte_index]),
+            Self::IntegerOutOfRange { value, min, max } => {
+                write!(f, "integer {} is outside the range ", value)?;
+                match min {
+                    Some(m) => write!(f, "{}", m)?,
+                    None => write!(f, "MIN")?,
+                }
+                write!(f, "..")?;
+                match max {
+                    Some(m) => write!(f, "{}", m),
+                    None => write!(f, "MAX"),
+                }
+            },
+            Self::LengthOutOfRange { length, min, max }
+                => write!(f, "length {} is outside the permitted size range {}..{}", length, min, max),
+            Self::LengthRequiresFragmentation { length }
+                => write!(f, "length {} cannot be encoded in a single length determinant and requires fragmentation", length),
+            Self::UnknownValueHasKnownIndex { index, known_count }
+                => write!(f, "unknown extension value has index {}, which belongs to one of the {} known extension additions", index, known_count),
         }
     }
 }
@@ -99,6 +223,26 @@ pub enum ErrorKind {
     /// The string is not valid UTF-8.
     #[non_exhaustive]
     InvalidUtf8String { bytes: Vec<u8> },
+
+    /// The index of an ENUMERATED or CHOICE value does not refer to a known alternative.
+    #[non_exhaustive]
+    InvalidIndex { index: Integer },
+
+    /// A length value exceeds the maximum of the permitted size range.
+    #[non_exhaustive]
+    LengthExceedsMaximum { length: usize, max: usize },
+
+    /// The contents of an OBJECT IDENTIFIER are not a valid encoding of an object identifier.
+    #[non_exhaustive]
+    InvalidObjectIdentifier { contents: Vec<u8> },
+
+    /// A constrained integer exceeds the maximum of its permitted range.
+    #[non_exhaustive]
+    IntegerExceedsMaximum { value: Integer },
+
+    /// The minimum of a constrained integer's permitted range exceeds its maximum.
+    #[non_exhaustive]
+    EmptyRange {},
 }
 impl fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -111,6 +255,16 @@ impl fmt::Display for ErrorKind {
                 => write!(f, "failed to convert length value {} to usize", value),
             Self::InvalidUtf8String { bytes }
                 => write!(f, "byte string is not valid UTF-8: {:?}", bytes),
+            Self::InvalidIndex { index }
+                => write!(f, "invalid alternative index {}", index),
+            Self::LengthExceedsMaximum { length, max }
+                => write!(f, "length {} exceeds the permitted maximum {}", length, max),
+            Self::InvalidObjectIdentifier { contents }
+                => write!(f, "invalid object identifier encoding: {:?}", contents),
+            Self::IntegerExceedsMaximum { value }
+                => write!(f, "integer {} exceeds the maximum of its permitted range", value),
+            Self::EmptyRange {}
+                => write!(f, "the minimum of the permitted range exceeds its maximum"),
         }
     }
 }
@@ -161,6 +315,10 @@ fn bits_required_for_unsigned_value(n: &Integer) -> usize {
     let zero = Integer::from_short(0);
     assert!(n >= &zero);
 
+    if let Ok(short_n) = u128::try_from(n) {
+        return (u128::BITS - short_n.leading_zeros()) as usize;
+    }
+
     let mut my_n = n.clone();
     let mut bits = 0;
     while my_n > zero {
@@ -177,6 +335,10 @@ fn bits_required_for_unique_values(n: &Integer) -> usize {
 
     assert!(n > &zero);
 
+    if let Ok(short_n) = u128::try_from(n) {
+        return (u128::BITS - (short_n - 1).leading_zeros()) as usize;
+    }
+
     let mut value = n - &Integer::from_short(1);
     let mut count = 0;
     while value > zero {
@@ -187,24 +349,83 @@ fn bits_required_for_unique_values(n: &Integer) -> usize {
 }
 
 
-fn split_at_fallible<'a>(bits: &'a [bool], after_length: usize) -> Result<(&'a [bool], &'a [bool]), nom::Err<DecodingError<'a>>> {
+fn split_at_fallible<'a>(bits: BitSlice<'a>, after_length: usize) -> Result<(BitSlice<'a>, BitSlice<'a>), nom::Err<DecodingError<'a>>> {
     if bits.len() < after_length {
-        Err(nom::Err::Incomplete(nom::Needed::new(after_length - bits.len())))
+        Err(nom::Err::Error(DecodingError::new(bits, ErrorKind::Eof {})))
     } else {
         Ok(bits.split_at(after_length))
     }
 }
 
 
+/// Skips the padding bits up to the next octet boundary in the ALIGNED variant.
+///
+/// In the UNALIGNED variant, nothing is skipped.
+fn decode_alignment<'a>(bits: BitSlice<'a>) -> Result<BitSlice<'a>, nom::Err<DecodingError<'a>>> {
+    if !variant::is_aligned() {
+        return Ok(bits);
+    }
+    let (_padding, rest) = split_at_fallible(bits, bits.padding_to_octet())?;
+    Ok(rest)
+}
+
+
+/// Appends zero padding bits up to the next octet boundary in the ALIGNED variant.
+///
+/// In the UNALIGNED variant, nothing is appended.
+fn encode_alignment(uper_buf: &mut BitVec) {
+    if !variant::is_aligned() {
+        return;
+    }
+    while !uper_buf.len().is_multiple_of(8) {
+        uper_buf.push(false);
+    }
+}
+
+
+/// The layout of a constrained whole number with a given range.
+///
+/// In the UNALIGNED variant, this is always a bit-field. The layouts of the ALIGNED variant are
+/// specified in X.691 § 11.5.7.
+enum ConstrainedIntLayout {
+    /// A bit-field of the minimum number of bits that is not octet-aligned.
+    BitField,
+
+    /// An octet-aligned field of the given number of octets.
+    Octets(usize),
+
+    /// An octet-aligned field of the minimum number of octets, preceded by the number of octets as
+    /// a constrained whole number between 1 and the given maximum.
+    IndefiniteLength(usize),
+}
+impl ConstrainedIntLayout {
+    fn for_range(range: &Integer) -> Self {
+        if !variant::is_aligned() || range <= &Integer::from_short(255) {
+            // § 11.5.7.1
+            Self::BitField
+        } else if range == &Integer::from_short(256) {
+            // § 11.5.7.2
+            Self::Octets(1)
+        } else if range <= &Integer::from_short(64*1024) {
+            // § 11.5.7.3
+            Self::Octets(2)
+        } else {
+            // § 11.5.7.4
+            Self::IndefiniteLength(bits_required_for_unique_values(range).div_ceil(8))
+        }
+    }
+}
+
+
 /// Decodes a non-negative binary integer.
 ///
 /// It is expected that the bits passed represent the full integer. For integers of indeterminate
 /// size, PER prescribes a length encoding that generally precedes the integer itself.
 ///
 /// The encoding process is described in X.691 § 11.3.
-fn decode_nonneg_int_complete<'a>(complete_bits: &'a [bool]) -> ParseResult<'a, Integer> {
+fn decode_nonneg_int_complete<'a>(complete_bits: BitSlice<'a>) -> ParseResult<'a, Integer> {
     let ret = Integer::from_bits_unsigned(complete_bits);
-    Ok((&[], ret))
+    Ok((BitSlice::empty(), ret))
 }
 
 
@@ -216,25 +437,36 @@ fn decode_nonneg_int_complete<'a>(complete_bits: &'a [bool]) -> ParseResult<'a,
 ///
 /// The encoding process is described in X.691 § 11.3.
 #[must_use]
-fn encode_nonneg_int(uper_buf: &mut Vec<bool>, value: &Integer, bit_count: usize) -> Result<(), EncodingError> {
+fn encode_nonneg_int(uper_buf: &mut BitVec, value: &Integer, bit_count: usize) -> Result<(), EncodingError> {
     // ensure the integer is really non-negative
     let zero = Integer::from_short(0);
     if value < &zero {
         return Err(EncodingError::NonNegativeIntegerHasNegativeValue { obtained: value.clone() });
     }
 
+    // fast path for the common case
+    if bit_count <= 64 {
+        if let Ok(small_value) = u64::try_from(value) {
+            if bit_count == 64 || small_value >> bit_count == 0 {
+                uper_buf.push_bits(small_value, bit_count);
+                return Ok(());
+            }
+        }
+    }
+
     // ensure the integer fits in the bits
-    let bit_count_u32 = u32::try_from(bit_count).unwrap();
     if (value >> u32::try_from(bit_count).unwrap()) != zero {
         return Err(EncodingError::IntegerDoesNotFitIntoBits { value: value.clone(), bits: bit_count });
     }
 
-    let one = Integer::from_short(1);
-    for i in (0..bit_count_u32).rev() {
-        let mask = (&one) << i;
-        let bit_value = value & (&mask) != zero;
-        uper_buf.push(bit_value);
+    // take the big-endian representation, zero-extended to bit_count bits
+    let (_sign, bytes) = value.to_bigint().to_bytes_be();
+    let value_bits = BitSlice::new(&bytes);
+    for _ in value_bits.len()..bit_count {
+        uper_buf.push(false);
     }
+    let skip_bits = value_bits.len().saturating_sub(bit_count);
+    uper_buf.extend_from_slice(value_bits.split_at(skip_bits).1);
     Ok(())
 }
 
@@ -242,20 +474,35 @@ fn encode_nonneg_int(uper_buf: &mut Vec<bool>, value: &Integer, bit_count: usize
 /// Decodes a constrained whole number.
 ///
 /// The encoding process is described in X.691 § 11.5.
-fn decode_constrained_int<'a>(bits: &'a [bool], min: &Integer, max: &Integer) -> ParseResult<'a, Integer> {
-    assert!(min < max);
-    let range = &(max - min) + &Integer::from_short(1);
-    if &range == &Integer::from_short(1) {
+fn decode_constrained_int<'a>(bits: BitSlice<'a>, min: &Integer, max: &Integer) -> ParseResult<'a, Integer> {
+    if min > max {
+        return Err(nom::Err::Error(DecodingError::new(bits, ErrorKind::EmptyRange {})));
+    }
+    if min == max {
         // an integer that can only occupy one value is encoded into zero bits (X.691 § 11.5.4);
         // just return min
         return Ok((bits, min.clone()));
     }
+    let range = &(max - min) + &Integer::from_short(1);
 
     // what is encoded is not `n` but `n - min` (as a non-negative binary integer; § 11.5.6)
-    let bit_count = bits_required_for_unique_values(&range);
-
-    // take as many bits
-    let (my_bits, rest) = split_at_fallible(bits, bit_count)?;
+    let (my_bits, rest) = match ConstrainedIntLayout::for_range(&range) {
+        ConstrainedIntLayout::BitField => {
+            let bit_count = bits_required_for_unique_values(&range);
+            split_at_fallible(bits, bit_count)?
+        },
+        ConstrainedIntLayout::Octets(octet_count) => {
+            let rest = decode_alignment(bits)?;
+            split_at_fallible(rest, 8 * octet_count)?
+        },
+        ConstrainedIntLayout::IndefiniteLength(max_octet_count) => {
+            let (rest, octet_count) = decode_constrained_int(bits, &Integer::from_short(1), &Integer::from_usize(max_octet_count))?;
+            // decoded from a few bits, so it always fits
+            let octet_count = octet_count.try_to_usize().unwrap_or(usize::MAX);
+            let rest = decode_alignment(rest)?;
+            split_at_fallible(rest, octet_count.saturating_mul(8))?
+        },
+    };
     let my_offset = Integer::from_bits_unsigned(my_bits);
     let my_value = &my_offset + min;
 
@@ -266,15 +513,17 @@ fn decode_constrained_int<'a>(bits: &'a [bool], min: &Integer, max: &Integer) ->
 /// Decodes a normally-small non-negative integer.
 ///
 /// The encoding process is described in X.691 § 11.6.
-fn decode_normally_small_nonneg_int<'a>(bits: &'a [bool]) -> ParseResult<'a, Integer> {
+fn decode_normally_small_nonneg_int<'a>(bits: BitSlice<'a>) -> ParseResult<'a, Integer> {
     let (is_large, rest) = split_at_fallible(bits, 1)?;
-    if is_large[0] {
+    if is_large.bit(0) {
         // X.691 § 11.6.2 forwards us to § 11.7 (semi-constrained integer)
         // with § 11.9 to govern the length
         let (rest, length_integer) = decode_length(rest, &WholeNumberConstraint::SemiConstrained { min: Integer::from_short(0) })?;
-        let length = length_integer.try_to_usize()
+        // the length is given in octets (§ 11.7.4)
+        let length_bits = length_integer.try_to_usize()
+            .and_then(|length| length.checked_mul(8))
             .ok_or_else(|| nom::Err::Error(DecodingError::new(rest, ErrorKind::LengthValueNotUsize { value: length_integer })))?;
-        let (int_slice, rest) = split_at_fallible(rest, length)?;
+        let (int_slice, rest) = split_at_fallible(rest, length_bits)?;
         let (_, value) = decode_semi_constrained_int_complete(int_slice, &Integer::from_short(0))?;
         Ok((rest, value))
     } else {
@@ -289,7 +538,7 @@ fn decode_normally_small_nonneg_int<'a>(bits: &'a [bool]) -> ParseResult<'a, Int
 /// Encodes a normally-small non-negative integer.
 ///
 /// The encoding process is described in X.691 § 11.6.
-fn encode_normally_small_nonneg_int(uper_buf: &mut Vec<bool>, value: &Integer) -> Result<(), EncodingError> {
+fn encode_normally_small_nonneg_int(uper_buf: &mut BitVec, value: &Integer) -> Result<(), EncodingError> {
     let too_much_for_six_bits = Integer::from_usize(0b100_0000);
     if value < &too_much_for_six_bits {
         // announce small integer
@@ -314,7 +563,7 @@ fn encode_normally_small_nonneg_int(uper_buf: &mut Vec<bool>, value: &Integer) -
 /// size, PER prescribes a length encoding that generally precedes the integer itself.
 ///
 /// The encoding process is described in X.691 § 11.7.
-fn decode_semi_constrained_int_complete<'a>(complete_bits: &'a [bool], min: &Integer) -> ParseResult<'a, Integer> {
+fn decode_semi_constrained_int_complete<'a>(complete_bits: BitSlice<'a>, min: &Integer) -> ParseResult<'a, Integer> {
     // what is encoded is not `n` but `(n - min)`
     let (rest, mut integer) = decode_nonneg_int_complete(complete_bits)?;
     integer += min;
@@ -329,25 +578,54 @@ fn decode_semi_constrained_int_complete<'a>(complete_bits: &'a [bool], min: &Int
 /// size, PER prescribes a length encoding that generally precedes the integer itself.
 ///
 /// The encoding process is described in X.691 § 11.8.
-fn decode_unconstrained_int_complete<'a>(complete_bits: &'a [bool]) -> ParseResult<'a, Integer> {
+fn decode_unconstrained_int_complete<'a>(complete_bits: BitSlice<'a>) -> ParseResult<'a, Integer> {
     let ret = Integer::from_bits_signed(complete_bits);
-    Ok((&[], ret))
+    Ok((BitSlice::empty(), ret))
 }
 
 
-/// Returns whether the value with the given length is fragmented into multiple pieces.
-fn value_with_length_is_fragmented(length: &Integer) -> bool {
-    length == &Integer::from_short(1*16384)
-        || length == &Integer::from_short(2*16384)
-        || length == &Integer::from_short(3*16384)
-        || length == &Integer::from_short(4*16384)
+/// The number of items in a block of a fragmented value.
+///
+/// Defined in X.691 § 11.9.3.8.
+const FRAGMENT_BLOCK_SIZE: usize = 16384;
+
+
+/// Returns whether the value with the given length is fragmented into multiple pieces, i.e. whether
+/// the length determinant is followed by the items of the fragment and then another length
+/// determinant.
+///
+/// The encoding process is described in X.691 § 11.9.3.8.
+pub fn length_is_fragmented(constraint: &WholeNumberConstraint, length: &Integer) -> bool {
+    if !constraint_uses_general_length(constraint) {
+        return false;
+    }
+    length == &Integer::from_usize(FRAGMENT_BLOCK_SIZE)
+        || length == &Integer::from_usize(2*FRAGMENT_BLOCK_SIZE)
+        || length == &Integer::from_usize(3*FRAGMENT_BLOCK_SIZE)
+        || length == &Integer::from_usize(4*FRAGMENT_BLOCK_SIZE)
+}
+
+
+/// Returns whether lengths with the given constraint are encoded using the general length
+/// encoding, which supports fragmentation.
+///
+/// This is specified in X.691 § 11.9.4.
+fn constraint_uses_general_length(constraint: &WholeNumberConstraint) -> bool {
+    match constraint {
+        WholeNumberConstraint::Constrained { min, max } => {
+            // § 11.9.4.1
+            min != max && max >= &Integer::from_short(64*1024)
+        },
+        WholeNumberConstraint::NormallySmall => false,
+        _ => true,
+    }
 }
 
 
 /// Decodes a length value.
 ///
 /// The encoding process is described in X.691 § 11.9.4.
-pub fn decode_length<'a>(bits: &'a [bool], constraint: &WholeNumberConstraint) -> ParseResult<'a, Integer> {
+pub fn decode_length<'a>(bits: BitSlice<'a>, constraint: &WholeNumberConstraint) -> ParseResult<'a, Integer> {
     if let WholeNumberConstraint::Constrained { min, max } = constraint {
         // X.691 § 11.9.4.1
         let one = Integer::from_short(1);
@@ -359,10 +637,14 @@ pub fn decode_length<'a>(bits: &'a [bool], constraint: &WholeNumberConstraint) -
         }
         let int_64k = Integer::from_short(64*1024);
         if max < &int_64k {
-            // non-negative binary integer, minimum length
-            let bit_count = bits_required_for_unique_values(&range);
-            let (int_bits, rest) = split_at_fallible(bits, bit_count)?;
-            let (_, value) = decode_nonneg_int_complete(int_bits)?;
+            // constrained whole number (§ 11.5)
+            let (rest, value) = decode_constrained_int(bits, min, max)?;
+            if &value > max {
+                // both are small (see above)
+                let length = value.try_to_usize().unwrap_or(usize::MAX);
+                let max = max.try_to_usize().unwrap_or(usize::MAX);
+                return Err(nom::Err::Error(DecodingError::new(rest, ErrorKind::LengthExceedsMaximum { length, max })));
+            }
             return Ok((rest, value));
         }
     }
@@ -374,9 +656,12 @@ pub fn decode_length<'a>(bits: &'a [bool], constraint: &WholeNumberConstraint) -
         return decode_normally_small_nonneg_int(bits);
     }
 
+    // § 11.9.3.5 through 11.9.3.8: octet-aligned in the ALIGNED variant
+    let bits = decode_alignment(bits)?;
+
     // grab a bit
     let (is_big, rest) = split_at_fallible(bits, 1)?;
-    if !is_big[0] {
+    if !is_big.bit(0) {
         // take seven bits as an unsigned integer; that's the length
         let (my_bits, rest) = split_at_fallible(rest, 7)?;
         let my_value = Integer::from_bits_unsigned(my_bits);
@@ -384,7 +669,7 @@ pub fn decode_length<'a>(bits: &'a [bool], constraint: &WholeNumberConstraint) -
     } else {
         // take another bit
         let (is_huge, rest) = split_at_fallible(rest, 1)?;
-        if !is_huge[0] {
+        if !is_huge.bit(0) {
             // take 14 bits as an unsigned integer; that's the length
             let (my_bits, rest) = split_at_fallible(rest, 14)?;
             let my_value = Integer::from_bits_unsigned(my_bits);
@@ -412,7 +697,7 @@ pub fn decode_length<'a>(bits: &'a [bool], constraint: &WholeNumberConstraint) -
 ///
 /// The encoding process is described in X.691 § 11.9.4.
 #[must_use]
-pub fn encode_length(uper_buf: &mut Vec<bool>, constraint: &WholeNumberConstraint, value: usize) -> Result<(), EncodingError> {
+pub fn encode_length(uper_buf: &mut BitVec, constraint: &WholeNumberConstraint, value: usize) -> Result<(), EncodingError> {
     if let WholeNumberConstraint::Constrained { min, max } = constraint {
         // X.691 § 11.9.4.1
         let one = Integer::from_short(1);
@@ -424,11 +709,15 @@ pub fn encode_length(uper_buf: &mut Vec<bool>, constraint: &WholeNumberConstrain
             }
             return Ok(());
         }
+        let value_integer = Integer::from_usize(value);
+        if &value_integer < min || &value_integer > max {
+            return Err(EncodingError::LengthOutOfRange { length: value, min: min.clone(), max: max.clone() });
+        }
+
         let int_64k = Integer::from_short(64*1024);
         if max < &int_64k {
-            // non-negative binary integer, minimum length
-            let bit_count = bits_required_for_unique_values(&range);
-            encode_nonneg_int(uper_buf, &(&Integer::from_usize(value) - min), bit_count)?;
+            // constrained whole number (§ 11.5)
+            encode_constrained_int(uper_buf, min, max, &value_integer)?;
             return Ok(());
         }
     }
@@ -441,49 +730,194 @@ pub fn encode_length(uper_buf: &mut Vec<bool>, constraint: &WholeNumberConstrain
         return Ok(());
     }
 
+    // § 11.9.3.5 through 11.9.3.8: octet-aligned in the ALIGNED variant
+    encode_alignment(uper_buf);
+
     if value <= 0x7F {
         // fits in seven bits; encoded as 0LLL LLLL
         uper_buf.push(false);
-        for i in (0..7).rev() {
-            let bit_val = value & (1 << i) != 0;
-            uper_buf.push(bit_val);
-        }
+        uper_buf.push_bits(value as u64, 7);
     } else if value <= 0x3FFF {
         // fits in 14 bits, encoded as 10LL LLLL LLLL LLLL
         uper_buf.push(true);
         uper_buf.push(false);
-        for i in (0..14).rev() {
-            let bit_val = value & (1 << i) != 0;
-            uper_buf.push(bit_val);
-        }
-    } else {
+        uper_buf.push_bits(value as u64, 14);
+    } else if value.is_multiple_of(FRAGMENT_BLOCK_SIZE) && value / FRAGMENT_BLOCK_SIZE <= 4 {
         // overlong value split into multiple blocks
         // number of blocks encoded as 11BB BBBB where L = B * 16384
+        // (the blocks must be followed by another length determinant; see encode_fragmented)
         uper_buf.push(true);
         uper_buf.push(true);
-        let block_count = value / 16384;
-        for i in (0..6).rev() {
-            let bit_val = value & (1 << i) != 0;
-            uper_buf.push(bit_val);
+        let block_count = value / FRAGMENT_BLOCK_SIZE;
+        uper_buf.push_bits(block_count as u64, 6);
+    } else {
+        return Err(EncodingError::LengthRequiresFragmentation { length: value });
+    }
+    Ok(())
+}
+
+
+/// Decodes a value whose items are preceded by a length determinant, following any further
+/// fragments of the value.
+///
+/// Each item is decoded using `decode_item`. The encoding process is described in X.691
+/// § 11.9.3.8.
+pub fn decode_fragmented<'a, T, F>(bits: BitSlice<'a>, constraint: &WholeNumberConstraint, mut decode_item: F) -> ParseResult<'a, Vec<T>>
+    where T: fmt::Debug + std::any::Any, F: FnMut(BitSlice<'a>) -> ParseResult<'a, T>
+{
+    let mut items = Vec::new();
+    let mut rest = bits;
+    loop {
+        let (new_rest, length_integer) = decode_length(rest, constraint)?;
+        rest = new_rest;
+        let length = length_integer.try_to_usize()
+            .ok_or_else(|| nom::Err::Error(DecodingError::new(rest, ErrorKind::LengthValueNotUsize { value: length_integer.clone() })))?;
+
+        // the length has not been verified yet; cap the allocation by the remaining input
+        items.reserve(length.min(rest.len()));
+        for _ in 0..length {
+            let (new_rest, item) = trace::element(items.len(), rest, &mut decode_item)?;
+            rest = new_rest;
+            items.push(item);
+        }
+
+        if !length_is_fragmented(constraint, &length_integer) {
+            break;
+        }
+    }
+    Ok((rest, items))
+}
+
+
+/// Encodes a value whose items are preceded by a length determinant, splitting it into fragments
+/// if necessary.
+///
+/// Each item is encoded using `encode_item`. The encoding process is described in X.691
+/// § 11.9.3.8.
+pub fn encode_fragmented<T, F>(uper_buf: &mut BitVec, constraint: &WholeNumberConstraint, items: &[T], encode_item: F) -> Result<(), EncodingError>
+    where F: FnMut(&mut BitVec, &T) -> Result<(), EncodingError>
+{
+    encode_fragments(uper_buf, constraint, items, false, encode_item)
+}
+
+
+/// Encodes a value whose items are preceded by a length determinant, splitting it into fragments
+/// if necessary and, if `align_items` is set, aligning the items of each non-empty fragment.
+fn encode_fragments<T, F>(uper_buf: &mut BitVec, constraint: &WholeNumberConstraint, items: &[T], align_items: bool, mut encode_item: F) -> Result<(), EncodingError>
+    where F: FnMut(&mut BitVec, &T) -> Result<(), EncodingError>
+{
+    if !constraint_uses_general_length(constraint) {
+        encode_length(uper_buf, constraint, items.len())?;
+        if align_items && !items.is_empty() {
+            encode_alignment(uper_buf);
+        }
+        for item in items {
+            encode_item(uper_buf, item)?;
+        }
+        return Ok(());
+    }
+
+    if let WholeNumberConstraint::Constrained { min, max } = constraint {
+        let length_integer = Integer::from_usize(items.len());
+        if &length_integer < min || &length_integer > max {
+            return Err(EncodingError::LengthOutOfRange { length: items.len(), min: min.clone(), max: max.clone() });
+        }
+    }
+
+    let mut remaining = items;
+    loop {
+        // § 11.9.3.8.1: up to four blocks of 16K items each per fragment
+        let fragment_length = if remaining.len() >= FRAGMENT_BLOCK_SIZE {
+            (remaining.len() / FRAGMENT_BLOCK_SIZE).min(4) * FRAGMENT_BLOCK_SIZE
+        } else {
+            remaining.len()
+        };
+        encode_length(uper_buf, &WholeNumberConstraint::Unconstrained, fragment_length)?;
+
+        let (fragment, rest) = remaining.split_at(fragment_length);
+        if align_items && !fragment.is_empty() {
+            encode_alignment(uper_buf);
+        }
+        for item in fragment {
+            encode_item(uper_buf, item)?;
+        }
+        remaining = rest;
+
+        if fragment_length < FRAGMENT_BLOCK_SIZE {
+            // the final length determinant (possibly zero) has been written
+            break;
         }
     }
     Ok(())
 }
 
 
+/// Decodes a fragmented string of `bits_per_item`-bit characters.
+fn decode_fragmented_string<'a>(bits: BitSlice<'a>, length_constraint: &WholeNumberConstraint, bits_per_item: usize) -> ParseResult<'a, Vec<u8>> {
+    let align_items = string_contents_aligned(length_constraint, bits_per_item);
+    let mut ret = Vec::new();
+    let mut rest = bits;
+    loop {
+        let (new_rest, length_integer) = decode_length(rest, length_constraint)?;
+        let length = length_integer.try_to_usize()
+            .ok_or_else(|| nom::Err::Error(DecodingError::new(new_rest, ErrorKind::LengthValueNotUsize { value: length_integer.clone() })))?;
+        let length_bits = length.checked_mul(bits_per_item)
+            .ok_or_else(|| nom::Err::Error(DecodingError::new(new_rest, ErrorKind::LengthValueNotUsize { value: length_integer.clone() })))?;
+        let new_rest = if align_items && length > 0 {
+            decode_alignment(new_rest)?
+        } else {
+            new_rest
+        };
+        let (item_bits, new_rest) = split_at_fallible(new_rest, length_bits)?;
+        ret.extend(bits_to_bytes(item_bits, bits_per_item));
+        rest = new_rest;
+
+        if !length_is_fragmented(length_constraint, &length_integer) {
+            break;
+        }
+    }
+    Ok((rest, ret))
+}
+
+
+/// Returns whether the contents of a string with the given size constraint and item size are
+/// octet-aligned.
+///
+/// This is the case in the ALIGNED variant unless the string has a fixed size of at most 16 bits,
+/// as specified in X.691 § 16.9 through 16.11, § 17.6 through 17.8 and § 30.5.7.
+fn string_contents_aligned(length_constraint: &WholeNumberConstraint, bits_per_item: usize) -> bool {
+    if !variant::is_aligned() {
+        return false;
+    }
+    match length_constraint.singular_value().and_then(|length| length.try_to_usize()) {
+        Some(length) => length.saturating_mul(bits_per_item) > 16,
+        None => true,
+    }
+}
+
+
+/// Returns the number of bits per character of an IA5String.
+///
+/// In the ALIGNED variant, the seven bits of each character are padded to a power of two as
+/// specified in X.691 § 30.5.2.
+fn ia5_bits_per_char() -> usize {
+    if variant::is_aligned() { 8 } else { 7 }
+}
+
+
 /// Decodes a boolean value.
 ///
 /// The encoding is specified in X.691 § 12.
-pub fn decode_bool<'a>(bits: &'a [bool]) -> ParseResult<'a, bool> {
+pub fn decode_bool<'a>(bits: BitSlice<'a>) -> ParseResult<'a, bool> {
     let (value, rest) = split_at_fallible(bits, 1)?;
-    Ok((rest, value[0]))
+    Ok((rest, value.bit(0)))
 }
 
 
 /// Encodes a boolean value.
 ///
 /// The encoding is specified in X.691 § 12.
-pub fn encode_bool(uper_buf: &mut Vec<bool>, value: bool) {
+pub fn encode_bool(uper_buf: &mut BitVec, value: bool) {
     uper_buf.push(value);
 }
 
@@ -491,21 +925,37 @@ pub fn encode_bool(uper_buf: &mut Vec<bool>, value: bool) {
 /// Decodes an integer.
 ///
 /// The encoding is specified in X.691 § 13.
-pub fn decode_integer<'a>(bits: &'a [bool], constraint: &WholeNumberConstraint) -> ParseResult<'a, Integer> {
+pub fn decode_integer<'a>(bits: BitSlice<'a>, constraint: &WholeNumberConstraint) -> ParseResult<'a, Integer> {
     match constraint {
         WholeNumberConstraint::Constrained { min, max } => {
             // this is a definite-length case
             // no length is stored, the number is simply encoded into as few bits as possible
-            decode_constrained_int(bits, min, max)
+            let (rest, value) = decode_constrained_int(bits, min, max)?;
+            if &value > max {
+                // the bit-field can hold values beyond the range
+                return Err(nom::Err::Error(DecodingError::new(rest, ErrorKind::IntegerExceedsMaximum { value })));
+            }
+            Ok((rest, value))
         },
-        WholeNumberConstraint::SemiConstrained { .. } | WholeNumberConstraint::Unconstrained { .. } => {
+        WholeNumberConstraint::SemiConstrained { min } => {
+            // § 13.2.3
+            // this is an indefinite-length case
+            // a length is stored which represents the number of octets used to store (n - min)
+            let (rest, length_integer) = decode_length(bits, &WholeNumberConstraint::Unconstrained)?;
+            let length_bytes = length_integer.try_to_usize()
+                .ok_or_else(|| nom::Err::Error(DecodingError::new(rest, ErrorKind::LengthValueNotUsize { value: length_integer })))?;
+            let (integer_bits, rest) = split_at_fallible(rest, 8 * length_bytes)?;
+            let (_, integer) = decode_semi_constrained_int_complete(integer_bits, min)?;
+            Ok((rest, integer))
+        },
+        WholeNumberConstraint::Unconstrained { .. } => {
+            // § 13.2.4
             // this is an indefinite-length case
             // a length is stored which represents the number of octets used to store the number
             let (rest, length_integer) = decode_length(bits, &WholeNumberConstraint::Unconstrained)?;
-            let length_bits = length_integer.try_to_usize()
+            let length_bytes = length_integer.try_to_usize()
                 .ok_or_else(|| nom::Err::Error(DecodingError::new(rest, ErrorKind::LengthValueNotUsize { value: length_integer })))?;
-            let length_bytes = 8 * length_bits;
-            let (integer_bits, rest) = split_at_fallible(rest, length_bytes)?;
+            let (integer_bits, rest) = split_at_fallible(rest, 8 * length_bytes)?;
             let integer = Integer::from_bits_signed(integer_bits);
             Ok((rest, integer))
         },
@@ -518,7 +968,7 @@ pub fn decode_integer<'a>(bits: &'a [bool], constraint: &WholeNumberConstraint)
 ///
 /// The encoding is specified in X.691 § 13.
 #[must_use]
-pub fn encode_integer(uper_buf: &mut Vec<bool>, constraint: &WholeNumberConstraint, value: &Integer) -> Result<(), EncodingError> {
+pub fn encode_integer(uper_buf: &mut BitVec, constraint: &WholeNumberConstraint, value: &Integer) -> Result<(), EncodingError> {
     // § 13.2.1
     if let Some(singular_value) = constraint.singular_value() {
         if value != singular_value {
@@ -552,9 +1002,10 @@ pub fn encode_integer(uper_buf: &mut Vec<bool>, constraint: &WholeNumberConstrai
 ///
 /// This is specified in X.691 § 11.5.
 #[must_use]
-fn encode_constrained_int(uper_buf: &mut Vec<bool>, min: &Integer, max: &Integer, value: &Integer) -> Result<(), EncodingError> {
-    assert!(value >= min);
-    assert!(value <= max);
+fn encode_constrained_int(uper_buf: &mut BitVec, min: &Integer, max: &Integer, value: &Integer) -> Result<(), EncodingError> {
+    if value < min || value > max {
+        return Err(EncodingError::IntegerOutOfRange { value: value.clone(), min: Some(min.clone()), max: Some(max.clone()) });
+    }
 
     // § 11.5.3
     let range = &(max - min) + &Integer::from_short(1);
@@ -565,9 +1016,24 @@ fn encode_constrained_int(uper_buf: &mut Vec<bool>, min: &Integer, max: &Integer
     }
 
     // § 11.5.4
-    let bit_count = bits_required_for_unique_values(&range);
     let encode_value = value - min;
-    encode_nonneg_int(uper_buf, &encode_value, bit_count)?;
+    match ConstrainedIntLayout::for_range(&range) {
+        ConstrainedIntLayout::BitField => {
+            let bit_count = bits_required_for_unique_values(&range);
+            encode_nonneg_int(uper_buf, &encode_value, bit_count)?;
+        },
+        ConstrainedIntLayout::Octets(octet_count) => {
+            encode_alignment(uper_buf);
+            encode_nonneg_int(uper_buf, &encode_value, 8 * octet_count)?;
+        },
+        ConstrainedIntLayout::IndefiniteLength(max_octet_count) => {
+            // zero is encoded in one octet, not zero octets
+            let octet_count = bits_required_for_unsigned_value(&encode_value).div_ceil(8).max(1);
+            encode_constrained_int(uper_buf, &Integer::from_short(1), &Integer::from_usize(max_octet_count), &Integer::from_usize(octet_count))?;
+            encode_alignment(uper_buf);
+            encode_nonneg_int(uper_buf, &encode_value, 8 * octet_count)?;
+        },
+    }
     Ok(())
 }
 
@@ -576,8 +1042,10 @@ fn encode_constrained_int(uper_buf: &mut Vec<bool>, min: &Integer, max: &Integer
 ///
 /// This is specified in X.691 § 11.7, which mostly refers to § 11.3.
 #[must_use]
-fn encode_semi_constrained_int(uper_buf: &mut Vec<bool>, min: &Integer, value: &Integer) -> Result<(), EncodingError> {
-    assert!(value >= min);
+fn encode_semi_constrained_int(uper_buf: &mut BitVec, min: &Integer, value: &Integer) -> Result<(), EncodingError> {
+    if value < min {
+        return Err(EncodingError::IntegerOutOfRange { value: value.clone(), min: Some(min.clone()), max: None });
+    }
 
     // § 11.7.4
     let encode_value = value - min;
@@ -586,6 +1054,10 @@ fn encode_semi_constrained_int(uper_buf: &mut Vec<bool>, min: &Integer, value: &
     // § 11.3.6 applies
     // (X.691 prescribes the "minimal number of octets" encoding here, not the "minimal number of bits" encoding)
     // => round up to the next full octet and store length in octets, not bits
+    // (zero is encoded in one octet, not zero octets)
+    if bit_count == 0 {
+        bit_count = 8;
+    }
     while bit_count % 8 != 0 {
         bit_count += 1;
     }
@@ -600,68 +1072,16 @@ fn encode_semi_constrained_int(uper_buf: &mut Vec<bool>, min: &Integer, value: &
 ///
 /// This is specified in X.691 § 11.8, which pretty much directly refers to § 11.4.
 #[must_use]
-fn encode_unconstrained_int(uper_buf: &mut Vec<bool>, value: &Integer) -> Result<(), EncodingError> {
+fn encode_unconstrained_int(uper_buf: &mut BitVec, value: &Integer) -> Result<(), EncodingError> {
     // § 11.7.4
 
     // encode a 2's complement integer in the lowest number of octets (not bits!)
-
-    let zero = Integer::from_short(0);
-    let one = Integer::from_short(1);
-    let mut bits = if value < &zero {
-        // -2**31 fits into i32 while 2**31 does not
-        // => find the lowest number of bits for ((-n) - 1)
-        let positive_minus_one = &(-value) - &one;
-        let bit_count = bits_required_for_unsigned_value(&positive_minus_one);
-
-        // encode into that
-        let mut bits = Vec::new();
-        encode_nonneg_int(&mut bits, &positive_minus_one, bit_count)?;
-
-        // 2's complement negation ((NOT a) + 1)
-        // we can reuse ((-n) - 1) here because that's equivalent to (NOT (a - 1))
-        // => NOT
-        bits.reverse();
-        for bit in &mut bits {
-            *bit = !*bit;
-        }
-
-        // pad with 1s to next octet
-        while bits.len() % 8 != 0 {
-            bits.push(true);
-        }
-
-        // reverse back
-        bits.reverse();
-
-        bits
-    } else {
-        // positive integers are rather boring
-        let bit_count = bits_required_for_unsigned_value(&value);
-        let mut bits = Vec::with_capacity(bit_count + 1);
-        encode_nonneg_int(&mut bits, &value, bit_count)?;
-
-        bits.reverse();
-        if bits.len() > 0 && *bits.last().unwrap() {
-            // top bit is 1, but our sign is positive
-            // => prepend (since we reversed: append) a 0
-            bits.push(false);
-        }
-
-        // pad with 0s to next octet
-        while bits.len() % 8 != 0 {
-            bits.push(false);
-        }
-
-        // reverse back
-        bits.reverse();
-
-        bits
-    };
+    // (zero is encoded in one octet, not zero octets)
+    let bytes = value.to_bigint().to_signed_bytes_be();
 
     // write it out
-    let byte_count = bits.len() / 8;
-    encode_length(uper_buf, &WholeNumberConstraint::SemiConstrained { min: Integer::from_short(0) }, byte_count)?;
-    uper_buf.append(&mut bits);
+    encode_length(uper_buf, &WholeNumberConstraint::SemiConstrained { min: Integer::from_short(0) }, bytes.len())?;
+    uper_buf.extend_from_slice(BitSlice::new(&bytes));
 
     Ok(())
 }
@@ -670,27 +1090,17 @@ fn encode_unconstrained_int(uper_buf: &mut Vec<bool>, value: &Integer) -> Result
 /// Decodes an octet string.
 ///
 /// The encoding is specified in X.691 § 17.
-pub fn decode_octet_string<'a>(bits: &'a [bool], length_constraint: &WholeNumberConstraint) -> ParseResult<'a, Vec<u8>> {
-    let (rest, length_integer) = decode_length(bits, length_constraint)?;
-    let length_bytes: usize = length_integer.try_to_usize()
-        .ok_or_else(|| nom::Err::Error(DecodingError::new(rest, ErrorKind::LengthValueNotUsize { value: length_integer })))?;
-    let length_bits = 8 * length_bytes;
-    let (octet_bits, rest) = split_at_fallible(rest, length_bits)?;
-    Ok((rest, bits_to_bytes(octet_bits, 8)))
+pub fn decode_octet_string<'a>(bits: BitSlice<'a>, length_constraint: &WholeNumberConstraint) -> ParseResult<'a, Vec<u8>> {
+    decode_fragmented_string(bits, length_constraint, 8)
 }
 
 
 /// Decodes an IA5 string.
 ///
 /// The encoding is specified in X.691 § 17 in conjunction with § 30.5.3.
-pub fn decode_ia5_string<'a>(bits: &'a [bool], length_constraint: &WholeNumberConstraint) -> ParseResult<'a, Vec<u8>> {
-    // each IA5String character fits in 7 bytes
-    let (rest, length_integer) = decode_length(bits, length_constraint)?;
-    let length_bytes: usize = length_integer.try_to_usize()
-        .ok_or_else(|| nom::Err::Error(DecodingError::new(rest, ErrorKind::LengthValueNotUsize { value: length_integer })))?;
-    let length_bits = 7 * length_bytes;
-    let (octet_bits, rest) = split_at_fallible(rest, length_bits)?;
-    Ok((rest, bits_to_bytes(octet_bits, 7)))
+pub fn decode_ia5_string<'a>(bits: BitSlice<'a>, length_constraint: &WholeNumberConstraint) -> ParseResult<'a, Vec<u8>> {
+    // each IA5String character fits in 7 bits, which are padded to 8 in the ALIGNED variant
+    decode_fragmented_string(bits, length_constraint, ia5_bits_per_char())
 }
 
 
@@ -698,37 +1108,139 @@ pub fn decode_ia5_string<'a>(bits: &'a [bool], length_constraint: &WholeNumberCo
 ///
 /// The encoding is specified in X.691 § 17.
 #[must_use]
-pub fn encode_octet_string(uper_buf: &mut Vec<bool>, length_constraint: &WholeNumberConstraint, value: &[u8]) -> Result<(), EncodingError> {
-    encode_length(uper_buf, length_constraint, value.len())?;
-    for b in value {
-        // for 7, 6, 5, 4, 3, 2, 1, 0
-        for i in (0..8).rev() {
-            let bit = (*b & (1 << i)) != 0;
-            uper_buf.push(bit);
-        }
-    }
-    Ok(())
+pub fn encode_octet_string(uper_buf: &mut BitVec, length_constraint: &WholeNumberConstraint, value: &[u8]) -> Result<(), EncodingError> {
+    let align_items = string_contents_aligned(length_constraint, 8);
+    encode_fragments(uper_buf, length_constraint, value, align_items, |uper_buf, b| {
+        uper_buf.push_bits((*b).into(), 8);
+        Ok(())
+    })
 }
 
 /// Encodes an IA5 string.
 ///
 /// The encoding is specified in X.691 § 17 in conjunction with § 30.5.3.
 #[must_use]
-pub fn encode_ia5_string(uper_buf: &mut Vec<bool>, length_constraint: &WholeNumberConstraint, value: &str) -> Result<(), EncodingError> {
-    encode_length(uper_buf, length_constraint, value.len())?;
-    for (byte_index, b) in value.bytes().enumerate() {
-        // top bit must not be set
-        if b & 0b1000_0000 != 0 {
-            return Err(EncodingError::Ia5ByteHasTopBitSet { string: value.to_owned(), byte_index });
-        }
+pub fn encode_ia5_string(uper_buf: &mut BitVec, length_constraint: &WholeNumberConstraint, value: &str) -> Result<(), EncodingError> {
+    // top bit must not be set
+    if let Some(byte_index) = value.bytes().position(|b| b & 0b1000_0000 != 0) {
+        return Err(EncodingError::Ia5ByteHasTopBitSet { string: value.to_owned(), byte_index });
+    }
+
+    let bits_per_char = ia5_bits_per_char();
+    let align_items = string_contents_aligned(length_constraint, bits_per_char);
+    encode_fragments(uper_buf, length_constraint, value.as_bytes(), align_items, |uper_buf, b| {
+        // the top bit is not encoded in the UNALIGNED variant
+        uper_buf.push_bits((*b).into(), bits_per_char);
+        Ok(())
+    })
+}
+
 
-        // for 6, 5, 4, 3, 2, 1, 0
-        for i in (0..7).rev() {
-            let bit = (b & (1 << i)) != 0;
-            uper_buf.push(bit);
+/// Decodes a bit string.
+///
+/// The encoding is specified in X.691 § 16.
+pub fn decode_bit_string<'a>(bits: BitSlice<'a>, length_constraint: &WholeNumberConstraint) -> ParseResult<'a, BitVec> {
+    let align_items = string_contents_aligned(length_constraint, 1);
+    let mut ret = BitVec::new();
+    let mut rest = bits;
+    loop {
+        let (new_rest, length_integer) = decode_length(rest, length_constraint)?;
+        let length = length_integer.try_to_usize()
+            .ok_or_else(|| nom::Err::Error(DecodingError::new(new_rest, ErrorKind::LengthValueNotUsize { value: length_integer.clone() })))?;
+        let new_rest = if align_items && length > 0 {
+            decode_alignment(new_rest)?
+        } else {
+            new_rest
+        };
+        let (value_bits, new_rest) = split_at_fallible(new_rest, length)?;
+        ret.extend_from_slice(value_bits);
+        rest = new_rest;
+
+        if !length_is_fragmented(length_constraint, &length_integer) {
+            break;
         }
     }
-    Ok(())
+    Ok((rest, ret))
+}
+
+
+/// Encodes a bit string.
+///
+/// The encoding is specified in X.691 § 16.
+pub fn encode_bit_string(uper_buf: &mut BitVec, length_constraint: &WholeNumberConstraint, value: &BitVec) -> Result<(), EncodingError> {
+    let value_bits: Vec<bool> = value.iter().collect();
+    encode_bools_fragmented(uper_buf, length_constraint, &value_bits)
+}
+
+
+/// Encodes a bit string whose type has a list of named bits.
+///
+/// Trailing zero bits are not encoded, but the bit string is not shortened below the lower bound of
+/// its size constraint. This is specified in X.691 § 16.2 and § 16.3.
+pub fn encode_named_bit_string(uper_buf: &mut BitVec, length_constraint: &WholeNumberConstraint, value: &BitVec) -> Result<(), EncodingError> {
+    let mut value_bits: Vec<bool> = value.iter().collect();
+    value_bits.truncate(named_bit_string_length(length_constraint, value));
+    encode_bools_fragmented(uper_buf, length_constraint, &value_bits)
+}
+
+/// Returns the number of bits of a bit string with named bits that are encoded, i.e. its length
+/// without trailing zero bits but at least the lower bound of its size constraint.
+pub fn named_bit_string_length(length_constraint: &WholeNumberConstraint, value: &BitVec) -> usize {
+    let min_length = match length_constraint {
+        WholeNumberConstraint::Constrained { min, .. } => min.try_to_usize().unwrap_or(0),
+        _ => 0,
+    };
+    let used_length = value.iter()
+        .enumerate()
+        .filter_map(|(index, bit)| bit.then_some(index + 1))
+        .last()
+        .unwrap_or(0);
+    used_length.max(min_length).min(value.len())
+}
+
+fn encode_bools_fragmented(uper_buf: &mut BitVec, length_constraint: &WholeNumberConstraint, value_bits: &[bool]) -> Result<(), EncodingError> {
+    let align_items = string_contents_aligned(length_constraint, 1);
+    encode_fragments(uper_buf, length_constraint, value_bits, align_items, |uper_buf, bit| {
+        uper_buf.push(*bit);
+        Ok(())
+    })
+}
+
+
+/// Decodes an object identifier.
+///
+/// The encoding is specified in X.691 § 24; the contents octets are encoded according to X.690
+/// § 8.19.
+pub fn decode_object_identifier<'a>(bits: BitSlice<'a>) -> ParseResult<'a, ObjectIdentifier> {
+    let (rest, contents) = decode_octet_string(bits, &WholeNumberConstraint::Unconstrained)?;
+    match ObjectIdentifier::from_contents(&contents) {
+        Some(oid) => Ok((rest, oid)),
+        None => Err(nom::Err::Error(DecodingError::new(rest, ErrorKind::InvalidObjectIdentifier { contents }))),
+    }
+}
+
+
+/// Encodes an object identifier.
+///
+/// The encoding is specified in X.691 § 24; the contents octets are encoded according to X.690
+/// § 8.19.
+pub fn encode_object_identifier(uper_buf: &mut BitVec, value: &ObjectIdentifier) -> Result<(), EncodingError> {
+    encode_octet_string(uper_buf, &WholeNumberConstraint::Unconstrained, &value.to_contents())
+}
+
+
+/// Decodes a null value, which occupies no bits.
+///
+/// This is specified in X.691 § 18.
+pub fn decode_null<'a>(bits: BitSlice<'a>) -> ParseResult<'a, Null> {
+    Ok((bits, Null {}))
+}
+
+
+/// Encodes a null value, which occupies no bits.
+///
+/// This is specified in X.691 § 18.
+pub fn encode_null(_uper_buf: &mut BitVec, _value: &Null) {
 }
 
 
@@ -736,12 +1248,189 @@ pub fn encode_ia5_string(uper_buf: &mut Vec<bool>, length_constraint: &WholeNumb
 ///
 /// This can be used e.g. to obtain the bit field declaring the presence of optional values in a
 /// SEQUENCE with OPTIONAL values, as expounded upon in X.691 § 19.2.
-pub fn decode_bools<'a>(bits: &'a [bool], count: usize) -> ParseResult<'a, &'a [bool]> {
+pub fn decode_bools<'a>(bits: BitSlice<'a>, count: usize) -> ParseResult<'a, BitSlice<'a>> {
     let (value, rest) = split_at_fallible(bits, count)?;
     Ok((rest, value))
 }
 
 
+/// Decodes a normally small length.
+///
+/// The encoding process is described in X.691 § 11.9.3.4.
+fn decode_normally_small_length<'a>(bits: BitSlice<'a>) -> ParseResult<'a, usize> {
+    let (is_large, rest) = split_at_fallible(bits, 1)?;
+    if is_large.bit(0) {
+        let (rest, length_integer) = decode_length(rest, &WholeNumberConstraint::Unconstrained)?;
+        let length = length_integer.try_to_usize()
+            .ok_or_else(|| nom::Err::Error(DecodingError::new(rest, ErrorKind::LengthValueNotUsize { value: length_integer })))?;
+        Ok((rest, length))
+    } else {
+        // lengths 1 through 64 are encoded as (length - 1) in six bits
+        let (my_bits, rest) = split_at_fallible(rest, 6)?;
+        let length = my_bits.read_u64() as usize + 1;
+        Ok((rest, length))
+    }
+}
+
+
+/// Encodes a normally small length.
+///
+/// The encoding process is described in X.691 § 11.9.3.4.
+fn encode_normally_small_length(uper_buf: &mut BitVec, value: usize) -> Result<(), EncodingError> {
+    if value > 0 && value <= 64 {
+        uper_buf.push(false);
+        encode_nonneg_int(uper_buf, &Integer::from_usize(value - 1), 6)?;
+    } else {
+        uper_buf.push(true);
+        encode_length(uper_buf, &WholeNumberConstraint::Unconstrained, value)?;
+    }
+    Ok(())
+}
+
+
+/// Decodes an open type, returning the octets containing the encoding of its value.
+///
+/// The encoding is specified in X.691 § 11.2.
+pub fn decode_open_type<'a>(bits: BitSlice<'a>) -> ParseResult<'a, Vec<u8>> {
+    decode_octet_string(bits, &WholeNumberConstraint::Unconstrained)
+}
+
+
+/// Encodes an open type from the octets containing the encoding of its value.
+///
+/// The encoding is specified in X.691 § 11.2.
+pub fn encode_open_type(uper_buf: &mut BitVec, contents: &[u8]) -> Result<(), EncodingError> {
+    encode_octet_string(uper_buf, &WholeNumberConstraint::Unconstrained, contents)
+}
+
+
+/// Decodes a value from the octets of an open type.
+///
+/// Errors are reported at the position `rest`, which should be the position after the open type.
+pub fn decode_open_type_contents<'a, T, F>(rest: BitSlice<'a>, contents: &[u8], decode: F) -> Result<T, nom::Err<DecodingError<'a>>>
+    where F: for<'b> FnOnce(BitSlice<'b>) -> ParseResult<'b, T>
+{
+    let bits = BitSlice::new(contents);
+    match trace::with_copied_buffer(bits, rest, || decode(bits)) {
+        Ok((_padding, value)) => Ok(value),
+        Err(nom::Err::Error(e)) => Err(nom::Err::Error(DecodingError { bits: rest, ..e })),
+        Err(nom::Err::Failure(e)) => Err(nom::Err::Failure(DecodingError { bits: rest, ..e })),
+        Err(nom::Err::Incomplete(_)) => Err(nom::Err::Error(DecodingError::new(rest, ErrorKind::Eof {}))),
+    }
+}
+
+
+/// Encodes a value into the octets of an open type.
+///
+/// The encoding is padded to a whole number of octets; an empty encoding is replaced by a single
+/// zero octet (X.691 § 11.2.2 referring to § 11.1.3).
+pub fn encode_open_type_contents<F>(encode: F) -> Result<Vec<u8>, EncodingError>
+    where F: FnOnce(&mut BitVec) -> Result<(), EncodingError>
+{
+    let mut bits = BitVec::new();
+    encode(&mut bits)?;
+    let mut contents = bits.into_bytes();
+    if contents.is_empty() {
+        contents.push(0x00);
+    }
+    Ok(contents)
+}
+
+
+/// Decodes the index of an extension addition of an ENUMERATED or CHOICE type.
+///
+/// The encoding is specified in X.691 § 14.3 and § 23.8 respectively.
+pub fn decode_extension_index<'a>(bits: BitSlice<'a>) -> ParseResult<'a, usize> {
+    let (rest, index) = decode_normally_small_nonneg_int(bits)?;
+    let index_usize = index.try_to_usize()
+        .ok_or_else(|| nom::Err::Error(DecodingError::new(rest, ErrorKind::InvalidIndex { index })))?;
+    Ok((rest, index_usize))
+}
+
+
+/// Encodes the index of an extension addition of an ENUMERATED or CHOICE type.
+///
+/// The encoding is specified in X.691 § 14.3 and § 23.8 respectively.
+pub fn encode_extension_index(uper_buf: &mut BitVec, index: usize) -> Result<(), EncodingError> {
+    encode_normally_small_nonneg_int(uper_buf, &Integer::from_usize(index))
+}
+
+
+/// Decodes the extension additions of a SEQUENCE whose extension bit is set.
+///
+/// Returns the octets of the open type of each extension addition, or `None` if the addition is
+/// absent. The encoding is specified in X.691 § 19.7 through § 19.9.
+pub fn decode_extension_additions<'a>(bits: BitSlice<'a>) -> ParseResult<'a, Vec<Option<Vec<u8>>>> {
+    let (rest, count) = decode_normally_small_length(bits)?;
+    let (mut rest, presence) = decode_bools(rest, count)?;
+    let mut additions = Vec::with_capacity(count);
+    for is_present in presence.iter() {
+        if is_present {
+            let (new_rest, contents) = decode_open_type(rest)?;
+            additions.push(Some(contents));
+            rest = new_rest;
+        } else {
+            additions.push(None);
+        }
+    }
+    Ok((rest, additions))
+}
+
+
+/// Encodes the extension additions of a SEQUENCE whose extension bit is set.
+///
+/// Takes the octets of the open type of each extension addition, or `None` if the addition is
+/// absent. The encoding is specified in X.691 § 19.7 through § 19.9.
+pub fn encode_extension_additions(uper_buf: &mut BitVec, additions: &[Option<Vec<u8>>]) -> Result<(), EncodingError> {
+    encode_normally_small_length(uper_buf, additions.len())?;
+    for addition in additions {
+        uper_buf.push(addition.is_some());
+    }
+    for contents in additions.iter().flatten() {
+        encode_open_type(uper_buf, contents)?;
+    }
+    Ok(())
+}
+
+
+/// The value of the ASN.1 NULL type.
+///
+/// Unlike `()`, this is serialized as an empty structure, so that a present OPTIONAL NULL value
+/// can be told apart from an absent one.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Null {}
+
+
+/// The extension additions of a SEQUENCE value that are not known to the decoder.
+///
+/// Each entry corresponds to an extension addition following the known ones. `None` marks an
+/// absent addition while `Some` contains the octets of the open type encoding of a present one,
+/// allowing the addition to be re-encoded unchanged.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
+pub struct UnknownExtensions(pub Vec<Option<Vec<u8>>>);
+impl UnknownExtensions {
+    /// Takes the extension additions following the first `known_count` ones.
+    pub fn from_additions(mut additions: Vec<Option<Vec<u8>>>, known_count: usize) -> Self {
+        if additions.len() <= known_count {
+            Self::default()
+        } else {
+            Self(additions.split_off(known_count))
+        }
+    }
+
+    /// Appends the unknown extension additions to the given known ones.
+    pub fn append_to(&self, additions: &mut Vec<Option<Vec<u8>>>) {
+        additions.extend(self.0.iter().cloned());
+    }
+
+    /// Returns whether there are no unknown extension additions.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+
 /// Converts a slice of bytes to a vector of bits, most significant bit of each byte first.
 pub fn to_bits_msb_first(bytes: &[u8]) -> Vec<bool> {
     let mut ret = Vec::with_capacity(bytes.len() * 8);
@@ -782,24 +1471,23 @@ pub fn to_bytes_msb_first(bits: &[bool]) -> Vec<u8> {
 }
 
 /// Decodes a slice of bits to bytes.
-fn bits_to_bytes(bits: &[bool], bits_per_byte: usize) -> Vec<u8> {
+fn bits_to_bytes(bits: BitSlice<'_>, bits_per_byte: usize) -> Vec<u8> {
     assert_eq!(bits.len() % bits_per_byte, 0);
+    if bits_per_byte == 8 {
+        return bits.to_bytes();
+    }
     let mut ret = Vec::with_capacity(bits.len() / bits_per_byte);
-    let mut iter = bits.into_iter();
-    while let Some(b7) = iter.next() {
-        let mut byte = if *b7 { 1 << (bits_per_byte - 1) } else { 0 };
-        for shift_count in (0..(bits_per_byte - 1)).rev() {
-            if *iter.next().unwrap() {
-                byte |= 1 << shift_count;
-            }
-        }
-        ret.push(byte);
+    let mut rest = bits;
+    while !rest.is_empty() {
+        let (byte_bits, new_rest) = rest.split_at(bits_per_byte);
+        ret.push(byte_bits.read_u64() as u8);
+        rest = new_rest;
     }
     ret
 }
 
 /// Attempts to convert an octet string into a UTF-8 string.
-pub fn octet_string_to_utf8<'a>(rest: &'a [bool], octet_string: Vec<u8>) -> ParseResult<'a, String> {
+pub fn octet_string_to_utf8<'a>(rest: BitSlice<'a>, octet_string: Vec<u8>) -> ParseResult<'a, String> {
     let utf8_string = String::from_utf8(octet_string)
         .map_err(|e| nom::Err::Error(
             DecodingError::new(rest, ErrorKind::InvalidUtf8String { bytes: e.into_bytes() })
@@ -812,6 +1500,10 @@ pub fn octet_string_to_utf8<'a>(rest: &'a [bool], octet_string: Vec<u8>) -> Pars
 mod tests {
     use super::*;
 
+    fn bit_vec(bits: &[bool]) -> BitVec {
+        bits.iter().copied().collect()
+    }
+
     #[test]
     fn test_to_bits_msb_first() {
         assert_eq!(to_bits_msb_first(&[0x00]), &[false, false, false, false, false, false, false, false]);
@@ -824,28 +1516,28 @@ mod tests {
 
     #[test]
     fn test_decode_nonneg_int_complete() {
-        assert_eq!(decode_nonneg_int_complete(&[false, false, false, false, false, false, false, true ]).unwrap().1, Integer::from_short(1));
-        assert_eq!(decode_nonneg_int_complete(&[false, false, false, false, false, false, true,  true ]).unwrap().1, Integer::from_short(3));
-        assert_eq!(decode_nonneg_int_complete(&[false, true,  true,  true,  true,  true,  true,  true ]).unwrap().1, Integer::from_short(127));
-        assert_eq!(decode_nonneg_int_complete(&[true,  false, false, false, false, false, false, false]).unwrap().1, Integer::from_short(128));
-        assert_eq!(decode_nonneg_int_complete(&[true,  false, false, false, false, false, false, true ]).unwrap().1, Integer::from_short(129));
-        assert_eq!(decode_nonneg_int_complete(&[true,  false, false, false, false, false, true,  true ]).unwrap().1, Integer::from_short(131));
-        assert_eq!(decode_nonneg_int_complete(&[false, true,  false, false, false, false, false, false, false]).unwrap().1, Integer::from_short(128));
-        assert_eq!(decode_nonneg_int_complete(&[false, true,  false, false, false, false, false, false, true ]).unwrap().1, Integer::from_short(129));
-        assert_eq!(decode_nonneg_int_complete(&[false, true,  false, false, false, false, false, true,  true ]).unwrap().1, Integer::from_short(131));
+        assert_eq!(decode_nonneg_int_complete(bit_vec(&[false, false, false, false, false, false, false, true ]).as_slice()).unwrap().1, Integer::from_short(1));
+        assert_eq!(decode_nonneg_int_complete(bit_vec(&[false, false, false, false, false, false, true,  true ]).as_slice()).unwrap().1, Integer::from_short(3));
+        assert_eq!(decode_nonneg_int_complete(bit_vec(&[false, true,  true,  true,  true,  true,  true,  true ]).as_slice()).unwrap().1, Integer::from_short(127));
+        assert_eq!(decode_nonneg_int_complete(bit_vec(&[true,  false, false, false, false, false, false, false]).as_slice()).unwrap().1, Integer::from_short(128));
+        assert_eq!(decode_nonneg_int_complete(bit_vec(&[true,  false, false, false, false, false, false, true ]).as_slice()).unwrap().1, Integer::from_short(129));
+        assert_eq!(decode_nonneg_int_complete(bit_vec(&[true,  false, false, false, false, false, true,  true ]).as_slice()).unwrap().1, Integer::from_short(131));
+        assert_eq!(decode_nonneg_int_complete(bit_vec(&[false, true,  false, false, false, false, false, false, false]).as_slice()).unwrap().1, Integer::from_short(128));
+        assert_eq!(decode_nonneg_int_complete(bit_vec(&[false, true,  false, false, false, false, false, false, true ]).as_slice()).unwrap().1, Integer::from_short(129));
+        assert_eq!(decode_nonneg_int_complete(bit_vec(&[false, true,  false, false, false, false, false, true,  true ]).as_slice()).unwrap().1, Integer::from_short(131));
     }
 
     #[test]
     fn test_decode_unconstrained_int_complete() {
-        assert_eq!(decode_unconstrained_int_complete(&[false, false, false, false, false, false, false, true ]).unwrap().1, Integer::from_short(1));
-        assert_eq!(decode_unconstrained_int_complete(&[false, false, false, false, false, false, true,  true ]).unwrap().1, Integer::from_short(3));
-        assert_eq!(decode_unconstrained_int_complete(&[false, true,  true,  true,  true,  true,  true,  true ]).unwrap().1, Integer::from_short(127));
-        assert_eq!(decode_unconstrained_int_complete(&[true,  false, false, false, false, false, false, false]).unwrap().1, Integer::from_short(-128));
-        assert_eq!(decode_unconstrained_int_complete(&[true,  false, false, false, false, false, false, true ]).unwrap().1, Integer::from_short(-127));
-        assert_eq!(decode_unconstrained_int_complete(&[true,  false, false, false, false, false, true,  true ]).unwrap().1, Integer::from_short(-125));
-        assert_eq!(decode_unconstrained_int_complete(&[false, true,  false, false, false, false, false, false, false]).unwrap().1, Integer::from_short(128));
-        assert_eq!(decode_unconstrained_int_complete(&[false, true,  false, false, false, false, false, false, true ]).unwrap().1, Integer::from_short(129));
-        assert_eq!(decode_unconstrained_int_complete(&[false, true,  false, false, false, false, false, true,  true ]).unwrap().1, Integer::from_short(131));
+        assert_eq!(decode_unconstrained_int_complete(bit_vec(&[false, false, false, false, false, false, false, true ]).as_slice()).unwrap().1, Integer::from_short(1));
+        assert_eq!(decode_unconstrained_int_complete(bit_vec(&[false, false, false, false, false, false, true,  true ]).as_slice()).unwrap().1, Integer::from_short(3));
+        assert_eq!(decode_unconstrained_int_complete(bit_vec(&[false, true,  true,  true,  true,  true,  true,  true ]).as_slice()).unwrap().1, Integer::from_short(127));
+        assert_eq!(decode_unconstrained_int_complete(bit_vec(&[true,  false, false, false, false, false, false, false]).as_slice()).unwrap().1, Integer::from_short(-128));
+        assert_eq!(decode_unconstrained_int_complete(bit_vec(&[true,  false, false, false, false, false, false, true ]).as_slice()).unwrap().1, Integer::from_short(-127));
+        assert_eq!(decode_unconstrained_int_complete(bit_vec(&[true,  false, false, false, false, false, true,  true ]).as_slice()).unwrap().1, Integer::from_short(-125));
+        assert_eq!(decode_unconstrained_int_complete(bit_vec(&[false, true,  false, false, false, false, false, false, false]).as_slice()).unwrap().1, Integer::from_short(128));
+        assert_eq!(decode_unconstrained_int_complete(bit_vec(&[false, true,  false, false, false, false, false, false, true ]).as_slice()).unwrap().1, Integer::from_short(129));
+        assert_eq!(decode_unconstrained_int_complete(bit_vec(&[false, true,  false, false, false, false, false, true,  true ]).as_slice()).unwrap().1, Integer::from_short(131));
     }
 
     #[test]
@@ -860,4 +1552,451 @@ mod tests {
         assert_eq!(bits_required_for_unique_values(&Integer::from_short(8)), 3);
         assert_eq!(bits_required_for_unique_values(&Integer::from_short(9)), 4);
     }
+
+    #[test]
+    fn test_semi_constrained_integer() {
+        let one_min = WholeNumberConstraint::SemiConstrained { min: Integer::from_short(1) };
+        let cases: [(&WholeNumberConstraint, i128, &[u8]); 3] = [
+            (&one_min, 1, &[0x01, 0x00]),
+            (&one_min, 256, &[0x01, 0xFF]),
+            (&WholeNumberConstraint::SemiConstrained { min: Integer::from_short(-10) }, 290, &[0x02, 0x01, 0x2C]),
+        ];
+        for (constraint, value, encoded) in cases {
+            let value = Integer::from_short(value);
+            let mut bits = BitVec::new();
+            encode_integer(&mut bits, constraint, &value).unwrap();
+            assert_eq!(bits.as_slice(), BitSlice::new(encoded));
+
+            let (rest, decoded) = decode_integer(bits.as_slice(), constraint).unwrap();
+            assert_eq!(rest.len(), 0);
+            assert_eq!(decoded, value);
+        }
+
+        let mut bits = BitVec::new();
+        assert!(matches!(
+            encode_integer(&mut bits, &one_min, &Integer::from_short(0)),
+            Err(EncodingError::IntegerOutOfRange { .. }),
+        ));
+        assert!(matches!(
+            encode_integer(&mut bits, &WholeNumberConstraint::Constrained { min: Integer::from_short(0), max: Integer::from_short(5) }, &Integer::from_short(6)),
+            Err(EncodingError::IntegerOutOfRange { .. }),
+        ));
+
+        // 0 to 5 takes three bits, which can also hold 6 and 7
+        let zero_to_five = WholeNumberConstraint::Constrained { min: Integer::from_short(0), max: Integer::from_short(5) };
+        let bytes = [0b1100_0000];
+        let err = match decode_integer(BitSlice::new(&bytes), &zero_to_five) {
+            Err(nom::Err::Error(e)) => e,
+            other => panic!("unexpected result {:?}", other),
+        };
+        assert_eq!(err.kind(), &ErrorKind::IntegerExceedsMaximum { value: Integer::from_short(6) });
+    }
+
+    #[test]
+    fn test_single_value_and_empty_range() {
+        // a single permitted value takes no bits (X.691 § 11.5.4)
+        let only_seven = WholeNumberConstraint::Constrained { min: Integer::from_short(7), max: Integer::from_short(7) };
+        let mut bits = BitVec::new();
+        encode_integer(&mut bits, &only_seven, &Integer::from_short(7)).unwrap();
+        assert_eq!(bits.len(), 0);
+        let bytes = [0xFF];
+        let (rest, decoded) = decode_integer(BitSlice::new(&bytes), &only_seven).unwrap();
+        assert_eq!(rest.len(), 8);
+        assert_eq!(decoded, Integer::from_short(7));
+
+        let empty = WholeNumberConstraint::Constrained { min: Integer::from_short(7), max: Integer::from_short(6) };
+        let err = match decode_integer(BitSlice::new(&bytes), &empty) {
+            Err(nom::Err::Error(e)) => e,
+            other => panic!("unexpected result {:?}", other),
+        };
+        assert_eq!(err.kind(), &ErrorKind::EmptyRange {});
+        assert!(encode_integer(&mut bits, &empty, &Integer::from_short(7)).is_err());
+    }
+
+    #[test]
+    fn test_unconstrained_integer() {
+        let cases: [(i128, &[u8]); 6] = [
+            (0, &[0x01, 0x00]),
+            (-1, &[0x01, 0xFF]),
+            (127, &[0x01, 0x7F]),
+            (128, &[0x02, 0x00, 0x80]),
+            (-129, &[0x02, 0xFF, 0x7F]),
+            (1 << 70, &[0x09, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+        ];
+        for (value, encoded) in cases {
+            let value = Integer::from_short(value);
+            let mut bits = BitVec::new();
+            encode_integer(&mut bits, &WholeNumberConstraint::Unconstrained, &value).unwrap();
+            assert_eq!(bits.as_slice(), BitSlice::new(encoded));
+
+            let (rest, decoded) = decode_integer(bits.as_slice(), &WholeNumberConstraint::Unconstrained).unwrap();
+            assert_eq!(rest.len(), 0);
+            assert_eq!(decoded, value);
+        }
+
+        // constrained integers wider than 64 bits
+        let wide = WholeNumberConstraint::Constrained { min: Integer::from_short(-1), max: Integer::from_short(1 << 100) };
+        let value = Integer::from_short((1 << 99) + 5);
+        let mut bits = BitVec::new();
+        encode_integer(&mut bits, &wide, &value).unwrap();
+        assert_eq!(bits.len(), 101);
+        let (rest, decoded) = decode_integer(bits.as_slice(), &wide).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_constrained_length() {
+        let one_to_three = WholeNumberConstraint::Constrained { min: Integer::from_short(1), max: Integer::from_short(3) };
+
+        let mut bits = BitVec::new();
+        encode_length(&mut bits, &one_to_three, 3).unwrap();
+        assert_eq!(bits.iter().collect::<Vec<bool>>(), [true, false]);
+        let (rest, length) = decode_length(bits.as_slice(), &one_to_three).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(length, Integer::from_short(3));
+
+        // (n - min) = 3 would be a length of 4
+        assert!(decode_length(bit_vec(&[true, true]).as_slice(), &one_to_three).is_err());
+        assert!(matches!(
+            encode_length(&mut bits, &one_to_three, 0),
+            Err(EncodingError::LengthOutOfRange { length: 0, .. }),
+        ));
+        assert!(matches!(
+            encode_length(&mut bits, &one_to_three, 4),
+            Err(EncodingError::LengthOutOfRange { length: 4, .. }),
+        ));
+    }
+
+    #[test]
+    fn test_fragmented_length() {
+        for length in [16383, 16384, 5*16384 + 3, 8*16384] {
+            let value: Vec<u8> = (0..length).map(|i| (i % 251) as u8).collect();
+            let mut bits = BitVec::new();
+            encode_octet_string(&mut bits, &WholeNumberConstraint::Unconstrained, &value).unwrap();
+
+            let header_bits = match length {
+                // 10LL LLLL LLLL LLLL
+                16383 => 16,
+                // 1100 0001, 0000 0000
+                16384 => 16,
+                // 1100 0100, 1100 0001, 0000 0011
+                81923 => 24,
+                // 1100 0100, 1100 0100, 0000 0000
+                131072 => 24,
+                _ => unreachable!(),
+            };
+            assert_eq!(bits.len(), header_bits + 8*length);
+
+            let (rest, decoded) = decode_octet_string(bits.as_slice(), &WholeNumberConstraint::Unconstrained).unwrap();
+            assert_eq!(rest.len(), 0);
+            assert_eq!(decoded, value);
+        }
+
+        // fragments of a list are followed by the remaining items
+        let items: Vec<bool> = (0..16390).map(|i| i % 3 == 0).collect();
+        let mut bits = BitVec::new();
+        encode_fragmented(&mut bits, &WholeNumberConstraint::Unconstrained, &items, |uper_buf, item| {
+            encode_bool(uper_buf, *item);
+            Ok(())
+        }).unwrap();
+        assert_eq!(bits.len(), 8 + 16384 + 8 + 6);
+        let (rest, decoded) = decode_fragmented(bits.as_slice(), &WholeNumberConstraint::Unconstrained, decode_bool).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(decoded, items);
+
+        // a single length determinant cannot hold a length that requires fragmentation
+        assert!(matches!(
+            encode_length(&mut BitVec::new(), &WholeNumberConstraint::Unconstrained, 16385),
+            Err(EncodingError::LengthRequiresFragmentation { length: 16385 }),
+        ));
+    }
+
+    #[test]
+    fn test_bit_string() {
+        let value = bit_vec(&[true, false, true, true, false, false]);
+
+        // unconstrained: length 0000_0110, then the bits
+        let mut bits = BitVec::new();
+        encode_bit_string(&mut bits, &WholeNumberConstraint::Unconstrained, &value).unwrap();
+        assert_eq!(bits.len(), 8 + 6);
+        let (rest, decoded) = decode_bit_string(bits.as_slice(), &WholeNumberConstraint::Unconstrained).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(decoded, value);
+
+        // fixed size: no length
+        let six = WholeNumberConstraint::Constrained { min: Integer::from_short(6), max: Integer::from_short(6) };
+        let mut bits = BitVec::new();
+        encode_bit_string(&mut bits, &six, &value).unwrap();
+        assert_eq!(bits, value);
+        assert_eq!(decode_bit_string(bits.as_slice(), &six).unwrap().1, value);
+
+        // named bits: trailing zeros are dropped, but not below the lower bound
+        let two_to_eight = WholeNumberConstraint::Constrained { min: Integer::from_short(2), max: Integer::from_short(8) };
+        let mut bits = BitVec::new();
+        encode_named_bit_string(&mut bits, &two_to_eight, &value).unwrap();
+        // length (4 - 2) in three bits, then 1011
+        assert_eq!(bits, bit_vec(&[false, true, false, true, false, true, true]));
+        let mut bits = BitVec::new();
+        encode_named_bit_string(&mut bits, &two_to_eight, &bit_vec(&[false; 8])).unwrap();
+        assert_eq!(bits, bit_vec(&[false, false, false, false, false]));
+
+        // fragmented
+        let value: BitVec = (0..16390).map(|i| i % 5 == 0).collect();
+        let mut bits = BitVec::new();
+        encode_bit_string(&mut bits, &WholeNumberConstraint::Unconstrained, &value).unwrap();
+        assert_eq!(bits.len(), 8 + 16384 + 8 + 6);
+        let (rest, decoded) = decode_bit_string(bits.as_slice(), &WholeNumberConstraint::Unconstrained).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_object_identifier_and_null() {
+        let oid: ObjectIdentifier = "1.2.840.10045.4.3.2".parse().unwrap();
+        let mut bits = BitVec::new();
+        encode_object_identifier(&mut bits, &oid).unwrap();
+        encode_null(&mut bits, &Null {});
+        assert_eq!(bits.as_bytes(), [0x08, 0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x04, 0x03, 0x02]);
+
+        let (rest, decoded) = decode_object_identifier(bits.as_slice()).unwrap();
+        assert_eq!(decoded, oid);
+        let (rest, null) = decode_null(rest).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(null, Null {});
+
+        // truncated subidentifier
+        let bytes = [0x02, 0x2A, 0x86];
+        let err = match decode_object_identifier(BitSlice::new(&bytes)) {
+            Err(nom::Err::Error(e)) => e,
+            other => panic!("unexpected result {:?}", other),
+        };
+        assert_eq!(err.kind(), &ErrorKind::InvalidObjectIdentifier { contents: vec![0x2A, 0x86] });
+    }
+
+    #[test]
+    fn test_aligned() {
+        fn constrained(min: i128, max: i128) -> WholeNumberConstraint {
+            WholeNumberConstraint::Constrained { min: Integer::from_short(min), max: Integer::from_short(max) }
+        }
+
+        type Encode<'a> = &'a dyn Fn(&mut BitVec) -> Result<(), EncodingError>;
+
+        // each case is preceded by a single TRUE bit
+        let cases: [(Encode, &[u8], usize); 7] = [
+            // bit-field, not aligned
+            (&|b| encode_integer(b, &constrained(0, 7), &Integer::from_short(5)), &[0b1101_0000], 4),
+            // one octet, aligned
+            (&|b| encode_integer(b, &constrained(0, 255), &Integer::from_short(5)), &[0x80, 0x05], 16),
+            // two octets, aligned
+            (&|b| encode_integer(b, &constrained(0, 65535), &Integer::from_short(300)), &[0x80, 0x01, 0x2C], 24),
+            // number of octets (1 to 4, two bits), then the aligned octets
+            (&|b| encode_integer(b, &constrained(0, 4294967295), &Integer::from_short(256)), &[0b1010_0000, 0x01, 0x00], 24),
+            // aligned length, then the aligned octets
+            (&|b| encode_octet_string(b, &WholeNumberConstraint::Unconstrained, b"AB"), &[0x80, 0x02, 0x41, 0x42], 32),
+            // eight bits per character
+            (&|b| encode_ia5_string(b, &WholeNumberConstraint::Unconstrained, "AB"), &[0x80, 0x02, 0x41, 0x42], 32),
+            // fixed size of at most 16 bits: not aligned
+            (&|b| encode_octet_string(b, &constrained(2, 2), b"AB"), &[0b1010_0000, 0b1010_0001, 0b0000_0000], 17),
+        ];
+        for (encode, expected, bit_count) in cases {
+            let mut bits = BitVec::new();
+            with_variant(PerVariant::Aligned, || {
+                encode_bool(&mut bits, true);
+                encode(&mut bits)
+            }).unwrap();
+            assert_eq!(bits.len(), bit_count);
+            assert_eq!(bits.as_bytes(), expected);
+        }
+
+        let bytes = [0x80, 0x01, 0x2C];
+        let (rest, value) = with_variant(PerVariant::Aligned, || {
+            let (rest, _flag) = decode_bool(BitSlice::new(&bytes))?;
+            decode_integer(rest, &constrained(0, 65535))
+        }).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(value, Integer::from_short(300));
+        assert_eq!(current_variant(), PerVariant::Unaligned);
+
+        let bytes = [0b1010_0000, 0x01, 0x00];
+        let (_rest, value) = with_variant(PerVariant::Aligned, || {
+            let (rest, _flag) = decode_bool(BitSlice::new(&bytes))?;
+            decode_integer(rest, &constrained(0, 4294967295))
+        }).unwrap();
+        assert_eq!(value, Integer::from_short(256));
+
+        // generated types: not extended; carrierNum and nutsCode present; padding;
+        // carrierNum: 1000 - 1; nutsCode: length 4, "AT13"
+        use crate::uflex_3::ZoneType;
+        let bytes = [0x40, 0x10, 0x03, 0xE7, 0x04, 0x41, 0x54, 0x31, 0x33];
+        let (rest, zone) = ZoneType::try_from_per(BitSlice::new(&bytes), PerVariant::Aligned).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(zone.carrier_num, Some(Integer::from_short(1000)));
+        assert_eq!(zone.nuts_code.as_deref(), Some("AT13"));
+        let mut bits = BitVec::new();
+        zone.write_per(&mut bits, PerVariant::Aligned).unwrap();
+        assert_eq!(bits.as_bytes(), bytes);
+
+        // without padding and with seven bits per character
+        let mut bits = BitVec::new();
+        zone.write_per(&mut bits, PerVariant::Unaligned).unwrap();
+        assert_eq!(bits.len(), 12 + 15 + 8 + 4*7);
+        assert_eq!(ZoneType::try_from_uper(bits.as_slice()).unwrap().1, zone);
+    }
+
+    #[test]
+    fn test_extension_additions() {
+        let additions = vec![
+            None,
+            Some(encode_open_type_contents(|uper_buf| { encode_bool(uper_buf, true); Ok(()) }).unwrap()),
+            Some(encode_open_type_contents(|_uper_buf| Ok(())).unwrap()),
+        ];
+        assert_eq!(additions[1], Some(vec![0x80]));
+        assert_eq!(additions[2], Some(vec![0x00]));
+
+        let mut bits = BitVec::new();
+        encode_extension_additions(&mut bits, &additions).unwrap();
+        // normally small length (0 + 000010), presence bitmap (011), two open types (8 + 8 bits each)
+        assert_eq!(bits.len(), 7 + 3 + 16 + 16);
+
+        let (rest, decoded) = decode_extension_additions(bits.as_slice()).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(decoded, additions);
+
+        let value = decode_open_type_contents(rest, decoded[1].as_ref().unwrap(), decode_bool).unwrap();
+        assert!(value);
+        assert!(decode_open_type_contents(rest, &[], decode_bool).is_err());
+
+        let unknown = UnknownExtensions::from_additions(decoded, 1);
+        assert_eq!(unknown.0, &additions[1..]);
+    }
+
+    #[test]
+    fn test_unknown_extension_alternatives() {
+        use crate::uflex_3::{RegionalValidityType, TravelClassType};
+
+        // extension bit, normally small extension index 20
+        let bytes = [0b1001_0100];
+        let bits = BitSlice::new(&bytes);
+        let (rest, value) = TravelClassType::try_from_uper(bits).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(value, TravelClassType::Unknown(20));
+        let mut encoded = BitVec::new();
+        value.write_uper(&mut encoded).unwrap();
+        assert_eq!(encoded.as_slice(), bits);
+
+        // extension bit, normally small extension index 7, open type of length 1, padding
+        let bytes = [0b1000_0111, 0b0000_0001, 0xAB];
+        let bits = BitSlice::new(&bytes);
+        let (rest, value) = RegionalValidityType::try_from_uper(bits).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(value, RegionalValidityType::Unknown { index: 7, contents: vec![0xAB] });
+        let mut encoded = BitVec::new();
+        value.write_uper(&mut encoded).unwrap();
+        assert_eq!(encoded.as_slice(), bits);
+
+        // extension bit, large normally small extension index: length 1 (octets), 64, padding
+        let bytes = [0b1100_0000, 0b0101_0000, 0b0000_0000];
+        let bits = BitSlice::new(&bytes);
+        let (rest, value) = TravelClassType::try_from_uper(bits).unwrap();
+        assert_eq!(rest.len(), 6);
+        assert_eq!(value, TravelClassType::Unknown(64));
+        let mut encoded = BitVec::new();
+        value.write_uper(&mut encoded).unwrap();
+        assert_eq!(encoded.as_slice(), bits.split_at(18).0);
+
+        // root index 12 is out of range for TravelClassType (0 to 11)
+        let bytes = [0b0110_0000];
+        let bits = BitSlice::new(&bytes);
+        assert!(TravelClassType::try_from_uper(bits).is_err());
+    }
+
+    #[test]
+    fn test_derive() {
+        #[derive(Clone, Debug, PartialEq, UperDecode, UperEncode)]
+        struct Reading {
+            #[uper(min = -8, max = 7)]
+            offset: Integer,
+            #[uper(default = 3, min = 0, max = 3)]
+            level: Integer,
+            #[uper(optional, size(max = 3), element(ia5, size(min = 1, max = 1)))]
+            tags: Vec<String>,
+            #[uper(extension)]
+            note: Option<bool>,
+            #[uper(unknown)]
+            unknown_extensions: UnknownExtensions,
+        }
+
+        let reading = Reading {
+            offset: Integer::from_short(-3),
+            level: Integer::from_short(3),
+            tags: vec!["A".to_owned()],
+            note: Some(true),
+            unknown_extensions: UnknownExtensions::default(),
+        };
+        // extension bit, presence bits (level absent, tags present), offset 5 (4 bits), tags count 1
+        // (2 bits), 'A' (7 bits), one extension addition (7 bits + bitmap), open type of length 1
+        let bytes = [0b1010_1010, 0b1100_0001, 0b0000_0001, 0b0000_0001, 0b1000_0000];
+        let mut encoded = BitVec::new();
+        reading.write_uper(&mut encoded).unwrap();
+        assert_eq!(encoded.as_slice(), BitSlice::new(&bytes));
+        let (rest, decoded) = Reading::try_from_uper(BitSlice::new(&bytes)).unwrap();
+        assert_eq!(rest.len(), 0);
+        assert_eq!(decoded, reading);
+
+        // no extensions, level and tags absent, offset -8
+        let bytes = [0b0000_0000];
+        let (rest, decoded) = Reading::try_from_uper(BitSlice::new(&bytes)).unwrap();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(decoded.offset, Integer::from_short(-8));
+        assert_eq!(decoded.level, Integer::from_short(3));
+        assert_eq!(decoded.tags, Vec::<String>::new());
+        assert_eq!(decoded.note, None);
+    }
+
+    #[test]
+    fn test_unknown_value_with_known_index() {
+        #[derive(Clone, Debug, PartialEq, UperDecode, UperEncode)]
+        enum Mode {
+            Walk,
+            Ride,
+            #[uper(extension)]
+            Fly,
+            #[uper(unknown)]
+            Unknown(usize),
+        }
+
+        #[derive(Clone, Debug, PartialEq, UperDecode, UperEncode)]
+        enum Payload {
+            Flag(bool),
+            #[uper(extension)]
+            Level(bool),
+            #[uper(unknown)]
+            Unknown { index: usize, contents: Vec<u8> },
+        }
+
+        // index 0 is the known addition and would be decoded as such
+        let mut encoded = BitVec::new();
+        assert!(matches!(
+            Mode::Unknown(0).write_uper(&mut encoded),
+            Err(EncodingError::UnknownValueHasKnownIndex { index: 0, known_count: 1 }),
+        ));
+        let mut encoded = BitVec::new();
+        assert!(matches!(
+            Payload::Unknown { index: 0, contents: vec![0x80] }.write_uper(&mut encoded),
+            Err(EncodingError::UnknownValueHasKnownIndex { index: 0, known_count: 1 }),
+        ));
+
+        // index 1 follows the known addition
+        let mut encoded = BitVec::new();
+        Mode::Unknown(1).write_uper(&mut encoded).unwrap();
+        let (_rest, decoded) = Mode::try_from_uper(encoded.as_slice()).unwrap();
+        assert_eq!(decoded, Mode::Unknown(1));
+
+        let value = Payload::Unknown { index: 1, contents: vec![0x80] };
+        let mut encoded = BitVec::new();
+        value.write_uper(&mut encoded).unwrap();
+        let (_rest, decoded) = Payload::try_from_uper(encoded.as_slice()).unwrap();
+        assert_eq!(decoded, value);
+    }
 }
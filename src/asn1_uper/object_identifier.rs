@@ -0,0 +1,172 @@
+//! ASN.1 object identifiers.
+
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error as _;
+
+
+/// An ASN.1 object identifier, e.g. `1.2.840.10045.4.3.2` (ECDSA with SHA-256).
+///
+/// Object identifiers always consist of at least two arcs. The first arc is 0, 1 or 2; if it is 0
+/// or 1, the second arc is less than 40.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ObjectIdentifier {
+    arcs: Vec<u128>,
+}
+impl ObjectIdentifier {
+    /// Creates an object identifier from the given arcs, returning `None` if they do not form a
+    /// valid object identifier.
+    pub fn new(arcs: Vec<u128>) -> Option<Self> {
+        if arcs.len() < 2 {
+            return None;
+        }
+        match arcs[0] {
+            0 | 1 => if arcs[1] >= 40 { return None },
+            // the first two arcs are encoded together as a single subidentifier
+            2 => { arcs[1].checked_add(80)?; },
+            _ => return None,
+        }
+        Some(Self { arcs })
+    }
+
+    /// The arcs of the object identifier.
+    pub fn arcs(&self) -> &[u128] { &self.arcs }
+
+    /// Decodes an object identifier from the contents octets of its BER encoding, returning `None`
+    /// if they are not a valid encoding.
+    ///
+    /// The encoding is specified in X.690 § 8.19.
+    pub fn from_contents(contents: &[u8]) -> Option<Self> {
+        let mut subidentifiers = Vec::new();
+        let mut current: u128 = 0;
+        let mut in_subidentifier = false;
+        for &b in contents {
+            if !in_subidentifier && b == 0x80 {
+                // X.690 § 8.19.2: subidentifiers are encoded in the fewest possible octets
+                return None;
+            }
+            current = current.checked_mul(128)? | u128::from(b & 0x7F);
+            in_subidentifier = b & 0x80 != 0;
+            if !in_subidentifier {
+                subidentifiers.push(current);
+                current = 0;
+            }
+        }
+        if in_subidentifier || subidentifiers.is_empty() {
+            // truncated or empty
+            return None;
+        }
+
+        let first = subidentifiers[0];
+        let mut arcs = Vec::with_capacity(subidentifiers.len() + 1);
+        match first {
+            0..=39 => arcs.extend([0, first]),
+            40..=79 => arcs.extend([1, first - 40]),
+            _ => arcs.extend([2, first - 80]),
+        }
+        arcs.extend_from_slice(&subidentifiers[1..]);
+        Some(Self { arcs })
+    }
+
+    /// Encodes the object identifier into the contents octets of its BER encoding.
+    ///
+    /// The encoding is specified in X.690 § 8.19.
+    pub fn to_contents(&self) -> Vec<u8> {
+        let mut ret = Vec::new();
+        // the first two arcs have been checked in new()
+        let first = self.arcs[0] * 40 + self.arcs[1];
+        for &subidentifier in std::iter::once(&first).chain(&self.arcs[2..]) {
+            // base 128, most significant group first, all but the last octet with the top bit set
+            let group_count = (128 - subidentifier.leading_zeros() as usize).div_ceil(7);
+            for i in (0..group_count.max(1)).rev() {
+                let group = ((subidentifier >> (7 * i)) & 0x7F) as u8;
+                ret.push(if i > 0 { group | 0x80 } else { group });
+            }
+        }
+        ret
+    }
+}
+impl fmt::Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arc) in self.arcs.iter().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", arc)?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for ObjectIdentifier {
+    type Err = ParseObjectIdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let arcs = s.split('.')
+            .map(|arc| arc.parse())
+            .collect::<Result<Vec<u128>, _>>()
+            .map_err(|_| ParseObjectIdentifierError)?;
+        Self::new(arcs)
+            .ok_or(ParseObjectIdentifierError)
+    }
+}
+impl Serialize for ObjectIdentifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+impl<'de> Deserialize<'de> for ObjectIdentifier {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        string.parse().map_err(D::Error::custom)
+    }
+}
+
+
+/// An error that occurs when parsing an object identifier in dotted notation.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParseObjectIdentifierError;
+impl fmt::Display for ParseObjectIdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid object identifier")
+    }
+}
+impl std::error::Error for ParseObjectIdentifierError {
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_identifier() {
+        // ECDSA with SHA-256
+        let contents = [0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x04, 0x03, 0x02];
+        let oid = ObjectIdentifier::from_contents(&contents).unwrap();
+        assert_eq!(oid.arcs(), [1, 2, 840, 10045, 4, 3, 2]);
+        assert_eq!(oid.to_string(), "1.2.840.10045.4.3.2");
+        assert_eq!(oid.to_contents(), contents);
+        assert_eq!("1.2.840.10045.4.3.2".parse::<ObjectIdentifier>().unwrap(), oid);
+
+        // below arc 2, the second arc may exceed 39
+        let oid = ObjectIdentifier::new(vec![2, 999, 3]).unwrap();
+        assert_eq!(oid.to_contents(), [0x88, 0x37, 0x03]);
+        assert_eq!(ObjectIdentifier::from_contents(&[0x88, 0x37, 0x03]).unwrap(), oid);
+        assert_eq!(ObjectIdentifier::new(vec![2, 0]).unwrap().to_contents(), [0x50]);
+        assert_eq!(ObjectIdentifier::new(vec![0, 0]).unwrap().to_contents(), [0x00]);
+
+        assert_eq!(ObjectIdentifier::new(vec![1]), None);
+        assert_eq!(ObjectIdentifier::new(vec![1, 40]), None);
+        assert_eq!(ObjectIdentifier::new(vec![3, 1]), None);
+        assert!("1.2.x".parse::<ObjectIdentifier>().is_err());
+        assert!("".parse::<ObjectIdentifier>().is_err());
+
+        // empty, truncated and non-minimal encodings
+        assert_eq!(ObjectIdentifier::from_contents(&[]), None);
+        assert_eq!(ObjectIdentifier::from_contents(&[0x2A, 0x86]), None);
+        assert_eq!(ObjectIdentifier::from_contents(&[0x2A, 0x80, 0x01]), None);
+    }
+}
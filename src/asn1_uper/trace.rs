@@ -0,0 +1,414 @@
+//! Tracing of decoded fields.
+//!
+//! When decoding within [`trace_decode`], each field decoded by the generated `try_from_uper`
+//! functions is recorded with its path, its position within the bit stream, its raw bits and (for
+//! fields without subfields) its decoded value. This allows diagnosing payloads that fail to decode
+//! or decode to unexpected values.
+//!
+//! Tracing state is kept per thread; outside of [`trace_decode`], the tracing functions simply call
+//! the decoding function. In both cases, they add the path of the field to any decoding error.
+
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt::{self, Write as _};
+
+use crate::asn1_uper::{BitSlice, BitVec, Integer, ParseResult};
+
+
+/// The maximum number of raw bits output per field when rendering a field map.
+const MAX_RENDERED_BITS: usize = 64;
+
+
+/// A segment of the path to a field.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PathSegment {
+    /// A member of a SEQUENCE or an alternative of a CHOICE.
+    Member(&'static str),
+
+    /// An element of a SEQUENCE OF.
+    Index(usize),
+}
+
+
+/// A field that has been decoded while tracing.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TracedField {
+    /// The path to the field, starting at the outermost type.
+    pub path: Vec<PathSegment>,
+
+    /// The offset of the first bit of the field within the bit stream.
+    pub offset: usize,
+
+    /// The number of bits occupied by the field.
+    ///
+    /// If decoding the field has failed, this is the number of bits up to the failure.
+    pub length: usize,
+
+    /// The raw bits of the field.
+    pub bits: BitVec,
+
+    /// The decoded value of the field in its `Debug` representation.
+    ///
+    /// Only stored for fields without traced subfields, i.e. the leaves of the field tree.
+    pub value: Option<String>,
+
+    /// The error that occurred when decoding the field, if any.
+    ///
+    /// Only stored for the innermost field whose decoding has failed.
+    pub error: Option<String>,
+}
+impl TracedField {
+    /// Returns the path to the field in ASN.1 notation, e.g.
+    /// `transportDocument[0].ticket.openTicket.fromStationNum`.
+    pub fn path_string(&self) -> String {
+        path_to_string(&self.path)
+    }
+}
+
+
+/// Returns the given path in ASN.1 notation, e.g.
+/// `transportDocument[0].ticket.openTicket.fromStationNum`.
+pub fn path_to_string<'p, I: IntoIterator<Item = &'p PathSegment>>(path: I) -> String {
+    let mut ret = String::new();
+    for segment in path {
+        match segment {
+            PathSegment::Member(name) => {
+                if !ret.is_empty() {
+                    ret.push('.');
+                }
+                ret.push_str(name);
+            },
+            PathSegment::Index(index) => {
+                write!(ret, "[{}]", index).unwrap();
+            },
+        }
+    }
+    ret
+}
+
+
+/// The fields decoded from a bit stream while tracing.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FieldMap {
+    /// The bit stream that has been decoded.
+    pub bits: BitVec,
+
+    /// The decoded fields, each one preceding its subfields.
+    pub fields: Vec<TracedField>,
+}
+impl fmt::Display for FieldMap {
+    /// Renders the field map as an annotated dump.
+    ///
+    /// The dump starts with a hex dump of the bit stream, followed by one line per field, which
+    /// contains its bit offset, its length, its path, its raw bits and its value. Bits that are not
+    /// attributed to any field (e.g. presence bitmaps and extension bits) are output separately.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (line_index, line) in self.bits.as_bytes().chunks(16).enumerate() {
+            write!(f, "{:06X}:", line_index * 16)?;
+            for b in line {
+                write!(f, " {:02X}", b)?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f)?;
+
+        let bits = self.bits.as_slice();
+        let mut cursor = 0;
+        for field in &self.fields {
+            if field.offset > cursor && field.offset <= bits.len() {
+                let unattributed = bits.split_at(field.offset).0.split_at(cursor).1;
+                write_field_line(f, cursor, unattributed, "(unattributed)", None, None)?;
+            }
+
+            let path = field.path_string();
+            write_field_line(f, field.offset, field.bits.as_slice(), &path, field.value.as_deref(), field.error.as_deref())?;
+
+            cursor = if field.value.is_some() || field.error.is_some() {
+                field.offset + field.length
+            } else {
+                field.offset
+            };
+        }
+        if cursor < bits.len() {
+            write_field_line(f, cursor, bits.split_at(cursor).1, "(unattributed)", None, None)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_field_line(f: &mut fmt::Formatter<'_>, offset: usize, bits: BitSlice<'_>, path: &str, value: Option<&str>, error: Option<&str>) -> fmt::Result {
+    write!(f, "{:>6} +{:<5} {}", offset, bits.len(), path)?;
+    if let Some(value) = value {
+        write!(f, " = {}", value)?;
+    }
+    if let Some(error) = error {
+        write!(f, " !! {}", error)?;
+    }
+    if value.is_some() || error.is_some() || path.starts_with('(') {
+        write!(f, " [")?;
+        for bit in bits.iter().take(MAX_RENDERED_BITS) {
+            f.write_char(if bit { '1' } else { '0' })?;
+        }
+        if bits.len() > MAX_RENDERED_BITS {
+            write!(f, "...")?;
+        }
+        write!(f, "]")?;
+    }
+    writeln!(f)
+}
+
+
+struct Buffer {
+    start: usize,
+    length: usize,
+    offset: usize,
+}
+
+struct Tracer {
+    buffers: Vec<Buffer>,
+    path: Vec<PathSegment>,
+    fields: Vec<TracedField>,
+}
+impl Tracer {
+    fn offset_of(&self, bits: BitSlice<'_>) -> usize {
+        let address = bits.bit_address();
+        for buffer in self.buffers.iter().rev() {
+            if address >= buffer.start && address <= buffer.start + buffer.length {
+                return buffer.offset + (address - buffer.start);
+            }
+        }
+        0
+    }
+}
+
+thread_local! {
+    static TRACER: RefCell<Option<Tracer>> = const { RefCell::new(None) };
+}
+
+/// Restores the previous tracer when dropped, even if decoding panics.
+struct TracerGuard {
+    previous: Option<Tracer>,
+}
+impl Drop for TracerGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        TRACER.with(|t| *t.borrow_mut() = previous);
+    }
+}
+
+
+/// Decodes a value while recording every decoded field.
+pub fn trace_decode<'a, T, F>(bits: BitSlice<'a>, decode: F) -> (FieldMap, ParseResult<'a, T>)
+    where F: FnOnce(BitSlice<'a>) -> ParseResult<'a, T>
+{
+    let tracer = Tracer {
+        buffers: vec![Buffer { start: bits.bit_address(), length: bits.len(), offset: 0 }],
+        path: Vec::new(),
+        fields: Vec::new(),
+    };
+    let guard = TracerGuard {
+        previous: TRACER.with(|t| t.borrow_mut().replace(tracer)),
+    };
+
+    let result = decode(bits);
+
+    let tracer = TRACER.with(|t| t.borrow_mut().take())
+        .expect("tracer vanished while decoding");
+    drop(guard);
+
+    let field_map = FieldMap {
+        bits: bits.to_bit_vec(),
+        fields: tracer.fields,
+    };
+    (field_map, result)
+}
+
+
+/// Decodes a member of a SEQUENCE or an alternative of a CHOICE, tracing it if requested.
+///
+/// If decoding fails, the member is added to the path of the error.
+pub fn field<'a, T, F>(name: &'static str, bits: BitSlice<'a>, decode: F) -> ParseResult<'a, T>
+    where T: fmt::Debug + Any, F: FnOnce(BitSlice<'a>) -> ParseResult<'a, T>
+{
+    traced(PathSegment::Member(name), bits, decode)
+}
+
+
+/// Decodes an element of a SEQUENCE OF, tracing it if requested.
+///
+/// If decoding fails, the element is added to the path of the error.
+pub fn element<'a, T, F>(index: usize, bits: BitSlice<'a>, decode: F) -> ParseResult<'a, T>
+    where T: fmt::Debug + Any, F: FnOnce(BitSlice<'a>) -> ParseResult<'a, T>
+{
+    traced(PathSegment::Index(index), bits, decode)
+}
+
+
+fn traced<'a, T, F>(segment: PathSegment, bits: BitSlice<'a>, decode: F) -> ParseResult<'a, T>
+    where T: fmt::Debug + Any, F: FnOnce(BitSlice<'a>) -> ParseResult<'a, T>
+{
+    let decode = |bits| decode(bits)
+        .map_err(|e| e.map(|e| e.in_field(segment)));
+
+    let field_index = TRACER.with(|t| {
+        let mut tracer_opt = t.borrow_mut();
+        let tracer = tracer_opt.as_mut()?;
+        tracer.path.push(segment);
+        let offset = tracer.offset_of(bits);
+        tracer.fields.push(TracedField {
+            path: tracer.path.clone(),
+            offset,
+            length: 0,
+            bits: BitVec::new(),
+            value: None,
+            error: None,
+        });
+        Some(tracer.fields.len() - 1)
+    });
+    let Some(field_index) = field_index else {
+        // not tracing
+        return decode(bits);
+    };
+
+    let result = decode(bits);
+
+    TRACER.with(|t| {
+        let mut tracer_opt = t.borrow_mut();
+        let Some(tracer) = tracer_opt.as_mut() else { return };
+        tracer.path.pop();
+
+        let (end, error) = match &result {
+            Ok((rest, _value)) => (tracer.offset_of(*rest), None),
+            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => (tracer.offset_of(e.bits()), Some(e.kind().to_string())),
+            Err(nom::Err::Incomplete(_)) => (tracer.fields[field_index].offset, Some("incomplete".to_owned())),
+        };
+        let is_leaf = field_index == tracer.fields.len() - 1;
+
+        let field = &mut tracer.fields[field_index];
+        field.length = end.saturating_sub(field.offset);
+        let consumed = field.length.min(bits.len());
+        field.bits = bits.split_at(consumed).0.to_bit_vec();
+        if is_leaf {
+            // errors are attributed to the innermost field
+            field.error = error;
+            if let Ok((_rest, value)) = &result {
+                field.value = Some(describe_value(value));
+            }
+        }
+    });
+
+    result
+}
+
+
+fn describe_value<T: fmt::Debug + Any>(value: &T) -> String {
+    let any: &dyn Any = value;
+    if let Some(integer) = any.downcast_ref::<Integer>() {
+        integer.to_string()
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+
+/// Runs the given decoding function on a buffer that contains a copy of some bits of the bit
+/// stream, e.g. the octets of an open type, so that fields within it are traced at the correct
+/// offsets.
+///
+/// `end` is the position in the original bit stream right after the copied bits.
+pub(crate) fn with_copied_buffer<R, F: FnOnce() -> R>(copy: BitSlice<'_>, end: BitSlice<'_>, decode: F) -> R {
+    let pushed = TRACER.with(|t| {
+        let mut tracer_opt = t.borrow_mut();
+        let Some(tracer) = tracer_opt.as_mut() else { return false };
+        let offset = tracer.offset_of(end).saturating_sub(copy.len());
+        tracer.buffers.push(Buffer { start: copy.bit_address(), length: copy.len(), offset });
+        true
+    });
+
+    let result = decode();
+
+    if pushed {
+        TRACER.with(|t| {
+            if let Some(tracer) = t.borrow_mut().as_mut() {
+                tracer.buffers.pop();
+            }
+        });
+    }
+    result
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asn1_uper::{
+        decode_bool, decode_fragmented, decode_integer, DecodingError, ErrorKind, WholeNumberConstraint,
+    };
+
+    fn decode_sample(bits: BitSlice<'_>) -> ParseResult<'_, (bool, Vec<Integer>)> {
+        let (rest, flag) = field("flag", bits, decode_bool)?;
+        let (rest, list) = field("list", rest, |rest| decode_fragmented(rest, &WholeNumberConstraint::Unconstrained, |rest| {
+            decode_integer(rest, &WholeNumberConstraint::Constrained { min: Integer::from_short(0), max: Integer::from_short(7) })
+        }))?;
+        Ok((rest, (flag, list)))
+    }
+
+    #[test]
+    fn test_trace_decode() {
+        // flag: 1; list: length 0000_0010, 101, 011
+        let bits: BitVec = "1000000101010110".chars().map(|c| c == '1').collect();
+        let (field_map, result) = trace_decode(bits.as_slice(), decode_sample);
+        let (rest, (flag, list)) = result.unwrap();
+        assert_eq!(rest.len(), 1);
+        assert!(flag);
+        assert_eq!(list, [Integer::from_short(5), Integer::from_short(3)]);
+
+        let summary: Vec<(String, usize, usize, Option<&str>)> = field_map.fields.iter()
+            .map(|f| (f.path_string(), f.offset, f.length, f.value.as_deref()))
+            .collect();
+        assert_eq!(summary, [
+            ("flag".to_owned(), 0, 1, Some("true")),
+            ("list".to_owned(), 1, 14, None),
+            ("list[0]".to_owned(), 9, 3, Some("5")),
+            ("list[1]".to_owned(), 12, 3, Some("3")),
+        ]);
+        assert_eq!(field_map.fields[2].bits.iter().collect::<Vec<bool>>(), [true, false, true]);
+
+        let rendered = field_map.to_string();
+        assert!(rendered.contains("     1 +8     (unattributed) [00000010]\n"));
+        assert!(rendered.contains("     9 +3     list[0] = 5 [101]\n"));
+
+        // outside of trace_decode, nothing is recorded
+        assert!(decode_sample(bits.as_slice()).is_ok());
+        let (field_map, result) = trace_decode(bits.as_slice().split_at(10).0, decode_sample);
+        assert!(result.is_err());
+        assert_eq!(field_map.fields.len(), 3);
+        assert!(field_map.fields[2].error.is_some());
+    }
+
+    #[test]
+    fn test_error_context() {
+        // flag: 1; list: length 0000_0010, 101, then the second element is cut off
+        let bits: BitVec = "100000010101".chars().map(|c| c == '1').collect();
+        let err = match decode_sample(bits.as_slice()) {
+            Err(nom::Err::Error(e)) => e,
+            other => panic!("unexpected result {:?}", other),
+        };
+        assert_eq!(err.path(), [PathSegment::Member("list"), PathSegment::Index(1)]);
+        assert_eq!(err.type_name(), None);
+        assert_eq!(err.offset(), None);
+        assert_eq!(err.to_string(), "end of file while decoding list[1]");
+
+        let err = err.in_type("Inner").in_type("Sample").at_offset_from(bits.as_slice());
+        assert_eq!(err.type_name(), Some("Sample"));
+        assert_eq!(err.offset(), Some(12));
+        assert_eq!(err.to_string(), "end of file while decoding Sample.list[1] at bit 12");
+
+        // bits from another buffer do not yield an offset
+        let other_bits = bits.clone();
+        let err = DecodingError::new(bits.as_slice().split_at(3).1, ErrorKind::Eof {});
+        assert_eq!(err.clone().at_offset_from(other_bits.as_slice()).offset(), None);
+        assert_eq!(err.at_offset_from(bits.as_slice()).offset(), Some(3));
+    }
+}
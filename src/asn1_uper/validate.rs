@@ -0,0 +1,141 @@
+//! Validation of values against the constraints of their types before encoding.
+//!
+//! Encoding stops at the first value violating a constraint and does not say where that value is.
+//! The `validate` functions generated by the `UperEncode` derive macro instead check the whole value
+//! and return each violation along with the path to the offending field.
+
+
+use std::fmt;
+
+use crate::asn1_uper::{EncodingError, Integer, WholeNumberConstraint};
+use crate::asn1_uper::trace::{path_to_string, PathSegment};
+
+
+/// The kind of constraint that has been violated.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum ViolationKind {
+    /// The value would be rejected by the encoder with the given error.
+    Encoding(EncodingError),
+
+    /// The member is present along with another member which is mutually exclusive with it.
+    ///
+    /// The UIC schemas contain many pairs such as `fromStationNum` and `fromStationIA5`, of which
+    /// at most one may be given.
+    MutuallyExclusive { other: &'static str },
+}
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Encoding(e)
+                => write!(f, "{}", e),
+            Self::MutuallyExclusive { other }
+                => write!(f, "member must not be present at the same time as {}", other),
+        }
+    }
+}
+
+
+/// A violation of a constraint by a field.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ConstraintViolation {
+    /// The path to the field, starting at the validated type.
+    pub path: Vec<PathSegment>,
+
+    pub kind: ViolationKind,
+}
+impl ConstraintViolation {
+    pub fn new(path: &[PathSegment], kind: ViolationKind) -> Self {
+        Self {
+            path: path.to_vec(),
+            kind,
+        }
+    }
+
+    /// Returns the path to the field in ASN.1 notation, e.g.
+    /// `transportDocument[0].ticket.openTicket.fromStationNum`.
+    pub fn path_string(&self) -> String {
+        path_to_string(&self.path)
+    }
+}
+impl fmt::Display for ConstraintViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "(top level): {}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.path_string(), self.kind)
+        }
+    }
+}
+impl std::error::Error for ConstraintViolation {
+}
+
+
+/// Checks that an integer is within the range permitted by the constraint.
+pub fn check_integer(path: &[PathSegment], violations: &mut Vec<ConstraintViolation>, constraint: &WholeNumberConstraint, value: &Integer) {
+    let (min, max) = match constraint {
+        WholeNumberConstraint::Constrained { min, max } => (Some(min), Some(max)),
+        WholeNumberConstraint::SemiConstrained { min } => (Some(min), None),
+        _ => (None, None),
+    };
+    if min.is_some_and(|min| value < min) || max.is_some_and(|max| value > max) {
+        let error = EncodingError::IntegerOutOfRange { value: value.clone(), min: min.cloned(), max: max.cloned() };
+        violations.push(ConstraintViolation::new(path, ViolationKind::Encoding(error)));
+    }
+}
+
+
+/// Checks that the length of a string or SEQUENCE OF is within the range permitted by its SIZE
+/// constraint.
+pub fn check_length(path: &[PathSegment], violations: &mut Vec<ConstraintViolation>, constraint: &WholeNumberConstraint, length: usize) {
+    if let WholeNumberConstraint::Constrained { min, max } = constraint {
+        let length_integer = Integer::from_usize(length);
+        if &length_integer < min || &length_integer > max {
+            let error = EncodingError::LengthOutOfRange { length, min: min.clone(), max: max.clone() };
+            violations.push(ConstraintViolation::new(path, ViolationKind::Encoding(error)));
+        }
+    }
+}
+
+
+/// Checks that a string only consists of IA5 characters.
+pub fn check_ia5(path: &[PathSegment], violations: &mut Vec<ConstraintViolation>, value: &str) {
+    if let Some(byte_index) = value.bytes().position(|b| b & 0b1000_0000 != 0) {
+        let error = EncodingError::Ia5ByteHasTopBitSet { string: value.to_owned(), byte_index };
+        violations.push(ConstraintViolation::new(path, ViolationKind::Encoding(error)));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checks() {
+        let path = [PathSegment::Member("carrierIA5"), PathSegment::Index(1)];
+        let constraint = WholeNumberConstraint::Constrained { min: Integer::from_short(1), max: Integer::from_short(3) };
+        let mut violations = Vec::new();
+
+        check_integer(&path, &mut violations, &constraint, &Integer::from_short(3));
+        check_integer(&path, &mut violations, &WholeNumberConstraint::Unconstrained, &Integer::from_short(-5));
+        check_length(&path, &mut violations, &constraint, 1);
+        check_length(&path, &mut violations, &WholeNumberConstraint::Unconstrained, 100_000);
+        check_ia5(&path, &mut violations, "ABC");
+        assert_eq!(violations, Vec::new());
+
+        check_integer(&path, &mut violations, &constraint, &Integer::from_short(4));
+        check_integer(&path, &mut violations, &WholeNumberConstraint::SemiConstrained { min: Integer::from_short(0) }, &Integer::from_short(-1));
+        check_length(&path, &mut violations, &constraint, 0);
+        check_ia5(&path, &mut violations, "Gr\u{00FC}n");
+        let messages: Vec<String> = violations.iter()
+            .map(|violation| violation.to_string())
+            .collect();
+        assert_eq!(messages, [
+            "carrierIA5[1]: integer 4 is outside the range 1..3",
+            "carrierIA5[1]: integer -1 is outside the range 0..MAX",
+            "carrierIA5[1]: length 0 is outside the permitted size range 1..3",
+            "carrierIA5[1]: IA5 string \"Gr\u{00FC}n\" byte 2 (0xC3) has top bit set",
+        ]);
+    }
+}
@@ -0,0 +1,58 @@
+//! Selection of the PER variant.
+//!
+//! The decoding and encoding functions use the UNALIGNED variant unless they are called within
+//! [`with_variant`]. The variant is kept per thread, which allows the generated types to offer
+//! `try_from_per` and `write_per` functions without passing the variant through every nested call.
+
+
+use std::cell::Cell;
+
+
+/// A variant of the Packed Encoding Rules.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PerVariant {
+    /// PER UNALIGNED, in which no padding bits are inserted.
+    #[default]
+    Unaligned,
+
+    /// PER ALIGNED, in which some fields (e.g. lengths, larger constrained integers and the
+    /// contents of strings) are preceded by padding bits up to the next octet boundary.
+    ///
+    /// Octet boundaries are counted from the start of the byte buffer being decoded or encoded.
+    Aligned,
+}
+
+
+thread_local! {
+    static VARIANT: Cell<PerVariant> = const { Cell::new(PerVariant::Unaligned) };
+}
+
+/// Restores the previous variant when dropped, even if decoding or encoding panics.
+struct VariantGuard {
+    previous: PerVariant,
+}
+impl Drop for VariantGuard {
+    fn drop(&mut self) {
+        VARIANT.with(|v| v.set(self.previous));
+    }
+}
+
+
+/// Runs the given function, in which all decoding and encoding uses the given PER variant.
+pub fn with_variant<R, F: FnOnce() -> R>(variant: PerVariant, f: F) -> R {
+    let _guard = VariantGuard {
+        previous: VARIANT.with(|v| v.replace(variant)),
+    };
+    f()
+}
+
+
+/// The PER variant currently used for decoding and encoding on this thread.
+pub fn current_variant() -> PerVariant {
+    VARIANT.with(|v| v.get())
+}
+
+
+pub(crate) fn is_aligned() -> bool {
+    current_variant() == PerVariant::Aligned
+}
@@ -0,0 +1,185 @@
+//! Traversal of decoded values.
+//!
+//! Types deriving `UperVisit` implement [`Visit`], which passes each primitive value contained
+//! within a value to a [`Visitor`] along with the path to it. [`VisitorMut`] receives mutable
+//! references instead, allowing values to be rewritten in place before encoding them again.
+//!
+//! Absent OPTIONAL members are not visited. Unknown ENUMERATED values, unknown CHOICE alternatives
+//! and unknown extension additions of SEQUENCE values are passed to [`Visitor::visit_unknown`]; the
+//! contents of unknown CHOICE alternatives are not visited.
+
+
+use std::fmt;
+
+use crate::asn1_uper::{BitVec, Integer, ObjectIdentifier};
+use crate::asn1_uper::trace::PathSegment;
+
+
+/// A primitive value encountered while visiting.
+#[derive(Clone, Copy, Debug)]
+pub enum Value<'v> {
+    Integer(&'v Integer),
+    Boolean(bool),
+    OctetString(&'v [u8]),
+
+    /// An IA5String or UTF8String.
+    String(&'v str),
+
+    BitString(&'v BitVec),
+    ObjectIdentifier(&'v ObjectIdentifier),
+    Null,
+
+    /// The ASN.1 name of an ENUMERATED value, or `None` if it is an unknown extension value.
+    Enumerated(Option<&'static str>),
+}
+
+impl fmt::Display for Value<'_> {
+    /// Formats the value roughly in ASN.1 value notation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::Boolean(true) => f.write_str("TRUE"),
+            Self::Boolean(false) => f.write_str("FALSE"),
+            Self::OctetString(value) => {
+                f.write_str("'")?;
+                for b in *value {
+                    write!(f, "{:02X}", b)?;
+                }
+                f.write_str("'H")
+            },
+            Self::String(value) => write!(f, "{:?}", value),
+            Self::BitString(value) => {
+                f.write_str("'")?;
+                for bit in value.iter() {
+                    f.write_str(if bit { "1" } else { "0" })?;
+                }
+                f.write_str("'B")
+            },
+            Self::ObjectIdentifier(value) => write!(f, "{}", value),
+            Self::Null => f.write_str("NULL"),
+            Self::Enumerated(Some(name)) => f.write_str(name),
+            Self::Enumerated(None) => f.write_str("(unknown extension value)"),
+        }
+    }
+}
+
+
+/// A value added by an extension that is unknown to the schema, encountered while visiting.
+#[derive(Clone, Copy, Debug)]
+pub enum Unknown<'v> {
+    /// An unknown ENUMERATED value along with its extension index.
+    Enumerated(usize),
+
+    /// An unknown CHOICE alternative along with its extension index and its encoded value.
+    Alternative { index: usize, contents: &'v [u8] },
+
+    /// A present unknown extension addition of a SEQUENCE along with its extension index and its
+    /// encoded value.
+    Addition { index: usize, contents: &'v [u8] },
+}
+
+impl fmt::Display for Unknown<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (description, index, contents) = match self {
+            Self::Enumerated(index) => return write!(f, "(unknown extension value {})", index),
+            Self::Alternative { index, contents } => ("alternative", index, contents),
+            Self::Addition { index, contents } => ("addition", index, contents),
+        };
+        write!(f, "(unknown extension {} {}: ", description, index)?;
+        write!(f, "{})", Value::OctetString(contents))
+    }
+}
+
+
+/// A mutable primitive value encountered while visiting.
+pub enum ValueMut<'v> {
+    Integer(&'v mut Integer),
+    Boolean(&'v mut bool),
+    OctetString(&'v mut Vec<u8>),
+
+    /// An IA5String or UTF8String.
+    String(&'v mut String),
+
+    BitString(&'v mut BitVec),
+    ObjectIdentifier(&'v mut ObjectIdentifier),
+    Null,
+    Enumerated(&'v mut dyn Enumerated),
+}
+
+
+/// A value of an ENUMERATED type, accessed by the ASN.1 names of its values.
+pub trait Enumerated {
+    /// Returns the ASN.1 name of the value, or `None` if it is an unknown extension value.
+    fn name(&self) -> Option<&'static str>;
+
+    /// Replaces the value with the value of the given ASN.1 name. Returns `false`, leaving the value
+    /// unchanged, if the type has no value of that name.
+    fn set_name(&mut self, name: &str) -> bool;
+}
+
+
+/// Receives the primitive values within a value.
+pub trait Visitor {
+    /// Called for each primitive value. `path` leads from the outermost visited value to this one;
+    /// [`field_name`] extracts the name of the field.
+    fn visit(&mut self, path: &[PathSegment], value: Value<'_>);
+
+    /// Called for each value added by an unknown extension. `path` leads to the ENUMERATED or
+    /// CHOICE value, or to the SEQUENCE value containing the unknown addition. An unknown ENUMERATED
+    /// value is also passed to [`visit`](Visitor::visit). Does nothing by default.
+    fn visit_unknown(&mut self, path: &[PathSegment], unknown: Unknown<'_>) {
+        let _ = (path, unknown);
+    }
+}
+impl<F: FnMut(&[PathSegment], Value<'_>)> Visitor for F {
+    fn visit(&mut self, path: &[PathSegment], value: Value<'_>) {
+        self(path, value)
+    }
+}
+
+
+/// Receives mutable references to the primitive values within a value.
+pub trait VisitorMut {
+    /// Called for each primitive value. `path` leads from the outermost visited value to this one;
+    /// [`field_name`] extracts the name of the field.
+    fn visit_mut(&mut self, path: &[PathSegment], value: ValueMut<'_>);
+}
+impl<F: FnMut(&[PathSegment], ValueMut<'_>)> VisitorMut for F {
+    fn visit_mut(&mut self, path: &[PathSegment], value: ValueMut<'_>) {
+        self(path, value)
+    }
+}
+
+
+/// A value whose primitive values can be visited.
+pub trait Visit {
+    /// Passes each primitive value within this value to the visitor. `path` is the path to this
+    /// value; it is extended while visiting and restored before returning.
+    fn visit_at(&self, path: &mut Vec<PathSegment>, visitor: &mut dyn Visitor);
+
+    /// Passes a mutable reference to each primitive value within this value to the visitor. `path`
+    /// is the path to this value; it is extended while visiting and restored before returning.
+    fn visit_mut_at(&mut self, path: &mut Vec<PathSegment>, visitor: &mut dyn VisitorMut);
+
+    /// Passes each primitive value within this value to the visitor.
+    fn visit(&self, visitor: &mut dyn Visitor) {
+        self.visit_at(&mut Vec::new(), visitor)
+    }
+
+    /// Passes a mutable reference to each primitive value within this value to the visitor.
+    fn visit_mut(&mut self, visitor: &mut dyn VisitorMut) {
+        self.visit_mut_at(&mut Vec::new(), visitor)
+    }
+}
+
+
+/// Returns the name of the field at the end of the given path, i.e. the last SEQUENCE member or
+/// CHOICE alternative. For an element of a SEQUENCE OF, this is the name of the SEQUENCE OF.
+pub fn field_name(path: &[PathSegment]) -> Option<&'static str> {
+    path.iter()
+        .rev()
+        .find_map(|segment| match segment {
+            PathSegment::Member(name) => Some(*name),
+            PathSegment::Index(_) => None,
+        })
+}
@@ -0,0 +1,539 @@
+//! The UIC ticket container (`#UT`) in its raw form.
+//!
+//! The container is specified in ERA-REC-122 B.12 § 10.6. Version 1 carries a DER-encoded signature
+//! padded to 50 bytes; version 2 carries the `r` and `s` values of a DSA signature, 32 bytes each.
+
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::{Cursor, Read};
+
+use crate::key_db::Signature;
+
+
+/// An error that may occur when decoding a UIC ticket container.
+///
+/// Offsets of errors within the header refer to the barcode contents; offsets of errors within
+/// records refer to the decompressed data.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ContainerError {
+    /// The barcode does not start with `#UT`.
+    NotUicTicket { offset: usize },
+
+    /// The barcode ends before the end of the header.
+    TruncatedHeader { offset: usize, field: &'static str, expected: usize, available: usize },
+
+    /// The container version is not known.
+    UnknownVersion { offset: usize, version: Vec<u8> },
+
+    /// A textual field contains non-ASCII bytes.
+    NonAsciiField { offset: usize, field: &'static str, bytes: Vec<u8> },
+
+    /// A length field is not a decimal number.
+    NonNumericLength { offset: usize, field: &'static str, bytes: Vec<u8> },
+
+    /// The compressed data is longer than the remainder of the barcode.
+    CompressedLengthExceedsBuffer { offset: usize, compressed_length: usize, available: usize },
+
+    /// The compressed data is not a valid zlib stream.
+    Decompression { offset: usize, error: std::io::Error },
+
+    /// The decompressed data ends before the end of a record header.
+    TruncatedRecordHeader { offset: usize, available: usize },
+
+    /// A record claims to be shorter than its own header.
+    RecordLengthTooShort { offset: usize, record_length: usize },
+
+    /// A record claims to be longer than the remainder of the decompressed data.
+    RecordLengthOverrun { offset: usize, record_length: usize, available: usize },
+
+    /// The data of a record could not be decoded.
+    RecordDecoding { offset: usize, record_id: String, record_version: String, message: String },
+}
+impl fmt::Display for ContainerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotUicTicket { offset }
+                => write!(f, "barcode does not contain a UIC ticket (no \"#UT\" at offset {})", offset),
+            Self::TruncatedHeader { offset, field, expected, available }
+                => write!(f, "barcode truncated at {} (offset {}): expected {} bytes, {} available", field, offset, expected, available),
+            Self::UnknownVersion { offset, version }
+                => write!(f, "unknown UIC ticket version {:?} at offset {}", version, offset),
+            Self::NonAsciiField { offset, field, bytes }
+                => write!(f, "{} at offset {} is not ASCII: {:?}", field, offset, bytes),
+            Self::NonNumericLength { offset, field, bytes }
+                => write!(f, "{} at offset {} is not a decimal number: {:?}", field, offset, bytes),
+            Self::CompressedLengthExceedsBuffer { offset, compressed_length, available }
+                => write!(f, "compressed data at offset {} is {} bytes long but only {} bytes are available", offset, compressed_length, available),
+            Self::Decompression { offset, error }
+                => write!(f, "failed to decompress data at offset {}: {}", offset, error),
+            Self::TruncatedRecordHeader { offset, available }
+                => write!(f, "record header at offset {} truncated: only {} bytes available", offset, available),
+            Self::RecordLengthTooShort { offset, record_length }
+                => write!(f, "record at offset {} has length {}, shorter than its header", offset, record_length),
+            Self::RecordLengthOverrun { offset, record_length, available }
+                => write!(f, "record at offset {} has length {} but only {} bytes are available", offset, record_length, available),
+            Self::RecordDecoding { offset, record_id, record_version, message }
+                => write!(f, "failed to decode record {:?} version {:?} at offset {}: {}", record_id, record_version, offset, message),
+        }
+    }
+}
+impl std::error::Error for ContainerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Decompression { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+
+/// Returns the `length` bytes at `offset` in the header, failing if the barcode is too short.
+fn header_field<'a>(bytes: &'a [u8], offset: usize, length: usize, field: &'static str) -> Result<&'a [u8], ContainerError> {
+    let available = bytes.len().saturating_sub(offset);
+    if available < length {
+        return Err(ContainerError::TruncatedHeader { offset, field, expected: length, available });
+    }
+    Ok(&bytes[offset..offset+length])
+}
+
+/// Interprets the given bytes as an ASCII string.
+fn ascii_field(bytes: &[u8], offset: usize, field: &'static str) -> Result<String, ContainerError> {
+    if !bytes.is_ascii() {
+        return Err(ContainerError::NonAsciiField { offset, field, bytes: bytes.to_vec() });
+    }
+    Ok(bytes.iter().map(|b| char::from(*b)).collect())
+}
+
+/// Interprets the given bytes as a decimal number.
+fn numeric_field(bytes: &[u8], offset: usize, field: &'static str) -> Result<usize, ContainerError> {
+    let non_numeric = || ContainerError::NonNumericLength { offset, field, bytes: bytes.to_vec() };
+    if bytes.is_empty() || !bytes.iter().all(|b| b.is_ascii_digit()) {
+        return Err(non_numeric());
+    }
+    std::str::from_utf8(bytes).ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(non_numeric)
+}
+
+
+
+/// An error that may occur when writing a UIC ticket container.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ContainerWriteError {
+    /// The container version is not 1 or 2.
+    UnknownVersion { version: u8 },
+
+    /// A textual field does not have the required length or contains non-ASCII characters.
+    InvalidField { field: &'static str, value: String, expected_length: usize },
+
+    /// The kind of signature does not match the container version.
+    SignatureVersionMismatch { version: u8 },
+
+    /// The signature does not have the required length.
+    SignatureLength { expected: usize, obtained: usize },
+
+    /// A record is too long for its length to fit into four decimal digits.
+    RecordTooLong { record_id: String, length: usize },
+
+    /// The compressed data is too long for its length to fit into four decimal digits.
+    CompressedTooLong { length: usize },
+
+    /// Compressing the records failed.
+    Compression(std::io::Error),
+}
+impl fmt::Display for ContainerWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownVersion { version }
+                => write!(f, "unknown UIC ticket version {}", version),
+            Self::InvalidField { field, value, expected_length }
+                => write!(f, "{} {:?} is not {} ASCII characters long", field, value, expected_length),
+            Self::SignatureVersionMismatch { version }
+                => write!(f, "signature kind does not match UIC ticket version {}", version),
+            Self::SignatureLength { expected, obtained }
+                => write!(f, "signature is {} bytes long, expected {}", obtained, expected),
+            Self::RecordTooLong { record_id, length }
+                => write!(f, "record {:?} is {} bytes long, which does not fit into four digits", record_id, length),
+            Self::CompressedTooLong { length }
+                => write!(f, "compressed data is {} bytes long, which does not fit into four digits", length),
+            Self::Compression(e)
+                => write!(f, "failed to compress data: {}", e),
+        }
+    }
+}
+impl std::error::Error for ContainerWriteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Compression(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+
+/// A record within a UIC ticket container, in its raw form.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UicRecord {
+    /// The ID of the record, e.g. `U_FLEX` (6 ASCII characters).
+    pub id: String,
+
+    /// The version of the record, e.g. `03` (2 ASCII characters).
+    pub version: String,
+
+    /// The data of the record, excluding its ID, version and length fields.
+    pub data: Vec<u8>,
+}
+impl UicRecord {
+    /// Creates a record with the given ID, version and data (excluding the ID, version and length
+    /// fields).
+    pub fn new<I: Into<String>, V: Into<String>>(id: I, version: V, data: Vec<u8>) -> Self {
+        Self {
+            id: id.into(),
+            version: version.into(),
+            data,
+        }
+    }
+
+    /// The length of the record, including its ID, version and length fields.
+    pub fn length(&self) -> usize {
+        12 + self.data.len()
+    }
+
+    /// Appends the encoded record, including its ID, version and length fields, to the buffer.
+    pub fn write(&self, buf: &mut Vec<u8>) -> Result<(), ContainerWriteError> {
+        check_ascii_field(&self.id, 6, "record ID")?;
+        check_ascii_field(&self.version, 2, "record version")?;
+        let length_text = format!("{:04}", self.length());
+        if length_text.len() != 4 {
+            return Err(ContainerWriteError::RecordTooLong { record_id: self.id.clone(), length: self.length() });
+        }
+        buf.extend(self.id.as_bytes());
+        buf.extend(self.version.as_bytes());
+        buf.extend(length_text.as_bytes());
+        buf.extend(&self.data);
+        Ok(())
+    }
+}
+
+
+/// A UIC ticket container, in its raw form.
+///
+/// A container that has been parsed using [`UicContainer::from_bytes`] remembers its original
+/// compressed data. As long as the records are not modified, [`UicContainer::to_bytes`] reuses
+/// that data, which means that the original barcode contents are reproduced exactly (and the
+/// signature remains valid). Once the records are modified, they are compressed anew.
+///
+/// The original compressed data is not taken into account when comparing or hashing containers; a
+/// parsed container is equal to one constructed using [`UicContainer::new`] with the same contents.
+#[derive(Clone, Debug)]
+pub struct UicContainer {
+    /// The version of the container format (1 or 2).
+    pub version: u8,
+
+    /// The number of the company that signed the ticket (4 ASCII characters).
+    pub signer_number: String,
+
+    /// The ID of the key with which the ticket was signed (5 ASCII characters).
+    pub key_id: String,
+
+    /// The signature over the compressed data.
+    ///
+    /// Version 1 containers carry an ASN.1 signature of 50 bytes (including padding); version 2
+    /// containers carry a DSA signature with `r` and `s` of 32 bytes each.
+    pub signature: Signature,
+
+    /// The records contained within the container.
+    pub records: Vec<UicRecord>,
+
+    /// Any data following the compressed data.
+    pub trailing_data: Vec<u8>,
+
+    /// The original decompressed and compressed data, if this container has been parsed.
+    original_data: Option<(Vec<u8>, Vec<u8>)>,
+}
+impl UicContainer {
+    /// Creates a container with the given header values and records and without trailing data.
+    ///
+    /// The records are compressed when the container is encoded.
+    pub fn new<S: Into<String>, K: Into<String>>(version: u8, signer_number: S, key_id: K, signature: Signature, records: Vec<UicRecord>) -> Self {
+        Self {
+            version,
+            signer_number: signer_number.into(),
+            key_id: key_id.into(),
+            signature,
+            records,
+            trailing_data: Vec::new(),
+            original_data: None,
+        }
+    }
+
+    /// Parses a UIC ticket container from the contents of a barcode.
+    pub fn from_bytes(barcode_contents: &[u8]) -> Result<Self, ContainerError> {
+        if !barcode_contents.starts_with(b"#UT") {
+            return Err(ContainerError::NotUicTicket { offset: 0 });
+        }
+        let version = header_field(barcode_contents, 3, 2, "version")?;
+        let signature_length = if version == b"01" {
+            // ASN.1 DER-encoded signature, padded
+            50
+        } else if version == b"02" {
+            // r and s of the DSA signature, 32 bytes each
+            64
+        } else {
+            return Err(ContainerError::UnknownVersion { offset: 3, version: version.to_vec() });
+        };
+
+        let signer_number_bytes = header_field(barcode_contents, 5, 4, "signer number")?;
+        let signer_number = ascii_field(signer_number_bytes, 5, "signer number")?;
+
+        let key_id_bytes = header_field(barcode_contents, 9, 5, "key ID")?;
+        let key_id = ascii_field(key_id_bytes, 9, "key ID")?;
+
+        let signature_bytes = header_field(barcode_contents, 14, signature_length, "signature")?;
+        let signature = if version == b"01" {
+            Signature::Asn1(signature_bytes.to_vec())
+        } else {
+            Signature::Dsa { r: signature_bytes[0..32].to_vec(), s: signature_bytes[32..64].to_vec() }
+        };
+
+        let compressed_len_offset = 14 + signature_length;
+        let compressed_len_bytes = header_field(barcode_contents, compressed_len_offset, 4, "compressed length")?;
+        let compressed_len = numeric_field(compressed_len_bytes, compressed_len_offset, "compressed length")?;
+
+        let compressed_offset = compressed_len_offset + 4;
+        let available = barcode_contents.len() - compressed_offset;
+        if compressed_len > available {
+            return Err(ContainerError::CompressedLengthExceedsBuffer { offset: compressed_offset, compressed_length: compressed_len, available });
+        }
+        let compressed_bytes = &barcode_contents[compressed_offset..compressed_offset+compressed_len];
+        let trailing_data = barcode_contents[compressed_offset+compressed_len..].to_vec();
+
+        // uncompress
+        let mut data_bytes = Vec::new();
+        flate2::read::ZlibDecoder::new(Cursor::new(compressed_bytes))
+            .read_to_end(&mut data_bytes)
+            .map_err(|error| ContainerError::Decompression { offset: compressed_offset, error })?;
+
+        let records = parse_records(&data_bytes)?;
+
+        Ok(Self {
+            version: if version == b"01" { 1 } else { 2 },
+            signer_number,
+            key_id,
+            signature,
+            records,
+            trailing_data,
+            original_data: Some((data_bytes, compressed_bytes.to_vec())),
+        })
+    }
+
+    /// Returns the records encoded back to back, as they are stored (compressed) in the container.
+    pub fn records_bytes(&self) -> Result<Vec<u8>, ContainerWriteError> {
+        let mut buf = Vec::new();
+        for record in &self.records {
+            record.write(&mut buf)?;
+        }
+        Ok(buf)
+    }
+
+    /// Returns the compressed records, i.e. the data over which the signature is calculated.
+    pub fn compressed_bytes(&self) -> Result<Vec<u8>, ContainerWriteError> {
+        let records_bytes = self.records_bytes()?;
+        if let Some((original_decompressed, original_compressed)) = &self.original_data {
+            if original_decompressed == &records_bytes {
+                return Ok(original_compressed.clone());
+            }
+        }
+
+        let mut compressed_bytes = Vec::new();
+        flate2::read::ZlibEncoder::new(Cursor::new(&records_bytes), flate2::Compression::best())
+            .read_to_end(&mut compressed_bytes)
+            .map_err(ContainerWriteError::Compression)?;
+        Ok(compressed_bytes)
+    }
+
+    /// Encodes this container into the contents of a barcode.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ContainerWriteError> {
+        check_ascii_field(&self.signer_number, 4, "signer number")?;
+        check_ascii_field(&self.key_id, 5, "key ID")?;
+
+        let mut buf = Vec::new();
+        match (self.version, &self.signature) {
+            (1, Signature::Asn1(signature_bytes)) => {
+                if signature_bytes.len() != 50 {
+                    return Err(ContainerWriteError::SignatureLength { expected: 50, obtained: signature_bytes.len() });
+                }
+                buf.extend(b"#UT01");
+                buf.extend(self.signer_number.as_bytes());
+                buf.extend(self.key_id.as_bytes());
+                buf.extend(signature_bytes);
+            },
+            (2, Signature::Dsa { r, s }) => {
+                if r.len() != 32 {
+                    return Err(ContainerWriteError::SignatureLength { expected: 32, obtained: r.len() });
+                }
+                if s.len() != 32 {
+                    return Err(ContainerWriteError::SignatureLength { expected: 32, obtained: s.len() });
+                }
+                buf.extend(b"#UT02");
+                buf.extend(self.signer_number.as_bytes());
+                buf.extend(self.key_id.as_bytes());
+                buf.extend(r);
+                buf.extend(s);
+            },
+            (1, _)|(2, _) => return Err(ContainerWriteError::SignatureVersionMismatch { version: self.version }),
+            (other, _) => return Err(ContainerWriteError::UnknownVersion { version: other }),
+        }
+
+        let compressed_bytes = self.compressed_bytes()?;
+        let compressed_length_text = format!("{:04}", compressed_bytes.len());
+        if compressed_length_text.len() != 4 {
+            return Err(ContainerWriteError::CompressedTooLong { length: compressed_bytes.len() });
+        }
+        buf.extend(compressed_length_text.as_bytes());
+        buf.extend(&compressed_bytes);
+        buf.extend(&self.trailing_data);
+        Ok(buf)
+    }
+
+    /// The members which are relevant for comparing and hashing, i.e. all except the original data.
+    fn comparison_key(&self) -> (u8, &str, &str, &Signature, &[UicRecord], &[u8]) {
+        (self.version, &self.signer_number, &self.key_id, &self.signature, &self.records, &self.trailing_data)
+    }
+}
+impl PartialEq for UicContainer {
+    fn eq(&self, other: &Self) -> bool {
+        self.comparison_key() == other.comparison_key()
+    }
+}
+impl Eq for UicContainer {
+}
+impl PartialOrd for UicContainer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for UicContainer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.comparison_key().cmp(&other.comparison_key())
+    }
+}
+impl Hash for UicContainer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.comparison_key().hash(state)
+    }
+}
+
+
+/// Parses the decompressed data of a container into its records.
+fn parse_records(data_bytes: &[u8]) -> Result<Vec<UicRecord>, ContainerError> {
+    let mut records = Vec::new();
+    let mut offset = 0;
+    while offset < data_bytes.len() {
+        let remaining_bytes = &data_bytes[offset..];
+        if remaining_bytes.len() < 12 {
+            return Err(ContainerError::TruncatedRecordHeader { offset, available: remaining_bytes.len() });
+        }
+
+        let record_id = ascii_field(&remaining_bytes[0..6], offset, "record ID")?;
+        let record_version = ascii_field(&remaining_bytes[6..8], offset + 6, "record version")?;
+        let record_length = numeric_field(&remaining_bytes[8..12], offset + 8, "record length")?;
+        if record_length < 12 {
+            return Err(ContainerError::RecordLengthTooShort { offset, record_length });
+        }
+        if record_length > remaining_bytes.len() {
+            return Err(ContainerError::RecordLengthOverrun { offset, record_length, available: remaining_bytes.len() });
+        }
+
+        records.push(UicRecord {
+            id: record_id,
+            version: record_version,
+            data: remaining_bytes[12..record_length].to_vec(),
+        });
+
+        offset += record_length;
+    }
+    Ok(records)
+}
+
+
+fn check_ascii_field(value: &str, expected_length: usize, field: &'static str) -> Result<(), ContainerWriteError> {
+    if value.len() != expected_length || !value.is_ascii() {
+        return Err(ContainerWriteError::InvalidField { field, value: value.to_owned(), expected_length });
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut signature = vec![0x30, 0x2C, 0x02, 0x14];
+        signature.resize(50, 0x00);
+        let records = vec![
+            UicRecord::new("U_HEAD", "01", b"1181ABCDEFGHIJKLMNOPQRST0101202312000DE  ".to_vec()),
+            UicRecord::new("1181AB", "00", b"2345".to_vec()),
+        ];
+        let container = UicContainer::new(1, "1181", "00001", Signature::Asn1(signature), records);
+        let bytes = container.to_bytes().unwrap();
+        assert!(bytes.starts_with(b"#UT011181"));
+
+        let parsed = UicContainer::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.records, container.records);
+        assert_eq!(parsed.to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_preserves_original_compression() {
+        // compressed using a different level than the writer would use
+        let records = UicRecord::new("1181AB", "00", b"2345".to_vec());
+        let mut records_bytes = Vec::new();
+        records.write(&mut records_bytes).unwrap();
+        let mut compressed = Vec::new();
+        flate2::read::ZlibEncoder::new(Cursor::new(&records_bytes), flate2::Compression::none())
+            .read_to_end(&mut compressed)
+            .unwrap();
+
+        let mut barcode = Vec::new();
+        barcode.extend(b"#UT0211810000");
+        barcode.extend(b"1");
+        barcode.extend([0x55u8; 64]);
+        barcode.extend(format!("{:04}", compressed.len()).as_bytes());
+        barcode.extend(&compressed);
+        barcode.extend(b"\0\0");
+
+        let mut container = UicContainer::from_bytes(&barcode).unwrap();
+        assert_eq!(container.trailing_data, b"\0\0");
+        assert_eq!(container.to_bytes().unwrap(), barcode);
+
+        // the original compressed data does not take part in comparisons
+        let mut constructed = UicContainer::new(
+            2, "1181", "00001",
+            Signature::Dsa { r: vec![0x55; 32], s: vec![0x55; 32] },
+            vec![records.clone()],
+        );
+        constructed.trailing_data = b"\0\0".to_vec();
+        assert_ne!(constructed.to_bytes().unwrap(), barcode);
+        assert_eq!(container, constructed);
+        assert_eq!(container.cmp(&constructed), Ordering::Equal);
+        let hash = |container: &UicContainer| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            container.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&container), hash(&constructed));
+
+        // modifying the records requires recompression
+        container.records[0].data = b"6789".to_vec();
+        let modified = container.to_bytes().unwrap();
+        let reparsed = UicContainer::from_bytes(&modified).unwrap();
+        assert_eq!(reparsed.records[0].data, b"6789");
+    }
+}
@@ -0,0 +1,396 @@
+//! Decoding of UIC dynamic barcodes (DOSIPAS).
+//!
+//! Unlike the `#UT` container, the frame of a dynamic barcode is itself UPER-encoded: a
+//! `UicBarcodeHeader` of format `U1` or `U2` contains a sequence of data blocks, each labeled with
+//! its data format (e.g. `FCB3` for ticket data corresponding to a U_FLEX version 3 record), signed
+//! by the level 1 signature, and optionally one block of level 2 data (e.g. `FDC1` dynamic content)
+//! signed by the level 2 signature. Format `U2` adds the end of validity of the barcode to the
+//! level 1 data.
+//!
+//! Ticket data blocks (`FCB1` to `FCB3`) are decoded using the U_FLEX decoders of a
+//! [`RecordDecoderRegistry`]. Signatures are not verified.
+
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{dosipas_1, dosipas_2};
+use crate::asn1_uper::{
+    decode_bool, decode_ia5_string, octet_string_to_utf8, BitSlice, DecodingError, Integer,
+    ObjectIdentifier, ParseResult, WholeNumberConstraint,
+};
+use crate::records::{RecordDecoderRegistry, RecordValue};
+
+
+/// Evaluates the expression with `$header` bound to the header, whichever its format.
+macro_rules! either_format {
+    ($value:expr, $header:ident => $body:expr) => {
+        match $value {
+            BarcodeHeader::U1($header) => $body,
+            BarcodeHeader::U2($header) => $body,
+        }
+    };
+}
+
+
+/// An error that may occur when decoding a dynamic barcode.
+#[derive(Debug)]
+pub enum DynamicBarcodeError {
+    /// The barcode header could not be decoded.
+    HeaderDecoding { message: String },
+
+    /// The barcode header has a format other than `U1` or `U2`.
+    UnknownFormat { format: String },
+
+    /// A data block could not be decoded.
+    ///
+    /// `index` is the index of the block in the level 1 data sequence, or `None` for the level 2
+    /// data.
+    DataDecoding { index: Option<usize>, format: String, message: String },
+}
+impl fmt::Display for DynamicBarcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HeaderDecoding { message }
+                => write!(f, "failed to decode dynamic barcode header: {}", message),
+            Self::UnknownFormat { format }
+                => write!(f, "unknown dynamic barcode header format {:?}", format),
+            Self::DataDecoding { index: Some(index), format, message }
+                => write!(f, "failed to decode data block {} of format {:?}: {}", index, format, message),
+            Self::DataDecoding { index: None, format, message }
+                => write!(f, "failed to decode level 2 data of format {:?}: {}", format, message),
+        }
+    }
+}
+impl std::error::Error for DynamicBarcodeError {
+}
+
+
+/// The header of a dynamic barcode.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
+pub enum BarcodeHeader {
+    U1(dosipas_1::UicBarcodeHeader),
+    U2(dosipas_2::UicBarcodeHeader),
+}
+impl BarcodeHeader {
+    /// The format of the header (`U1` or `U2`).
+    pub fn format(&self) -> &str {
+        either_format!(self, header => &header.format)
+    }
+
+    /// The RICS code of the provider of the level 1 key, or its IA5 representation.
+    pub fn security_provider(&self) -> Option<String> {
+        either_format!(self, header => {
+            let level_1_data = &header.level_2_signed_data.level_1_data;
+            level_1_data.security_provider_num.as_ref()
+                .map(|num| num.to_string())
+                .or_else(|| level_1_data.security_provider_ia_5.clone())
+        })
+    }
+
+    /// The ID of the level 1 key.
+    pub fn key_id(&self) -> Option<&Integer> {
+        either_format!(self, header => header.level_2_signed_data.level_1_data.key_id.as_ref())
+    }
+
+    /// The object identifier of the algorithm of the level 1 key.
+    pub fn level_1_key_alg(&self) -> Option<&ObjectIdentifier> {
+        either_format!(self, header => header.level_2_signed_data.level_1_data.level_1_key_alg.as_ref())
+    }
+
+    /// The object identifier of the algorithm of the level 2 key.
+    pub fn level_2_key_alg(&self) -> Option<&ObjectIdentifier> {
+        either_format!(self, header => header.level_2_signed_data.level_1_data.level_2_key_alg.as_ref())
+    }
+
+    /// The object identifier of the algorithm of the level 1 signature.
+    pub fn level_1_signing_alg(&self) -> Option<&ObjectIdentifier> {
+        either_format!(self, header => header.level_2_signed_data.level_1_data.level_1_signing_alg.as_ref())
+    }
+
+    /// The object identifier of the algorithm of the level 2 signature.
+    pub fn level_2_signing_alg(&self) -> Option<&ObjectIdentifier> {
+        either_format!(self, header => header.level_2_signed_data.level_1_data.level_2_signing_alg.as_ref())
+    }
+
+    /// The public key with which the level 2 signature can be verified.
+    pub fn level_2_public_key(&self) -> Option<&[u8]> {
+        either_format!(self, header => header.level_2_signed_data.level_1_data.level_2_public_key.as_deref())
+    }
+
+    /// The signature over the level 1 data.
+    pub fn level_1_signature(&self) -> Option<&[u8]> {
+        either_format!(self, header => header.level_2_signed_data.level_1_signature.as_deref())
+    }
+
+    /// The signature over the level 2 signed data.
+    pub fn level_2_signature(&self) -> Option<&[u8]> {
+        either_format!(self, header => header.level_2_signature.as_deref())
+    }
+
+    /// The formats and contents of the data blocks in the level 1 data.
+    pub fn data_sequence(&self) -> Vec<(&str, &[u8])> {
+        either_format!(self, header => header.level_2_signed_data.level_1_data.data_sequence.iter()
+            .map(|block| (block.data_format.as_str(), block.data.as_slice()))
+            .collect())
+    }
+
+    /// The format and contents of the level 2 data.
+    pub fn level_2_data(&self) -> Option<(&str, &[u8])> {
+        either_format!(self, header => header.level_2_signed_data.level_2_data.as_ref()
+            .map(|block| (block.data_format.as_str(), block.data.as_slice())))
+    }
+}
+
+
+/// A data block of a dynamic barcode.
+#[derive(Debug)]
+pub struct DataBlock {
+    /// The format of the data, e.g. `FCB3`.
+    pub format: String,
+
+    pub data: Vec<u8>,
+
+    /// The decoded value of the data.
+    ///
+    /// `None` if the format is not a ticket data format or no decoder is registered for it.
+    pub value: Option<Box<dyn RecordValue>>,
+}
+
+
+/// A decoded dynamic barcode.
+#[derive(Debug)]
+pub struct DecodedDynamicBarcode {
+    pub header: BarcodeHeader,
+
+    /// The data blocks of the level 1 data.
+    pub data_blocks: Vec<DataBlock>,
+
+    /// The level 2 data, if any.
+    pub level_2_data: Option<DataBlock>,
+}
+
+
+/// Decodes the format of the header, which precedes everything that differs between the formats.
+fn decode_format(bits: BitSlice<'_>) -> ParseResult<'_, String> {
+    // UicBarcodeHeader has no extension marker; its only OPTIONAL member is level2Signature
+    let (rest, _level_2_signature_present) = decode_bool(bits)?;
+    let (rest, format) = decode_ia5_string(rest, &WholeNumberConstraint::Unconstrained)?;
+    octet_string_to_utf8(rest, format)
+}
+
+
+fn describe_error(error: nom::Err<DecodingError<'_>>, bits: BitSlice<'_>) -> String {
+    match error {
+        nom::Err::Error(e) | nom::Err::Failure(e) => e.at_offset_from(bits).to_string(),
+        nom::Err::Incomplete(_) => "incomplete data".to_owned(),
+    }
+}
+
+
+/// The U_FLEX record version corresponding to a ticket data format.
+fn uflex_version(data_format: &str) -> Option<&'static str> {
+    match data_format {
+        "FCB1" => Some("01"),
+        "FCB2" => Some("02"),
+        "FCB3" => Some("03"),
+        _ => None,
+    }
+}
+
+
+fn decode_data_block(registry: &RecordDecoderRegistry, index: Option<usize>, format: &str, data: &[u8]) -> Result<DataBlock, DynamicBarcodeError> {
+    let value = uflex_version(format)
+        .and_then(|version| registry.decode("U_FLEX", version, data))
+        .transpose()
+        .map_err(|e| DynamicBarcodeError::DataDecoding {
+            index,
+            format: format.to_owned(),
+            message: e.to_string(),
+        })?;
+    Ok(DataBlock {
+        format: format.to_owned(),
+        data: data.to_vec(),
+        value,
+    })
+}
+
+
+/// Returns whether the contents of a barcode start with a dynamic barcode header of a known format.
+pub fn is_dynamic_barcode(barcode_contents: &[u8]) -> bool {
+    match decode_format(BitSlice::new(barcode_contents)) {
+        Ok((_rest, format)) => format == "U1" || format == "U2",
+        Err(_) => false,
+    }
+}
+
+
+/// Decodes the contents of a dynamic barcode using the decoders for the standard records.
+pub fn decode_dynamic_barcode_bytes(barcode_contents: &[u8]) -> Result<DecodedDynamicBarcode, DynamicBarcodeError> {
+    decode_dynamic_barcode_bytes_with_registry(barcode_contents, &RecordDecoderRegistry::default())
+}
+
+
+/// Decodes the contents of a dynamic barcode using the decoders in the given registry.
+pub fn decode_dynamic_barcode_bytes_with_registry(barcode_contents: &[u8], registry: &RecordDecoderRegistry) -> Result<DecodedDynamicBarcode, DynamicBarcodeError> {
+    let bits = BitSlice::new(barcode_contents);
+    let header_error = |e| DynamicBarcodeError::HeaderDecoding { message: describe_error(e, bits) };
+
+    let (_rest, format) = decode_format(bits)
+        .map_err(header_error)?;
+    let header = match format.as_str() {
+        "U1" => BarcodeHeader::U1(dosipas_1::UicBarcodeHeader::try_from_uper(bits).map_err(header_error)?.1),
+        "U2" => BarcodeHeader::U2(dosipas_2::UicBarcodeHeader::try_from_uper(bits).map_err(header_error)?.1),
+        _ => return Err(DynamicBarcodeError::UnknownFormat { format }),
+    };
+
+    let data_blocks = header.data_sequence().into_iter()
+        .enumerate()
+        .map(|(index, (format, data))| decode_data_block(registry, Some(index), format, data))
+        .collect::<Result<Vec<_>, _>>()?;
+    let level_2_data = header.level_2_data()
+        .map(|(format, data)| decode_data_block(registry, None, format, data))
+        .transpose()?;
+
+    Ok(DecodedDynamicBarcode {
+        header,
+        data_blocks,
+        level_2_data,
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asn1_uper::BitVec;
+    use crate::uflex_3::UicRailTicketData;
+
+    fn ticket_data() -> UicRailTicketData {
+        serde_json::from_value(serde_json::json!({
+            "issuing_detail": {
+                "security_provider_num": "1080", "issuing_year": "2024", "issuing_day": "100",
+                "issuing_time": "600", "specimen": true, "secure_paper_ticket": false,
+                "activated": true, "currency": "EUR", "currency_fract": "2",
+            },
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_decode_u1() {
+        let ticket_data = ticket_data();
+        let mut fcb = BitVec::new();
+        ticket_data.write_uper(&mut fcb).unwrap();
+
+        let header = dosipas_1::UicBarcodeHeader {
+            format: "U1".to_owned(),
+            level_2_signed_data: dosipas_1::Level2DataType {
+                level_1_data: dosipas_1::Level1DataType {
+                    security_provider_num: Some(Integer::from_short(1080)),
+                    security_provider_ia_5: None,
+                    key_id: Some(Integer::from_short(5)),
+                    data_sequence: vec![
+                        dosipas_1::DataType { data_format: "FCB3".to_owned(), data: fcb.into_bytes() },
+                        dosipas_1::DataType { data_format: "_1080VOR".to_owned(), data: vec![0x01, 0x02] },
+                    ],
+                    level_1_key_alg: None,
+                    level_2_key_alg: None,
+                    level_1_signing_alg: None,
+                    level_2_signing_alg: None,
+                    level_2_public_key: None,
+                },
+                level_1_signature: Some(vec![0xAB; 8]),
+                level_2_data: Some(dosipas_1::DataType { data_format: "FDC1".to_owned(), data: vec![0x03] }),
+            },
+            level_2_signature: None,
+        };
+        let mut bits = BitVec::new();
+        header.write_uper(&mut bits).unwrap();
+        let barcode = bits.into_bytes();
+        assert!(is_dynamic_barcode(&barcode));
+
+        let decoded = decode_dynamic_barcode_bytes(&barcode).unwrap();
+        assert_eq!(decoded.header, BarcodeHeader::U1(header));
+        assert_eq!(decoded.header.security_provider().as_deref(), Some("1080"));
+        assert_eq!(decoded.header.key_id(), Some(&Integer::from_short(5)));
+        assert_eq!(decoded.header.level_1_signature(), Some(&[0xAB; 8][..]));
+        assert_eq!(decoded.header.level_2_signature(), None);
+
+        assert_eq!(decoded.data_blocks.len(), 2);
+        assert_eq!(decoded.data_blocks[0].format, "FCB3");
+        let value = decoded.data_blocks[0].value.as_ref().unwrap();
+        assert_eq!(value.downcast_ref::<UicRailTicketData>(), Some(&ticket_data));
+        assert_eq!(decoded.data_blocks[1].format, "_1080VOR");
+        assert!(decoded.data_blocks[1].value.is_none());
+
+        let level_2_data = decoded.level_2_data.as_ref().unwrap();
+        assert_eq!(level_2_data.format, "FDC1");
+        assert_eq!(level_2_data.data, [0x03]);
+        assert!(level_2_data.value.is_none());
+    }
+
+    #[test]
+    fn test_decode_u2() {
+        let header = dosipas_2::UicBarcodeHeader {
+            format: "U2".to_owned(),
+            level_2_signed_data: dosipas_2::Level2DataType {
+                level_1_data: dosipas_2::Level1DataType {
+                    security_provider_num: None,
+                    security_provider_ia_5: Some("XY".to_owned()),
+                    key_id: None,
+                    data_sequence: vec![
+                        dosipas_2::DataType { data_format: "FCB3".to_owned(), data: vec![0xFF] },
+                    ],
+                    level_1_key_alg: None,
+                    level_2_key_alg: None,
+                    level_1_signing_alg: None,
+                    level_2_signing_alg: None,
+                    level_2_public_key: None,
+                    end_of_validity_year: Some(Integer::from_short(2025)),
+                    end_of_validity_day: Some(Integer::from_short(1)),
+                    end_of_validity_time: None,
+                    validity_duration: Some(Integer::from_short(60)),
+                },
+                level_1_signature: None,
+                level_2_data: None,
+            },
+            level_2_signature: Some(vec![0xCD; 4]),
+        };
+        let mut bits = BitVec::new();
+        header.write_uper(&mut bits).unwrap();
+        let barcode = bits.into_bytes();
+        assert!(is_dynamic_barcode(&barcode));
+
+        // the ticket data block is truncated
+        let error = decode_dynamic_barcode_bytes(&barcode).unwrap_err();
+        assert!(matches!(error, DynamicBarcodeError::DataDecoding { index: Some(0), .. }));
+
+        let mut registry = RecordDecoderRegistry::default();
+        registry.unregister("U_FLEX", "03");
+        let decoded = decode_dynamic_barcode_bytes_with_registry(&barcode, &registry).unwrap();
+        assert_eq!(decoded.header.format(), "U2");
+        assert_eq!(decoded.header.security_provider().as_deref(), Some("XY"));
+        assert_eq!(decoded.header.level_2_signature(), Some(&[0xCD; 4][..]));
+        assert!(decoded.data_blocks[0].value.is_none());
+        assert!(decoded.level_2_data.is_none());
+    }
+
+    #[test]
+    fn test_detection() {
+        assert!(!is_dynamic_barcode(b"#UT02118100001"));
+        assert!(!is_dynamic_barcode(&[]));
+
+        // a header with the unknown format "U9"
+        let mut bits = BitVec::new();
+        crate::asn1_uper::encode_bool(&mut bits, false);
+        crate::asn1_uper::encode_ia5_string(&mut bits, &WholeNumberConstraint::Unconstrained, "U9").unwrap();
+        let barcode = bits.into_bytes();
+        assert!(!is_dynamic_barcode(&barcode));
+        assert!(matches!(
+            decode_dynamic_barcode_bytes(&barcode),
+            Err(DynamicBarcodeError::UnknownFormat { format }) if format == "U9",
+        ));
+    }
+}
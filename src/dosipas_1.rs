@@ -0,0 +1,37 @@
+// This file has been generated by asn1_uper_model.py.
+// Manual changes are likely to disappear without a trace.
+
+
+use serde::{Deserialize, Serialize};
+
+use crate::asn1_uper::{UperDecode, UperEncode, UperVisit};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct UicBarcodeHeader {
+    #[uper(ia5)] pub format: String,
+    pub level_2_signed_data: Level2DataType,
+    pub level_2_signature: Option<Vec<u8>>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct Level2DataType {
+    pub level_1_data: Level1DataType,
+    pub level_1_signature: Option<Vec<u8>>,
+    pub level_2_data: Option<DataType>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct Level1DataType {
+    #[uper(min = 1, max = 32000)] pub security_provider_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "securityProviderIA5", exclusive_with = security_provider_num, ia5)] pub security_provider_ia_5: Option<String>,
+    #[uper(min = 0, max = 99999)] pub key_id: Option<crate::asn1_uper::Integer>,
+    #[serde(default)] pub data_sequence: Vec<DataType>,
+    pub level_1_key_alg: Option<crate::asn1_uper::ObjectIdentifier>,
+    pub level_2_key_alg: Option<crate::asn1_uper::ObjectIdentifier>,
+    pub level_1_signing_alg: Option<crate::asn1_uper::ObjectIdentifier>,
+    pub level_2_signing_alg: Option<crate::asn1_uper::ObjectIdentifier>,
+    #[uper(name = "level2publicKey")] pub level_2_public_key: Option<Vec<u8>>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct DataType {
+    #[uper(ia5)] pub data_format: String,
+    pub data: Vec<u8>,
+}
@@ -0,0 +1,38 @@
+//! UIC (rail) ticket barcode decoder.
+//!
+//! The main entry point is [`decode_barcode_bytes`], which takes the raw contents of a UIC ticket
+//! barcode and returns a [`DecodedTicket`] describing the container and the records within it.
+//! Dynamic barcodes (DOSIPAS), recognized by [`is_dynamic_barcode`], are decoded using
+//! [`decode_dynamic_barcode_bytes`] instead.
+
+
+pub mod asn1_uper;
+pub mod container;
+mod cryptography;
+pub mod dosipas;
+pub mod dosipas_1;
+pub mod dosipas_2;
+pub mod key_db;
+pub mod records;
+pub mod ticket;
+pub mod uflex_1;
+pub mod uflex_1_ext;
+pub mod uflex_2;
+pub mod uflex_2_ext;
+pub mod uflex_3;
+pub mod uflex_3_ext;
+pub mod uflex_upgrade;
+pub mod uhead;
+pub mod utlay;
+pub mod utlay_painter;
+
+
+pub use crate::container::{ContainerError, ContainerWriteError, UicContainer, UicRecord};
+pub use crate::dosipas::{
+    decode_dynamic_barcode_bytes, decode_dynamic_barcode_bytes_with_registry, is_dynamic_barcode,
+    DecodedDynamicBarcode,
+};
+pub use crate::records::{RecordDecoder, RecordDecoderRegistry, RecordError, RecordValue};
+pub use crate::ticket::{
+    decode_barcode_bytes, decode_barcode_bytes_with_registry, DecodedTicket, Header, Record,
+};
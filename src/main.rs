@@ -1,24 +1,29 @@
-mod asn1_uper;
-mod cryptography;
-mod key_db;
 mod pngify;
-mod uflex_3;
-mod uflex_3_ext;
-mod utlay_painter;
 
 
+use std::fmt::Write as _;
 use std::fs::File;
-use std::io::{Cursor, Read, BufWriter};
+use std::io::BufWriter;
 use std::path::{Path, PathBuf};
 
-use clap::Parser;
-use flate2;
+use clap::{Parser, ValueEnum};
 use rand::Rng as _;
 use rxing::Writer as _;
-
-use crate::asn1_uper::{to_bits_msb_first, to_bytes_msb_first};
-use crate::key_db::Signature;
-use crate::uflex_3_ext::output_ticket_validity;
+use serde::Serialize;
+
+use uic_ticket_decode::{
+    decode_barcode_bytes, decode_dynamic_barcode_bytes, is_dynamic_barcode, DecodedDynamicBarcode,
+    DecodedTicket, RecordValue, UicContainer, UicRecord,
+};
+use uic_ticket_decode::asn1_uper::{BitSlice, BitVec};
+use uic_ticket_decode::asn1_uper::trace::trace_decode;
+use uic_ticket_decode::dosipas::{BarcodeHeader, DataBlock};
+use uic_ticket_decode::key_db::Signature;
+use uic_ticket_decode::uflex_3::UicRailTicketData;
+use uic_ticket_decode::uflex_3_ext::{output_ticket_validity, ticket_validity};
+use uic_ticket_decode::uflex_upgrade::upgrade_record_value;
+use uic_ticket_decode::uhead::UHead;
+use uic_ticket_decode::utlay::UTlay;
 
 
 #[derive(Parser)]
@@ -26,6 +31,36 @@ enum ProgMode {
     Barcode(BarcodeArgs),
     Data(DataArgs),
     Encode(EncodeArgs),
+    FieldMap(UFlexDataArgs),
+    CheckCanonical(UFlexDataArgs),
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable text.
+    #[default]
+    Text,
+
+    /// A single JSON document. If the barcode cannot be decoded, the document only contains an
+    /// `error` member with the reason.
+    Json,
+}
+
+/// The outcome of verifying the signature of a ticket.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum SignatureVerification {
+    /// The signature matches the ticket data.
+    Valid,
+
+    /// The signature does not match the ticket data.
+    Invalid,
+
+    /// The key database does not contain the key with which the ticket has been signed.
+    KeyNotFound,
+
+    /// The signature cannot be verified, e.g. because it or the key is malformed.
+    Error { message: String },
 }
 
 #[derive(Parser)]
@@ -35,6 +70,10 @@ struct BarcodeArgs {
     /// Path to the XML key database for verification.
     #[arg(short, long)]
     pub keys_path: Option<PathBuf>,
+
+    /// The format in which to output the decoded ticket.
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: OutputFormat,
 }
 
 #[derive(Parser)]
@@ -44,9 +83,26 @@ struct DataArgs {
     #[arg(short, long)]
     pub re_encode_path: Option<PathBuf>,
 
+    /// Upgrade a U_FLEX record of version 01 or 02 to version 03 when re-encoding it.
+    #[arg(short, long)]
+    pub upgrade: bool,
+
     /// Path to the XML key database for verification.
     #[arg(short, long)]
     pub keys_path: Option<PathBuf>,
+
+    /// The format in which to output the decoded ticket.
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: OutputFormat,
+}
+
+#[derive(Parser)]
+struct UFlexDataArgs {
+    pub data_path: PathBuf,
+
+    /// Treat the file as the UPER-encoded U_FLEX record data instead of barcode data.
+    #[arg(short, long)]
+    pub uper: bool,
 }
 
 #[derive(Parser)]
@@ -57,6 +113,14 @@ struct EncodeArgs {
     #[arg(short, long)]
     pub png: bool,
 
+    /// Path to a JSON file with a U_HEAD record to emit before the U_FLEX record.
+    #[arg(long)]
+    pub head_json_path: Option<PathBuf>,
+
+    /// Path to a JSON file with a U_TLAY record to emit after the U_FLEX record.
+    #[arg(long)]
+    pub layout_json_path: Option<PathBuf>,
+
     #[arg(default_value = "6969")]
     pub signer_number: String,
 
@@ -74,13 +138,6 @@ fn hexdump(bs: &[u8]) {
 
 
 fn encode(encode_args: EncodeArgs) {
-    if encode_args.signer_number.len() != 4 {
-        panic!("signer number {:?} is not 4 bytes long", encode_args.signer_number);
-    }
-    if encode_args.key_id.len() != 5 {
-        panic!("key ID {:?} is not 4 bytes long", encode_args.key_id);
-    }
-
     // outer structure:
     // "#UT02"
     // 4 bytes signer number
@@ -92,59 +149,76 @@ fn encode(encode_args: EncodeArgs) {
 
     // inner structure:
     // loop start
-    // 6 bytes record ID ("U_FLEX")
+    // 6 bytes record ID ("U_HEAD", "U_FLEX", "U_TLAY")
     // 2 bytes version ("03")
     // 4 bytes textual representation of record length
     // rest record data
     // loop end
 
-    // record data: unaligned PER encoding of UicRailTicketData structure
+    // U_HEAD record data: fixed-length text fields
+    // U_FLEX record data: unaligned PER encoding of UicRailTicketData structure
+    // U_TLAY record data: layout standard followed by positioned text fields
+
+    let mut records = Vec::new();
+
+    if let Some(head_json_path) = &encode_args.head_json_path {
+        // deserialize UHead from JSON
+        let head_json_string = std::fs::read_to_string(head_json_path)
+            .expect("failed to read U_HEAD JSON file");
+        let uhead: UHead = serde_json::from_str(&head_json_string)
+            .expect("failed to deserialize U_HEAD JSON");
+        let uhead_bytes = uhead.to_bytes()
+            .expect("failed to serialize U_HEAD record");
+        records.push(UicRecord::new("U_HEAD", "01", uhead_bytes));
+    }
 
     // deserialize UicRailTicketData from JSON
     let json_string = std::fs::read_to_string(&encode_args.json_path)
         .expect("failed to read JSON file");
-    let ticket_data: crate::uflex_3::UicRailTicketData = serde_json::from_str(&json_string)
+    let ticket_data: UicRailTicketData = serde_json::from_str(&json_string)
         .expect("failed to deserialize JSON");
 
+    // report all constraint violations at once instead of failing at the first one
+    if let Err(violations) = ticket_data.validate() {
+        eprintln!("ticket data violates constraints:");
+        for violation in violations {
+            eprintln!("  {}", violation);
+        }
+        std::process::exit(1);
+    }
+
     // serialize UicRailTicketData to bytes
-    let mut uper_bits = Vec::new();
+    let mut uper_bits = BitVec::new();
     ticket_data.write_uper(&mut uper_bits)
         .expect("failed to serialize ticket bits");
-    let uper_bytes = to_bytes_msb_first(&uper_bits);
-    let uper_length = 6 + 2 + 4 + uper_bytes.len();
-    let uper_length_text = format!("{:04}", uper_length);
-    if uper_length_text.len() != 4 {
-        panic!("record length as text does not fit into four bytes");
-    }
-    let mut full_flex: Vec<u8> = Vec::with_capacity(uper_length);
-    full_flex.extend(b"U_FLEX03");
-    full_flex.extend(uper_length_text.as_bytes());
-    full_flex.extend(&uper_bytes);
-
-    // zlib-compress
-    let mut compressed_bytes = Vec::new();
-    flate2::read::ZlibEncoder::new(Cursor::new(&full_flex), flate2::Compression::best())
-        .read_to_end(&mut compressed_bytes)
-        .expect("failed to compress data");
-    let compressed_length_text = format!("{:04}", compressed_bytes.len());
-    if compressed_length_text.len() != 4 {
-        panic!("compressed data length as text does not fit into four bytes");
+    let uper_bytes = uper_bits.into_bytes();
+    records.push(UicRecord::new("U_FLEX", "03", uper_bytes));
+
+    if let Some(layout_json_path) = &encode_args.layout_json_path {
+        // deserialize UTlay from JSON
+        let layout_json_string = std::fs::read_to_string(layout_json_path)
+            .expect("failed to read U_TLAY JSON file");
+        let utlay: UTlay = serde_json::from_str(&layout_json_string)
+            .expect("failed to deserialize U_TLAY JSON");
+        let utlay_bytes = utlay.to_bytes()
+            .expect("failed to serialize U_TLAY record");
+        records.push(UicRecord::new("U_TLAY", "01", utlay_bytes));
     }
 
     // embed in outer structure
-    let mut outer_bytes: Vec<u8> = Vec::new();
-    outer_bytes.extend(b"#UT02");
-    outer_bytes.extend(encode_args.signer_number.as_bytes());
-    outer_bytes.extend(encode_args.key_id.as_bytes());
-
     let mut dsa_r = [0u8; 32];
     let mut dsa_s = [0u8; 32];
     rand::thread_rng().fill(&mut dsa_r);
     rand::thread_rng().fill(&mut dsa_s);
-    outer_bytes.extend(&dsa_r);
-    outer_bytes.extend(&dsa_s);
-    outer_bytes.extend(compressed_length_text.as_bytes());
-    outer_bytes.extend(&compressed_bytes);
+    let container = UicContainer::new(
+        2,
+        encode_args.signer_number,
+        encode_args.key_id,
+        Signature::Dsa { r: dsa_r.to_vec(), s: dsa_s.to_vec() },
+        records,
+    );
+    let outer_bytes = container.to_bytes()
+        .expect("failed to assemble UIC ticket container");
 
     if encode_args.png {
         // convert bytes to pseudo-textual string by pretending it's ISO-8859-1
@@ -174,300 +248,608 @@ fn encode(encode_args: EncodeArgs) {
 }
 
 
+/// Reads the UPER-encoded U_FLEX record data, either directly or from the U_FLEX version 03 record
+/// of the barcode data.
+fn read_uflex_data(args: &UFlexDataArgs) -> Vec<u8> {
+    let data = std::fs::read(&args.data_path)
+        .expect("failed to read data");
+    if args.uper {
+        data
+    } else {
+        let container = match UicContainer::from_bytes(&data) {
+            Ok(c) => c,
+            Err(e) => {
+                eprintln!("failed to decode ticket container: {}", e);
+                std::process::exit(1);
+            },
+        };
+        let record = container.records.into_iter()
+            .find(|record| record.id == "U_FLEX" && record.version == "03");
+        match record {
+            Some(r) => r.data,
+            None => {
+                eprintln!("ticket does not contain a U_FLEX version 03 record");
+                std::process::exit(1);
+            },
+        }
+    }
+}
+
+
+fn field_map(field_map_args: UFlexDataArgs) {
+    let uper_bytes = read_uflex_data(&field_map_args);
+    let uper_bits = BitSlice::new(&uper_bytes);
+    let (field_map, result) = trace_decode(uper_bits, UicRailTicketData::try_from_uper);
+    print!("{}", field_map);
+    if let Err(e) = result {
+        println!();
+        match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                println!("decoding failed: {}", e.at_offset_from(uper_bits));
+            },
+            nom::Err::Incomplete(_) => {
+                println!("decoding failed: incomplete data");
+            },
+        }
+        std::process::exit(1);
+    }
+}
+
+
+fn check_canonical(check_args: UFlexDataArgs) {
+    let uper_bytes = read_uflex_data(&check_args);
+    match UicRailTicketData::check_canonical_uper(BitSlice::new(&uper_bytes)) {
+        Ok(report) => {
+            print!("{}", report);
+            if !report.is_canonical() {
+                std::process::exit(1);
+            }
+        },
+        Err(e) => {
+            println!("{}", e);
+            std::process::exit(1);
+        },
+    }
+}
+
+
 fn main() {
     let prog_mode = ProgMode::parse();
-    let (barcode_contents, re_encode_path, keys_path_opt) = match prog_mode {
+    let (barcode_contents, re_encode_path, upgrade, keys_path_opt, format) = match prog_mode {
         ProgMode::Barcode(barcode_args) => {
             let barcode = rxing::helpers::detect_in_file(&barcode_args.barcode_path, None)
                 .expect("failed to detect Aztec barcode");
             let barcode_contents: Vec<u8> = barcode.getText().chars()
                 .map(|c| u8::try_from(u32::from(c)).expect("failed to decode character as byte"))
                 .collect();
-            (barcode_contents, None, barcode_args.keys_path)
+            (barcode_contents, None, false, barcode_args.keys_path, barcode_args.format)
         },
         ProgMode::Data(data_args) => {
             let data = std::fs::read(&data_args.data_path)
                 .expect("failed to read barcode data");
-            (data, data_args.re_encode_path, data_args.keys_path)
+            (data, data_args.re_encode_path, data_args.upgrade, data_args.keys_path, data_args.format)
         },
         ProgMode::Encode(encode_args) => {
             encode(encode_args);
             return;
         },
+        ProgMode::FieldMap(field_map_args) => {
+            field_map(field_map_args);
+            return;
+        },
+        ProgMode::CheckCanonical(check_args) => {
+            check_canonical(check_args);
+            return;
+        },
     };
 
-    print!("barcode contents:");
-    hexdump(&barcode_contents);
+    if format == OutputFormat::Text {
+        print!("barcode contents:");
+        hexdump(&barcode_contents);
+    }
 
-    // ERA-REC-122 B.12 § 10.6
-    if !barcode_contents.starts_with(b"#UT") {
-        panic!("barcode does not contain a UIC ticket");
+    if is_dynamic_barcode(&barcode_contents) {
+        decode_dynamic(&barcode_contents, re_encode_path.as_deref(), upgrade, keys_path_opt.is_some(), format);
+        return;
     }
-    let version = &barcode_contents[3..5];
-    let (compressed_bytes, signer_number, key_id, signature) = if version == b"01" {
-        println!("UIC ticket version 1");
 
-        let signer_number_bytes = &barcode_contents[5..9];
-        let signer_number = String::from_utf8(Vec::from(signer_number_bytes))
-            .expect("signer number is not decodable");
-        println!("  signer number: {}", signer_number);
+    let ticket = match decode_barcode_bytes(&barcode_contents) {
+        Ok(t) => t,
+        Err(e) => exit_with_error(format, format!("failed to decode ticket: {}", e)),
+    };
 
-        let key_id_bytes = &barcode_contents[9..14];
-        let key_id = String::from_utf8(Vec::from(key_id_bytes))
-            .expect("key ID is not decodable");
-        println!("  key ID: {}", key_id);
+    // verify?
+    let signature_verification = keys_path_opt
+        .map(|keys_path| verify_signature(&ticket, &keys_path, format));
 
-        let signature_bytes = &barcode_contents[14..64];
-        print!("  signature bytes (ASN.1):");
-        hexdump(signature_bytes);
-
-        let compressed_len_bytes = &barcode_contents[64..68];
-        let compressed_len_string = String::from_utf8(Vec::from(compressed_len_bytes))
-            .expect("compressed length is not decodable");
-        let compressed_len: usize = compressed_len_string.parse()
-            .expect("compressed length is not parsable");
-        println!("  compressed data length is {} bytes", compressed_len);
-        (&barcode_contents[68..68+compressed_len], signer_number, key_id, Signature::Asn1(signature_bytes.to_vec()))
-    } else if version == b"02" {
-        println!("UIC ticket version 2");
-
-        let signer_number_bytes = &barcode_contents[5..9];
-        let signer_number = String::from_utf8(Vec::from(signer_number_bytes))
-            .expect("signer number is not decodable");
-        println!("  signer number: {}", signer_number);
-
-        let key_id_bytes = &barcode_contents[9..14];
-        let key_id = String::from_utf8(Vec::from(key_id_bytes))
-            .expect("key ID is not decodable");
-        println!("  key ID: {}", key_id);
+    match format {
+        OutputFormat::Text => output_text(&ticket, signature_verification.as_ref()),
+        OutputFormat::Json => output_json(&ticket, signature_verification.as_ref()),
+    }
+    if signature_verification == Some(SignatureVerification::Invalid) {
+        // the text output has already exited
+        std::process::exit(1);
+    }
 
-        let signature_r = &barcode_contents[14..46];
-        print!("  DSA signature r:");
-        hexdump(signature_r);
-
-        let signature_s = &barcode_contents[46..78];
-        print!("  DSA signature s:");
-        hexdump(signature_s);
-
-        let compressed_len_bytes = &barcode_contents[78..82];
-        let compressed_len_string = String::from_utf8(Vec::from(compressed_len_bytes))
-            .expect("compressed length is not decodable");
-        let compressed_len: usize = compressed_len_string.parse()
-            .expect("compressed length is not parsable");
-        println!("  compressed data length is {} bytes", compressed_len);
-        (&barcode_contents[82..82+compressed_len], signer_number, key_id, Signature::Dsa { r: signature_r.to_vec(), s: signature_s.to_vec() })
-    } else {
-        panic!("unknown UIC ticket version {:?}", version);
+    if let Some(path) = re_encode_path {
+        let uflex_value = ticket.records.iter()
+            .filter(|record| record.id == "U_FLEX")
+            .find_map(|record| record.value.as_ref());
+        if let Some(value) = uflex_value {
+            re_encode(value.as_ref(), &path, upgrade);
+        }
+    }
+}
+
+/// Writes the record data of a U_FLEX record value, upgrading it to version 03 if requested.
+fn re_encode(value: &dyn RecordValue, path: &Path, upgrade: bool) {
+    let upgraded = if upgrade { upgrade_record_value(value) } else { None };
+    let bytes = match upgraded {
+        Some(Err(e)) => {
+            eprintln!("failed to upgrade ticket data: {}", e);
+            std::process::exit(1);
+        },
+        Some(Ok(upgraded)) => {
+            for loss in &upgraded.losses {
+                eprintln!("warning: {}", loss);
+            }
+            upgraded.ticket_data.to_record_data()
+                .expect("failed to encode upgraded UicRailTicketData")
+        },
+        None => value.to_record_data()
+            .expect("failed to re-encode UicRailTicketData"),
     };
+    std::fs::write(path, &bytes)
+        .expect("failed to write re-encoded data");
+}
 
-    print!("  compressed data bytes:");
-    hexdump(compressed_bytes);
+fn decode_dynamic(barcode_contents: &[u8], re_encode_path: Option<&Path>, upgrade: bool, verify: bool, format: OutputFormat) {
+    let barcode = match decode_dynamic_barcode_bytes(barcode_contents) {
+        Ok(b) => b,
+        Err(e) => exit_with_error(format, format!("failed to decode dynamic barcode: {}", e)),
+    };
+    if verify {
+        eprintln!("verifying the signatures of dynamic barcodes is not supported");
+    }
 
-    // verify?
-    if let Some(keys_path) = keys_path_opt {
-        let keys_db_string = std::fs::read_to_string(keys_path)
-            .expect("failed to read key database");
-        let keys_db = crate::key_db::database_from_xml(&keys_db_string)
-            .expect("failed to parse key database");
-
-        let signer_number_u16: u16 = signer_number.parse()
-            .expect("failed to parse signer number as u16");
-        let key_id_u32: u32 = key_id.parse()
-            .expect("failed to parse key ID as u32");
-        let key = keys_db.get(&(signer_number_u16, key_id_u32))
-            .expect("key not found, cannot verify");
-
-        let data_valid = key.verify(&signature, &compressed_bytes)
-            .expect("verification failed");
-        if data_valid {
-            println!("  signature is OK");
-        } else {
-            panic!("signature is invalid!");
+    match format {
+        OutputFormat::Text => output_dynamic_text(&barcode),
+        OutputFormat::Json => output_dynamic_json(&barcode),
+    }
+
+    if let Some(path) = re_encode_path {
+        let fcb_value = barcode.data_blocks.iter()
+            .find_map(|block| block.value.as_ref());
+        if let Some(value) = fcb_value {
+            re_encode(value.as_ref(), path, upgrade);
         }
     }
+}
 
-    // uncompress
-    let mut data_bytes = Vec::new();
-    flate2::read::ZlibDecoder::new(Cursor::new(compressed_bytes))
-        .read_to_end(&mut data_bytes)
-        .expect("failed to decompress data");
+/// Reports that the barcode cannot be decoded and exits. In the JSON format, the reason is output as
+/// a document with an `error` member.
+fn exit_with_error(format: OutputFormat, message: String) -> ! {
+    match format {
+        OutputFormat::Text => eprintln!("{}", message),
+        OutputFormat::Json => println!("{}", serde_json::json!({ "error": message })),
+    }
+    std::process::exit(1);
+}
 
-    print!("  uncompressed data bytes:");
-    hexdump(&data_bytes);
+/// Verifies the signature of the ticket using the key database at the given path. Exits if the key
+/// database cannot be read.
+fn verify_signature(ticket: &DecodedTicket, keys_path: &Path, format: OutputFormat) -> SignatureVerification {
+    let keys_db_string = match std::fs::read_to_string(keys_path) {
+        Ok(s) => s,
+        Err(e) => exit_with_error(format, format!("failed to read key database: {}", e)),
+    };
+    let keys_db = match uic_ticket_decode::key_db::database_from_xml(&keys_db_string) {
+        Ok(db) => db,
+        Err(e) => exit_with_error(format, format!("failed to parse key database: {}", e)),
+    };
 
-    let mut remaining_bytes = data_bytes.as_slice();
-    while remaining_bytes.len() > 0 {
-        println!();
+    let Ok(signer_number_u16) = ticket.header.signer_number.parse::<u16>() else {
+        return SignatureVerification::Error {
+            message: format!("signer number {:?} is not a number", ticket.header.signer_number),
+        };
+    };
+    let Ok(key_id_u32) = ticket.header.key_id.parse::<u32>() else {
+        return SignatureVerification::Error {
+            message: format!("key ID {:?} is not a number", ticket.header.key_id),
+        };
+    };
+    let Some(key) = keys_db.get(&(signer_number_u16, key_id_u32)) else {
+        return SignatureVerification::KeyNotFound;
+    };
 
-        let record_id = &remaining_bytes[0..6];
-        let record_id_string = String::from_utf8(Vec::from(record_id))
-            .expect("failed to decode record ID");
-        println!("record {}", record_id_string);
+    match ticket.verify_signature(key) {
+        Ok(true) => SignatureVerification::Valid,
+        Ok(false) => SignatureVerification::Invalid,
+        Err(e) => SignatureVerification::Error { message: e.to_string() },
+    }
+}
 
-        let record_version = &remaining_bytes[6..8];
-        let record_version_string = String::from_utf8(Vec::from(record_version))
-            .expect("failed to decode record version");
-        println!("  version {}", record_version_string);
+fn output_text(ticket: &DecodedTicket, signature_verification: Option<&SignatureVerification>) {
+    output_header(ticket);
 
-        let record_length_bytes = &remaining_bytes[8..12];
-        let record_length_string = String::from_utf8(Vec::from(record_length_bytes))
-            .expect("failed to decode record length");
-        let record_length: usize = record_length_string.parse()
-            .expect("failed to parse record length");
-        println!("  length (including ID and version): {}", record_length);
+    match signature_verification {
+        Some(SignatureVerification::Valid) => println!("  signature is OK"),
+        Some(SignatureVerification::Invalid) => exit_with_error(OutputFormat::Text, "signature is INVALID".to_owned()),
+        Some(SignatureVerification::KeyNotFound) => println!("  signature cannot be verified: key not found"),
+        Some(SignatureVerification::Error { message }) => println!("  signature cannot be verified: {}", message),
+        None => {},
+    }
 
-        let record_data = &remaining_bytes[12..record_length];
-        decode_record(record_id, record_version, record_data, re_encode_path.as_ref().map(|p| p.as_path()));
+    print!("  uncompressed data bytes:");
+    hexdump(&ticket.decompressed_data);
 
-        remaining_bytes = &remaining_bytes[record_length..];
+    for record in &ticket.records {
+        println!();
+        println!("record {}", record.id);
+        println!("  version {}", record.version);
+        println!("  length (including ID and version): {}", record.length);
+
+        match &record.value {
+            Some(value) => {
+                output_record_value(value.as_ref());
+            },
+            None => {
+                println!("  cannot decode this record type; hex dump:");
+                hexdump(&record.data);
+            },
+        }
     }
 }
 
-fn decode_record(record_id: &[u8], record_version: &[u8], record_data: &[u8], re_encode_path: Option<&Path>) {
-    if record_id == b"U_FLEX" && record_version == b"03" {
-        decode_record_uflex_3(record_data, re_encode_path);
-    } else if record_id == b"U_HEAD" && record_version == b"01" {
-        decode_record_uhead_1(record_data);
-    } else if record_id == b"U_TLAY" && record_version == b"01" {
-        decode_record_utlay_1(record_data);
-    } else {
-        println!("  cannot decode this record type; hex dump:");
-        hexdump(record_data);
+fn output_json(ticket: &DecodedTicket, signature_verification: Option<&SignatureVerification>) {
+    let json_string = serde_json::to_string_pretty(&ticket_to_json(ticket, signature_verification))
+        .expect("failed to serialize JSON");
+    println!("{}", json_string);
+}
+
+fn ticket_to_json(ticket: &DecodedTicket, signature_verification: Option<&SignatureVerification>) -> serde_json::Value {
+    let signature = match &ticket.header.signature {
+        Signature::Asn1(signature_bytes) => serde_json::json!({
+            "asn1": bytes_to_hex(signature_bytes),
+        }),
+        Signature::Dsa { r, s } => serde_json::json!({
+            "dsa": {
+                "r": bytes_to_hex(r),
+                "s": bytes_to_hex(s),
+            },
+        }),
+    };
+
+    let mut records = Vec::with_capacity(ticket.records.len());
+    for record in &ticket.records {
+        let value = record.value.as_ref()
+            .map(|value| record_value_to_json(value.as_ref()));
+        let validity = record.value.as_ref()
+            .and_then(|value| validity_to_json(value.as_ref()));
+        records.push(serde_json::json!({
+            "id": record.id,
+            "version": record.version,
+            "length": record.length,
+            "data": bytes_to_hex(&record.data),
+            "value": value,
+            "validity": validity,
+        }));
     }
+
+    serde_json::json!({
+        "header": {
+            "version": ticket.header.version,
+            "signer_number": ticket.header.signer_number,
+            "key_id": ticket.header.key_id,
+            "signature": signature,
+        },
+        "signature_verification": signature_verification,
+        "compressed_data": bytes_to_hex(&ticket.compressed_data),
+        "decompressed_data": bytes_to_hex(&ticket.decompressed_data),
+        "records": records,
+    })
 }
 
-fn decode_record_uflex_3(record_data: &[u8], re_encode_path: Option<&Path>) {
-    // https://github.com/UnionInternationalCheminsdeFer/UIC-barcode/blob/master/misc/uicRailTicketData_v3.0.3.asn
+/// Converts a decoded record value into JSON, or into an object with an `error` member if that
+/// fails.
+fn record_value_to_json(value: &dyn RecordValue) -> serde_json::Value {
+    value.to_json()
+        .unwrap_or_else(|e| serde_json::json!({ "error": e.to_string() }))
+}
 
-    // convert record data to bits
-    let record_data_bits = to_bits_msb_first(record_data);
+/// Interprets the validity of U_FLEX version 3 ticket data as JSON, or as an object with an `error`
+/// member if that fails. Returns `None` for any other value.
+fn validity_to_json(value: &dyn RecordValue) -> Option<serde_json::Value> {
+    let data = value.downcast_ref::<UicRailTicketData>()?;
+    let validity_json = match ticket_validity(&data.issuing_detail, &data.transport_document) {
+        Ok(validity) => serde_json::to_value(validity)
+            .expect("failed to serialize validity as JSON"),
+        Err(e) => serde_json::json!({ "error": e.to_string() }),
+    };
+    Some(validity_json)
+}
 
-    // the top structure is UicRailTicketData
-    let (_rest, uic_rail_ticket_data) = crate::uflex_3::UicRailTicketData::try_from_uper(&record_data_bits)
-        .expect("failed to decode UicRailTicketData");
+fn output_dynamic_text(barcode: &DecodedDynamicBarcode) {
+    println!("UIC dynamic barcode, header format {}", barcode.header.format());
+    if let Some(security_provider) = barcode.header.security_provider() {
+        println!("  security provider: {}", security_provider);
+    }
+    if let Some(key_id) = barcode.header.key_id() {
+        println!("  key ID: {}", key_id);
+    }
+    if let Some(signature) = barcode.header.level_1_signature() {
+        print!("  level 1 signature bytes:");
+        hexdump(signature);
+    }
+    if let Some(signature) = barcode.header.level_2_signature() {
+        print!("  level 2 signature bytes:");
+        hexdump(signature);
+    }
 
-    println!("{:#?}", uic_rail_ticket_data);
+    let level_2_block = barcode.level_2_data.as_ref()
+        .map(|block| ("level 2 data".to_owned(), block));
+    let blocks = barcode.data_blocks.iter()
+        .enumerate()
+        .map(|(index, block)| (format!("data block {}", index), block))
+        .chain(level_2_block);
+    for (title, block) in blocks {
+        println!();
+        println!("{}", title);
+        println!("  format {}", block.format);
+        println!("  length: {}", block.data.len());
+
+        match &block.value {
+            Some(value) => {
+                output_record_value(value.as_ref());
+            },
+            None => {
+                println!("  cannot decode this data format; hex dump:");
+                hexdump(&block.data);
+            },
+        }
+    }
+}
 
-    // output interpreted date/time info
-    output_ticket_validity(&uic_rail_ticket_data.issuing_detail, &uic_rail_ticket_data.transport_document);
+fn output_dynamic_json(barcode: &DecodedDynamicBarcode) {
+    let json_string = serde_json::to_string_pretty(&dynamic_barcode_to_json(barcode))
+        .expect("failed to serialize JSON");
+    println!("{}", json_string);
+}
 
-    if let Some(path) = re_encode_path {
-        let mut buf = Vec::new();
-        uic_rail_ticket_data.write_uper(&mut buf)
-            .expect("failed to re-encode UicRailTicketData");
+fn dynamic_barcode_to_json(barcode: &DecodedDynamicBarcode) -> serde_json::Value {
+    let block_to_json = |block: &DataBlock| {
+        let value = block.value.as_ref()
+            .map(|value| record_value_to_json(value.as_ref()));
+        let validity = block.value.as_ref()
+            .and_then(|value| validity_to_json(value.as_ref()));
+        serde_json::json!({
+            "format": block.format,
+            "data": bytes_to_hex(&block.data),
+            "value": value,
+            "validity": validity,
+        })
+    };
 
-        let bytes = to_bytes_msb_first(&buf);
-        std::fs::write(path, &bytes)
-            .expect("failed to write re-encoded data");
+    // the data blocks are output separately
+    let header = &barcode.header;
+    let mut header_json = serde_json::json!({
+        "format": header.format(),
+        "security_provider": header.security_provider(),
+        "key_id": header.key_id(),
+        "level_1_key_alg": header.level_1_key_alg(),
+        "level_2_key_alg": header.level_2_key_alg(),
+        "level_1_signing_alg": header.level_1_signing_alg(),
+        "level_2_signing_alg": header.level_2_signing_alg(),
+        "level_2_public_key": header.level_2_public_key().map(bytes_to_hex),
+        "level_1_signature": header.level_1_signature().map(bytes_to_hex),
+        "level_2_signature": header.level_2_signature().map(bytes_to_hex),
+    });
+    if let BarcodeHeader::U2(u2_header) = header {
+        let level_1_data = &u2_header.level_2_signed_data.level_1_data;
+        header_json["end_of_validity_year"] = serde_json::json!(level_1_data.end_of_validity_year);
+        header_json["end_of_validity_day"] = serde_json::json!(level_1_data.end_of_validity_day);
+        header_json["end_of_validity_time"] = serde_json::json!(level_1_data.end_of_validity_time);
+        header_json["validity_duration"] = serde_json::json!(level_1_data.validity_duration);
     }
+
+    serde_json::json!({
+        "header": header_json,
+        "data_blocks": barcode.data_blocks.iter().map(block_to_json).collect::<Vec<_>>(),
+        "level_2_data": barcode.level_2_data.as_ref().map(block_to_json),
+    })
 }
 
-fn bytes_to_string(bs: &[u8]) -> String {
-    match std::str::from_utf8(bs) {
-        Ok(s) => format!("{:?}", s),
-        Err(_) => format!("{:?}", bs),
+fn bytes_to_hex(bs: &[u8]) -> String {
+    let mut ret = String::with_capacity(2 * bs.len());
+    for b in bs {
+        write!(ret, "{:02X}", b).unwrap();
     }
+    ret
 }
 
-fn decode_record_uhead_1(record_data: &[u8]) {
-    // https://www.era.europa.eu/system/files/2022-11/era_technical_document_tap_b_7_v1.3.0.pdf § 8.3
-
-    let distributing_ru = bytes_to_string(&record_data[0..4]);
-    println!("  distributing RU: {}", distributing_ru);
-
-    let ticket_key = bytes_to_string(&record_data[4..24]);
-    println!("  ticket key: {}", ticket_key);
-
-    let time_of_issuance = bytes_to_string(&record_data[24..36]);
-    println!("  time of issuance: {}", time_of_issuance);
-
-    let flags = bytes_to_string(&record_data[36..37]);
-    println!("  flags: {}", flags);
-    if let Ok(s) = std::str::from_utf8(&record_data[36..37]) {
-        if let Ok(b) = s.parse::<u8>() {
-            if b == 0 {
-                println!("    no flags set");
-            } else {
-                if b & 0b001 != 0 {
-                    println!("    international ticket");
-                }
-                if b & 0b010 != 0 {
-                    println!("    edited by agent");
-                }
-                if b & 0b100 != 0 {
-                    println!("    specimen");
-                }
-            }
-        }
+fn output_header(ticket: &DecodedTicket) {
+    println!("UIC ticket version {}", ticket.header.version);
+    println!("  signer number: {}", ticket.header.signer_number);
+    println!("  key ID: {}", ticket.header.key_id);
+    match &ticket.header.signature {
+        Signature::Asn1(signature_bytes) => {
+            print!("  signature bytes (ASN.1):");
+            hexdump(signature_bytes);
+        },
+        Signature::Dsa { r, s } => {
+            print!("  DSA signature r:");
+            hexdump(r);
+            print!("  DSA signature s:");
+            hexdump(s);
+        },
     }
+    println!("  compressed data length is {} bytes", ticket.compressed_data.len());
+    print!("  compressed data bytes:");
+    hexdump(&ticket.compressed_data);
+}
 
-    let language = bytes_to_string(&record_data[37..39]);
-    println!("  language: {}", language);
+fn output_record_value(value: &dyn RecordValue) {
+    for line in value.describe().lines() {
+        println!("  {}", line);
+    }
 
-    let second_language = bytes_to_string(&record_data[39..41]);
-    println!("  second language: {}", second_language);
+    if let Some(uic_rail_ticket_data) = value.downcast_ref::<UicRailTicketData>() {
+        // output interpreted date/time info
+        output_ticket_validity(&uic_rail_ticket_data.issuing_detail, &uic_rail_ticket_data.transport_document);
+    }
 }
 
-fn decode_record_utlay_1(record_data: &[u8]) {
-    // https://www.era.europa.eu/system/files/2022-11/era_technical_document_tap_b_7_v1.3.0.pdf § 8.4
-
-    let layout_standard = bytes_to_string(&record_data[0..4]);
-    println!("  layout standard: {}", layout_standard);
-
-    let number_fields = bytes_to_string(&record_data[4..8]);
-    println!("  number of fields: {}", number_fields);
-    if let Ok(s) = std::str::from_utf8(&record_data[4..8]) {
-        if let Ok(n) = s.parse::<usize>() {
-            let mut canvas = utlay_painter::Canvas::new();
-            let mut index = 8;
-            for i in 0..n {
-                println!("  field {}:", i);
-
-                let field_line = bytes_to_string(&record_data[index+0..index+2]);
-                println!("    line: {}", field_line);
-
-                let field_column = bytes_to_string(&record_data[index+2..index+4]);
-                println!("    column: {}", field_column);
-
-                let field_height = bytes_to_string(&record_data[index+4..index+6]);
-                println!("    height: {}", field_height);
-
-                let field_width = bytes_to_string(&record_data[index+6..index+8]);
-                println!("    width: {}", field_width);
-
-                let field_formatting = bytes_to_string(&record_data[index+8..index+9]);
-                println!("    formatting: {}", field_formatting);
-
-                let field_text_length = bytes_to_usize(&record_data[index+9..index+13])
-                    .expect("failed to decode field text length");
-
-                let field_text = bytes_to_string(&record_data[index+13..index+13+field_text_length]);
-                println!("    text: {}", field_text);
-
-                let orig_index = index;
-                index += 13 + field_text_length;
-
-                let Some(line_num) = bytes_to_usize(&record_data[orig_index+0..orig_index+2]) else { continue };
-                let Some(col_num) = bytes_to_usize(&record_data[orig_index+2..orig_index+4]) else { continue };
-                let Some(height_num) = bytes_to_usize(&record_data[orig_index+4..orig_index+6]) else { continue };
-                let Some(width_num) = bytes_to_usize(&record_data[orig_index+6..orig_index+8]) else { continue };
-                let Ok(text) = String::from_utf8(Vec::from(&record_data[orig_index+13..orig_index+13+field_text_length])) else { continue };
-                let record = utlay_painter::Record {
-                    line: line_num,
-                    column: col_num,
-                    width: width_num,
-                    height: height_num,
-                    text,
-                };
-                canvas.paint_record(&record);
-            }
-            let canvas_in_a_box = utlay_painter::CanvasInABox(&canvas);
-            println!("{}", canvas_in_a_box);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uic_ticket_decode::asn1_uper::Integer;
+
+    /// The U_FLEX payload of a specimen open ticket with one traveler and one transport document.
+    const OPEN_TICKET: &[u8] = include_bytes!("../tests/data/open_ticket.uper");
+
+    fn decode_with_uflex(uflex_data: Vec<u8>) -> DecodedTicket {
+        let container = UicContainer::new(
+            2,
+            "1181",
+            "00001",
+            Signature::Dsa { r: vec![0x11; 32], s: vec![0x22; 32] },
+            vec![UicRecord::new("U_FLEX", "03", uflex_data)],
+        );
+        decode_barcode_bytes(&container.to_bytes().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_ticket_to_json() {
+        let ticket = decode_with_uflex(OPEN_TICKET.to_vec());
+        let document = ticket_to_json(&ticket, Some(&SignatureVerification::Error { message: "malformed".to_owned() }));
+
+        let mut keys: Vec<&str> = document.as_object().unwrap().keys()
+            .map(|key| key.as_str())
+            .collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["compressed_data", "decompressed_data", "header", "records", "signature_verification"]);
+
+        assert_eq!(document["header"], serde_json::json!({
+            "version": 2,
+            "signer_number": "1181",
+            "key_id": "00001",
+            "signature": {
+                "dsa": {
+                    "r": "11".repeat(32),
+                    "s": "22".repeat(32),
+                },
+            },
+        }));
+        assert_eq!(document["signature_verification"], serde_json::json!({ "result": "error", "message": "malformed" }));
+        assert_eq!(document["decompressed_data"], serde_json::json!(bytes_to_hex(&ticket.decompressed_data)));
+
+        let records = document["records"].as_array().unwrap();
+        assert_eq!(records.len(), 1);
+        let record = &records[0];
+        assert_eq!(record["id"], "U_FLEX");
+        assert_eq!(record["version"], "03");
+        assert_eq!(record["length"], 12 + OPEN_TICKET.len());
+        assert_eq!(record["data"], serde_json::json!(bytes_to_hex(OPEN_TICKET)));
+        assert_eq!(record["value"]["issuing_detail"]["issuer_pnr"], "ABC123");
+        assert_eq!(record["value"]["transport_document"][0]["ticket"]["OpenTicket"]["reference_ia_5"], "REF001");
+        assert_eq!(record["validity"], serde_json::json!({
+            "issued": "2023-05-30T10:00:00Z",
+            "documents": [
+                [
+                    { "kind": "valid_from", "at": "2023-05-30T00:00:00" },
+                    { "kind": "valid_to", "at": "2023-05-31T23:59:00" },
+                ],
+            ],
+        }));
+    }
+
+    #[test]
+    fn test_signature_verification_json() {
+        let ticket = decode_with_uflex(OPEN_TICKET.to_vec());
+        for (verification, expected) in [
+            (SignatureVerification::Valid, serde_json::json!({ "result": "valid" })),
+            (SignatureVerification::Invalid, serde_json::json!({ "result": "invalid" })),
+            (SignatureVerification::KeyNotFound, serde_json::json!({ "result": "key_not_found" })),
+        ] {
+            let document = ticket_to_json(&ticket, Some(&verification));
+            assert_eq!(document["signature_verification"], expected);
         }
     }
-}
 
-fn bytes_to_usize(slice: &[u8]) -> Option<usize> {
-    let s = std::str::from_utf8(slice).ok()?;
-    s.parse().ok()
+    #[test]
+    fn test_ticket_to_json_invalid_validity() {
+        // day 366 of 2023 does not exist; the validity must be reported as an error, not panic
+        let (_rest, mut data) = UicRailTicketData::try_from_uper(BitSlice::new(OPEN_TICKET)).unwrap();
+        data.issuing_detail.issuing_day = Integer::from_short(366);
+        let mut uper_bits = BitVec::new();
+        data.write_uper(&mut uper_bits).unwrap();
+
+        let ticket = decode_with_uflex(uper_bits.into_bytes());
+        let document = ticket_to_json(&ticket, None);
+        assert_eq!(document["signature_verification"], serde_json::Value::Null);
+        assert_eq!(document["records"][0]["value"]["issuing_detail"]["issuing_day"], "366");
+        assert_eq!(document["records"][0]["validity"], serde_json::json!({
+            "error": "time of issuance is not a valid date or time",
+        }));
+    }
+
+    #[test]
+    fn test_dynamic_barcode_to_json() {
+        use uic_ticket_decode::dosipas_2;
+
+        let header = dosipas_2::UicBarcodeHeader {
+            format: "U2".to_owned(),
+            level_2_signed_data: dosipas_2::Level2DataType {
+                level_1_data: dosipas_2::Level1DataType {
+                    security_provider_num: Some(Integer::from_short(1181)),
+                    security_provider_ia_5: None,
+                    key_id: Some(Integer::from_short(5)),
+                    data_sequence: vec![
+                        dosipas_2::DataType { data_format: "FCB3".to_owned(), data: OPEN_TICKET.to_vec() },
+                    ],
+                    level_1_key_alg: Some("1.2.840.10045.3.1.7".parse().unwrap()),
+                    level_2_key_alg: None,
+                    level_1_signing_alg: Some("2.16.840.1.101.3.4.3.2".parse().unwrap()),
+                    level_2_signing_alg: None,
+                    level_2_public_key: Some(vec![0x04, 0x05]),
+                    end_of_validity_year: Some(Integer::from_short(2025)),
+                    end_of_validity_day: Some(Integer::from_short(1)),
+                    end_of_validity_time: None,
+                    validity_duration: Some(Integer::from_short(60)),
+                },
+                level_1_signature: Some(vec![0xAB; 4]),
+                level_2_data: None,
+            },
+            level_2_signature: Some(vec![0xCD; 4]),
+        };
+        let mut bits = BitVec::new();
+        header.write_uper(&mut bits).unwrap();
+        let barcode = decode_dynamic_barcode_bytes(&bits.into_bytes()).unwrap();
+        let document = dynamic_barcode_to_json(&barcode);
+
+        // the data blocks are only output once, outside the header
+        assert_eq!(document["header"], serde_json::json!({
+            "format": "U2",
+            "security_provider": "1181",
+            "key_id": "5",
+            "level_1_key_alg": "1.2.840.10045.3.1.7",
+            "level_2_key_alg": null,
+            "level_1_signing_alg": "2.16.840.1.101.3.4.3.2",
+            "level_2_signing_alg": null,
+            "level_2_public_key": "0405",
+            "level_1_signature": "ABABABAB",
+            "level_2_signature": "CDCDCDCD",
+            "end_of_validity_year": "2025",
+            "end_of_validity_day": "1",
+            "end_of_validity_time": null,
+            "validity_duration": "60",
+        }));
+        let data_blocks = document["data_blocks"].as_array().unwrap();
+        assert_eq!(data_blocks.len(), 1);
+        assert_eq!(data_blocks[0]["format"], "FCB3");
+        assert_eq!(data_blocks[0]["data"], serde_json::json!(bytes_to_hex(OPEN_TICKET)));
+        assert_eq!(data_blocks[0]["value"]["issuing_detail"]["issuer_pnr"], "ABC123");
+        assert_eq!(document["level_2_data"], serde_json::Value::Null);
+    }
 }
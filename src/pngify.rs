@@ -3,7 +3,7 @@ use std::io::Write;
 use png;
 use rxing::common::BitMatrix;
 
-use crate::asn1_uper::to_bytes_msb_first;
+use uic_ticket_decode::asn1_uper::to_bytes_msb_first;
 
 
 pub(crate) fn write_bit_matrix_as_png<W: Write>(writer: W, bit_matrix: &BitMatrix, thickness: u32, margin: u32) {
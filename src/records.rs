@@ -0,0 +1,148 @@
+//! Decoders for the records contained within a UIC ticket.
+//!
+//! Record decoders are registered in a [`RecordDecoderRegistry`] under the record ID and version
+//! they handle. Besides the standard records (`U_FLEX`, `U_HEAD` and `U_TLAY`), decoders can be
+//! registered for proprietary records, whose IDs consist of the RICS code of the issuing company
+//! followed by two further characters (e.g. `1181AB`).
+
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+
+
+/// An error that may occur when decoding or encoding a record.
+pub type RecordError = Box<dyn std::error::Error + Send + Sync>;
+
+
+/// The decoded value of a record.
+pub trait RecordValue: Any + fmt::Debug + Send + Sync {
+    /// Returns a human-readable description of the value.
+    fn describe(&self) -> String {
+        format!("{:#?}", self)
+    }
+
+    /// Serializes the value as JSON.
+    fn to_json(&self) -> Result<serde_json::Value, RecordError>;
+
+    /// Encodes the value back into record data (excluding the record ID, version and length).
+    fn to_record_data(&self) -> Result<Vec<u8>, RecordError>;
+}
+impl dyn RecordValue {
+    /// Returns the value as a concrete type if it is of that type.
+    pub fn downcast_ref<T: RecordValue>(&self) -> Option<&T> {
+        let any: &dyn Any = self;
+        any.downcast_ref()
+    }
+}
+
+
+/// Implements [`RecordValue`] for the `UicRailTicketData` of the given U_FLEX model module and
+/// defines a `decode_record` function for the given version, to be registered in the
+/// [`RecordDecoderRegistry`].
+macro_rules! impl_uflex_record_value {
+    ($model:ident, $version:literal) => {
+        impl $crate::records::RecordValue for $crate::$model::UicRailTicketData {
+            fn to_json(&self) -> Result<serde_json::Value, $crate::records::RecordError> {
+                Ok(serde_json::to_value(self)?)
+            }
+
+            fn to_record_data(&self) -> Result<Vec<u8>, $crate::records::RecordError> {
+                let mut bits = $crate::asn1_uper::BitVec::new();
+                self.write_uper(&mut bits)?;
+                Ok(bits.into_bytes())
+            }
+        }
+
+        #[doc = concat!("Decodes a U_FLEX record, version ", $version, ", for the record decoder registry.")]
+        pub fn decode_record(record_data: &[u8]) -> Result<Box<dyn $crate::records::RecordValue>, $crate::records::RecordError> {
+            // convert record data to bits
+            let record_data_bits = $crate::asn1_uper::BitSlice::new(record_data);
+
+            // the top structure is UicRailTicketData
+            let (_rest, uic_rail_ticket_data) = $crate::$model::UicRailTicketData::try_from_uper(record_data_bits)
+                .map_err(|e| match e {
+                    nom::Err::Error(e) | nom::Err::Failure(e)
+                        => format!("failed to decode U_FLEX record: {}", e.at_offset_from(record_data_bits)),
+                    nom::Err::Incomplete(_)
+                        => "failed to decode U_FLEX record: incomplete data".to_owned(),
+                })?;
+            Ok(Box::new(uic_rail_ticket_data))
+        }
+    };
+}
+pub(crate) use impl_uflex_record_value;
+
+
+/// A decoder for a specific type of record.
+pub trait RecordDecoder: Send + Sync {
+    /// Decodes the given record data (excluding the record ID, version and length).
+    fn decode(&self, record_data: &[u8]) -> Result<Box<dyn RecordValue>, RecordError>;
+}
+impl<F> RecordDecoder for F
+    where F: Fn(&[u8]) -> Result<Box<dyn RecordValue>, RecordError> + Send + Sync
+{
+    fn decode(&self, record_data: &[u8]) -> Result<Box<dyn RecordValue>, RecordError> {
+        self(record_data)
+    }
+}
+
+
+/// A collection of record decoders, keyed by record ID and version.
+pub struct RecordDecoderRegistry {
+    decoders: BTreeMap<(String, String), Box<dyn RecordDecoder>>,
+}
+impl RecordDecoderRegistry {
+    /// Creates a registry without any decoders.
+    pub fn new() -> Self {
+        Self {
+            decoders: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a decoder for records with the given ID and version.
+    ///
+    /// Returns the decoder previously registered for this ID and version, if any.
+    pub fn register<I: Into<String>, V: Into<String>, D: RecordDecoder + 'static>(&mut self, id: I, version: V, decoder: D) -> Option<Box<dyn RecordDecoder>> {
+        self.decoders.insert((id.into(), version.into()), Box::new(decoder))
+    }
+
+    /// Removes the decoder for records with the given ID and version.
+    pub fn unregister(&mut self, id: &str, version: &str) -> Option<Box<dyn RecordDecoder>> {
+        self.decoders.remove(&(id.to_owned(), version.to_owned()))
+    }
+
+    /// Returns the decoder for records with the given ID and version.
+    pub fn get(&self, id: &str, version: &str) -> Option<&dyn RecordDecoder> {
+        self.decoders.get(&(id.to_owned(), version.to_owned()))
+            .map(|d| d.as_ref())
+    }
+
+    /// Decodes a record using the decoder registered for its ID and version.
+    ///
+    /// Returns `None` if no decoder is registered for this ID and version.
+    pub fn decode(&self, id: &str, version: &str, record_data: &[u8]) -> Option<Result<Box<dyn RecordValue>, RecordError>> {
+        self.get(id, version)
+            .map(|decoder| decoder.decode(record_data))
+    }
+}
+impl Default for RecordDecoderRegistry {
+    /// Creates a registry containing decoders for the standard records.
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry.register("U_FLEX", "01", crate::uflex_1_ext::decode_record);
+        registry.register("U_FLEX", "02", crate::uflex_2_ext::decode_record);
+        registry.register("U_FLEX", "03", crate::uflex_3_ext::decode_record);
+        registry.register("U_HEAD", "01", crate::uhead::decode_record);
+        registry.register("U_TLAY", "01", crate::utlay::decode_record);
+        registry
+    }
+}
+impl fmt::Debug for RecordDecoderRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.decoders.keys())
+            .finish()
+    }
+}
+
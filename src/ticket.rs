@@ -0,0 +1,249 @@
+//! Decoding of the UIC ticket container (`#UT`) and the records within it.
+
+
+use crate::container::{ContainerError, UicContainer};
+use crate::key_db::{self, Key, Signature};
+use crate::records::{RecordDecoderRegistry, RecordValue};
+
+
+/// The header of a UIC ticket container.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Header {
+    /// The version of the container format (1 or 2).
+    pub version: u8,
+
+    /// The number of the company that signed the ticket (four digits).
+    pub signer_number: String,
+
+    /// The ID of the key with which the ticket was signed (five digits).
+    pub key_id: String,
+
+    /// The signature over the compressed data.
+    pub signature: Signature,
+}
+
+
+/// A record contained within a UIC ticket.
+#[derive(Debug)]
+pub struct Record {
+    /// The ID of the record, e.g. `U_FLEX`.
+    pub id: String,
+
+    /// The version of the record, e.g. `03`.
+    pub version: String,
+
+    /// The length of the record, including its ID, version and length fields.
+    pub length: usize,
+
+    /// The data of the record, excluding its ID, version and length fields.
+    pub data: Vec<u8>,
+
+    /// The decoded value of the record.
+    ///
+    /// `None` if no decoder is registered for this record's ID and version.
+    pub value: Option<Box<dyn RecordValue>>,
+}
+
+
+/// A decoded UIC ticket.
+#[derive(Debug)]
+pub struct DecodedTicket {
+    /// The header of the ticket container.
+    pub header: Header,
+
+    /// The compressed data, as signed.
+    pub compressed_data: Vec<u8>,
+
+    /// The decompressed data, i.e. the concatenation of all records.
+    pub decompressed_data: Vec<u8>,
+
+    /// The records contained within the ticket.
+    pub records: Vec<Record>,
+}
+impl DecodedTicket {
+    /// Decodes the records of a UIC ticket container that has been parsed from a barcode.
+    fn from_parsed_container(container: &UicContainer, registry: &RecordDecoderRegistry) -> Result<Self, ContainerError> {
+        let compressed_data = container.compressed_bytes()
+            .expect("records of a parsed container are always encodable");
+        let decompressed_data = container.records_bytes()
+            .expect("records of a parsed container are always encodable");
+
+        let mut records = Vec::with_capacity(container.records.len());
+        let mut offset = 0;
+        for record in &container.records {
+            let value = registry.decode(&record.id, &record.version, &record.data)
+                .transpose()
+                .map_err(|e| ContainerError::RecordDecoding {
+                    offset,
+                    record_id: record.id.clone(),
+                    record_version: record.version.clone(),
+                    message: e.to_string(),
+                })?;
+            records.push(Record {
+                id: record.id.clone(),
+                version: record.version.clone(),
+                length: record.length(),
+                data: record.data.clone(),
+                value,
+            });
+            offset += record.length();
+        }
+
+        Ok(Self {
+            header: Header {
+                version: container.version,
+                signer_number: container.signer_number.clone(),
+                key_id: container.key_id.clone(),
+                signature: container.signature.clone(),
+            },
+            compressed_data,
+            decompressed_data,
+            records,
+        })
+    }
+
+    /// Verifies the signature of this ticket using the given key.
+    pub fn verify_signature(&self, key: &Key) -> Result<bool, key_db::Error> {
+        key.verify(&self.header.signature, &self.compressed_data)
+    }
+}
+
+
+/// Decodes the contents of a UIC ticket barcode using the decoders for the standard records.
+pub fn decode_barcode_bytes(barcode_contents: &[u8]) -> Result<DecodedTicket, ContainerError> {
+    decode_barcode_bytes_with_registry(barcode_contents, &RecordDecoderRegistry::default())
+}
+
+
+/// Decodes the contents of a UIC ticket barcode using the decoders in the given registry.
+pub fn decode_barcode_bytes_with_registry(barcode_contents: &[u8], registry: &RecordDecoderRegistry) -> Result<DecodedTicket, ContainerError> {
+    let container = UicContainer::from_bytes(barcode_contents)?;
+    DecodedTicket::from_parsed_container(&container, registry)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+
+    fn make_barcode(records: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::best());
+        encoder.write_all(records).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut barcode = Vec::new();
+        barcode.extend(b"#UT02");
+        barcode.extend(b"1181");
+        barcode.extend(b"00001");
+        barcode.extend([0u8; 64]);
+        barcode.extend(format!("{:04}", compressed.len()).as_bytes());
+        barcode.extend(&compressed);
+        barcode
+    }
+
+    #[test]
+    fn test_decode_unknown_record() {
+        let ticket = decode_barcode_bytes(&make_barcode(b"1181AB0000162345")).unwrap();
+        assert_eq!(ticket.header.version, 2);
+        assert_eq!(ticket.header.signer_number, "1181");
+        assert_eq!(ticket.header.key_id, "00001");
+        assert_eq!(ticket.records.len(), 1);
+        assert_eq!(ticket.records[0].id, "1181AB");
+        assert_eq!(ticket.records[0].version, "00");
+        assert_eq!(ticket.records[0].data, b"2345");
+        assert!(ticket.records[0].value.is_none());
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Proprietary(Vec<u8>);
+    impl RecordValue for Proprietary {
+        fn to_json(&self) -> Result<serde_json::Value, crate::records::RecordError> {
+            Ok(serde_json::to_value(&self.0)?)
+        }
+
+        fn to_record_data(&self) -> Result<Vec<u8>, crate::records::RecordError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn test_decode_registered_record() {
+        let mut registry = RecordDecoderRegistry::default();
+        registry.register("1181AB", "00", |data: &[u8]| -> Result<Box<dyn RecordValue>, crate::records::RecordError> {
+            if data.starts_with(b"0") {
+                return Err("leading zero".into());
+            }
+            Ok(Box::new(Proprietary(data.to_vec())))
+        });
+
+        let ticket = decode_barcode_bytes_with_registry(&make_barcode(b"1181AB0000162345"), &registry).unwrap();
+        let value = ticket.records[0].value.as_ref().unwrap();
+        assert_eq!(value.downcast_ref::<Proprietary>(), Some(&Proprietary(b"2345".to_vec())));
+        assert_eq!(value.to_json().unwrap(), serde_json::json!([50, 51, 52, 53]));
+        assert_eq!(value.to_record_data().unwrap(), b"2345");
+
+        assert!(matches!(
+            decode_barcode_bytes_with_registry(&make_barcode(b"1181AB0000160345"), &registry),
+            Err(ContainerError::RecordDecoding { offset: 0, .. }),
+        ));
+    }
+
+    #[test]
+    fn test_container_errors() {
+        let barcode = make_barcode(b"1181AB0000162345");
+
+        assert!(matches!(
+            decode_barcode_bytes(b"#XY02"),
+            Err(ContainerError::NotUicTicket { offset: 0 }),
+        ));
+        assert!(matches!(
+            decode_barcode_bytes(b"#UT0"),
+            Err(ContainerError::TruncatedHeader { offset: 3, expected: 2, available: 1, .. }),
+        ));
+        assert!(matches!(
+            decode_barcode_bytes(b"#UT03"),
+            Err(ContainerError::UnknownVersion { offset: 3, .. }),
+        ));
+        assert!(matches!(
+            decode_barcode_bytes(&barcode[..50]),
+            Err(ContainerError::TruncatedHeader { offset: 14, expected: 64, available: 36, .. }),
+        ));
+
+        let mut bad_signer = barcode.clone();
+        bad_signer[6] = 0xC3;
+        assert!(matches!(
+            decode_barcode_bytes(&bad_signer),
+            Err(ContainerError::NonAsciiField { offset: 5, .. }),
+        ));
+
+        let mut bad_length = barcode.clone();
+        bad_length[79] = b'x';
+        assert!(matches!(
+            decode_barcode_bytes(&bad_length),
+            Err(ContainerError::NonNumericLength { offset: 78, .. }),
+        ));
+
+        assert!(matches!(
+            decode_barcode_bytes(&barcode[..barcode.len()-1]),
+            Err(ContainerError::CompressedLengthExceedsBuffer { offset: 82, .. }),
+        ));
+
+        let mut bad_zlib = barcode.clone();
+        bad_zlib[82] = 0x00;
+        assert!(matches!(
+            decode_barcode_bytes(&bad_zlib),
+            Err(ContainerError::Decompression { offset: 82, .. }),
+        ));
+
+        assert!(matches!(
+            decode_barcode_bytes(&make_barcode(b"1181AB0000172345")),
+            Err(ContainerError::RecordLengthOverrun { offset: 0, record_length: 17, available: 16 }),
+        ));
+        assert!(matches!(
+            decode_barcode_bytes(&make_barcode(b"1181AB0000162345U_")),
+            Err(ContainerError::TruncatedRecordHeader { offset: 16, available: 2 }),
+        ));
+    }
+}
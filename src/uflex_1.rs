@@ -0,0 +1,843 @@
+// This file has been generated by asn1_uper_model.py.
+// Manual changes are likely to disappear without a trace.
+
+
+use serde::{Deserialize, Serialize};
+
+use crate::asn1_uper::{UperDecode, UperEncode, UperVisit};
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct UicRailTicketData {
+    pub issuing_detail: IssuingData,
+    pub traveler_detail: Option<TravelerData>,
+    #[uper(optional)] #[serde(default)] pub transport_document: Vec<DocumentData>,
+    pub control_detail: Option<ControlData>,
+    #[uper(optional)] #[serde(default)] pub extension: Vec<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct DocumentData {
+    pub token: Option<TokenType>,
+    pub ticket: DocumentDataTicket,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub enum DocumentDataTicket {
+    Reservation(ReservationData),
+    CarCarriageReservation(CarCarriageReservationData),
+    OpenTicket(OpenTicketData),
+    Pass(PassData),
+    Voucher(VoucherData),
+    CustomerCard(CustomerCardData),
+    CounterMark(CountermarkData),
+    ParkingGround(ParkingGroundData),
+    FipTicket(FipTicketData),
+    StationPassage(StationPassageData),
+    Extension(ExtensionData),
+    /// An alternative added by an extension that is unknown to this version of the schema, along
+    /// with its extension index and its encoded value.
+    #[uper(unknown)]
+    Unknown { index: usize, contents: Vec<u8> },
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct IssuingData {
+    #[uper(min = 1, max = 32000)] pub security_provider_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "securityProviderIA5", exclusive_with = security_provider_num, ia5)] pub security_provider_ia_5: Option<String>,
+    #[uper(min = 1, max = 32000)] pub issuer_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "issuerIA5", exclusive_with = issuer_num, ia5)] pub issuer_ia_5: Option<String>,
+    #[uper(min = 2016, max = 2269)] pub issuing_year: crate::asn1_uper::Integer,
+    #[uper(min = 1, max = 366)] pub issuing_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub issuing_time: crate::asn1_uper::Integer,
+    #[uper(utf8)] pub issuer_name: Option<String>,
+    pub specimen: bool,
+    pub secure_paper_ticket: bool,
+    pub activated: bool,
+    #[uper(default = "EUR", ia5, size(min = 3, max = 3))] pub currency: String,
+    #[uper(default = 2, min = 1, max = 3)] pub currency_fract: crate::asn1_uper::Integer,
+    #[uper(ia5)] pub issuer_pnr: Option<String>,
+    pub extension: Option<ExtensionData>,
+    pub issued_on_train_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "issuedOnTrainIA5", exclusive_with = issued_on_train_num, ia5)] pub issued_on_train_ia_5: Option<String>,
+    pub issued_on_line: Option<crate::asn1_uper::Integer>,
+    pub point_of_sale: Option<GeoCoordinateType>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct ControlData {
+    #[uper(optional)] #[serde(default)] pub identification_by_card_reference: Vec<CardReferenceType>,
+    pub identification_by_id_card: bool,
+    pub identification_by_passport_id: bool,
+    pub identification_item: Option<crate::asn1_uper::Integer>,
+    pub passport_validation_required: bool,
+    pub online_validation_required: bool,
+    #[uper(min = 0, max = 99)] pub random_detailed_validation_required: Option<crate::asn1_uper::Integer>,
+    pub age_check_required: bool,
+    pub reduction_card_check_required: bool,
+    #[uper(utf8)] pub info_text: Option<String>,
+    #[uper(optional)] #[serde(default)] pub included_tickets: Vec<TicketLinkType>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct TravelerData {
+    #[uper(optional)] #[serde(default)] pub traveler: Vec<TravelerType>,
+    #[uper(ia5, size(min = 2, max = 2))] pub preferred_language: Option<String>,
+    #[uper(utf8)] pub group_name: Option<String>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct ReservationData {
+    pub train_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "trainIA5", exclusive_with = train_num, ia5)] pub train_ia_5: Option<String>,
+    #[uper(default = 0, min = -1, max = 500)] pub departure_date: crate::asn1_uper::Integer,
+    #[uper(name = "referenceIA5", exclusive_with = reference_num, ia5)] pub reference_ia_5: Option<String>,
+    pub reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(min = 0, max = 65535)] pub product_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productIdIA5", exclusive_with = product_id_num, ia5)] pub product_id_ia_5: Option<String>,
+    #[uper(min = 0, max = 32000)] pub service_brand: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "serviceBrandAbrUTF8", utf8)] pub service_brand_abr_utf_8: Option<String>,
+    #[uper(name = "serviceBrandNameUTF8", utf8)] pub service_brand_name_utf_8: Option<String>,
+    #[uper(default = ServiceType::Seat)] pub service: ServiceType,
+    #[uper(default = CodeTableType::StationUicReservation)] pub station_code_table: CodeTableType,
+    #[uper(min = 1, max = 9999999)] pub from_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "fromStationIA5", exclusive_with = from_station_num, ia5)] pub from_station_ia_5: Option<String>,
+    #[uper(min = 1, max = 9999999)] pub to_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "toStationIA5", exclusive_with = to_station_num, ia5)] pub to_station_ia_5: Option<String>,
+    #[uper(name = "fromStationNameUTF8", utf8)] pub from_station_name_utf_8: Option<String>,
+    #[uper(name = "toStationNameUTF8", utf8)] pub to_station_name_utf_8: Option<String>,
+    #[uper(min = 0, max = 1439)] pub departure_time: crate::asn1_uper::Integer,
+    #[uper(min = -60, max = 60)] pub departure_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(default = 0, min = -1, max = 20)] pub arrival_date: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub arrival_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub arrival_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub carrier_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "carrierIA5", optional, exclusive_with = carrier_num, element(ia5))] #[serde(default)] pub carrier_ia_5: Vec<String>,
+    #[uper(default = TravelClassType::Second)] pub class_code: TravelClassType,
+    #[uper(ia5, size(min = 1, max = 2))] pub service_level: Option<String>,
+    pub places: Option<PlacesType>,
+    pub additional_places: Option<PlacesType>,
+    pub bicycle_places: Option<PlacesType>,
+    pub compartment_details: Option<CompartmentDetailsType>,
+    #[uper(default = 0, min = 0, max = 200)] pub number_of_overbooked: crate::asn1_uper::Integer,
+    #[uper(optional)] #[serde(default)] pub berth: Vec<BerthDetailData>,
+    #[uper(optional)] #[serde(default)] pub tariff: Vec<TariffType>,
+    #[uper(default = PriceTypeType::TravelPrice)] pub price_type: PriceTypeType,
+    pub price: Option<crate::asn1_uper::Integer>,
+    #[uper(optional)] #[serde(default)] pub vat_detail: Vec<VatDetailType>,
+    #[uper(default = 0, min = 0, max = 9)] pub type_of_supplement: crate::asn1_uper::Integer,
+    #[uper(default = 0, min = 0, max = 200)] pub number_of_supplements: crate::asn1_uper::Integer,
+    pub luggage: Option<LuggageRestrictionType>,
+    #[uper(utf8)] pub info_text: Option<String>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct VatDetailType {
+    #[uper(min = 1, max = 999)] pub country: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 999)] pub percentage: crate::asn1_uper::Integer,
+    pub amount: Option<crate::asn1_uper::Integer>,
+    #[uper(ia5)] pub vat_id: Option<String>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct CarCarriageReservationData {
+    pub train_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "trainIA5", exclusive_with = train_num, ia5)] pub train_ia_5: Option<String>,
+    #[uper(default = 0, min = -1, max = 500)] pub begin_loading_date: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub begin_loading_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 0, max = 1439)] pub end_loading_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub loading_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "referenceIA5", exclusive_with = reference_num, ia5)] pub reference_ia_5: Option<String>,
+    pub reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(min = 0, max = 65535)] pub product_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productIdIA5", exclusive_with = product_id_num, ia5)] pub product_id_ia_5: Option<String>,
+    #[uper(min = 1, max = 32000)] pub service_brand: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "serviceBrandAbrUTF8", utf8)] pub service_brand_abr_utf_8: Option<String>,
+    #[uper(name = "serviceBrandNameUTF8", utf8)] pub service_brand_name_utf_8: Option<String>,
+    #[uper(default = CodeTableType::StationUicReservation)] pub station_code_table: CodeTableType,
+    #[uper(min = 1, max = 9999999)] pub from_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "fromStationIA5", exclusive_with = from_station_num, ia5)] pub from_station_ia_5: Option<String>,
+    #[uper(min = 1, max = 9999999)] pub to_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "toStationIA5", exclusive_with = to_station_num, ia5)] pub to_station_ia_5: Option<String>,
+    #[uper(name = "fromStationNameUTF8", utf8)] pub from_station_name_utf_8: Option<String>,
+    #[uper(name = "toStationNameUTF8", utf8)] pub to_station_name_utf_8: Option<String>,
+    #[uper(ia5)] pub coach: Option<String>,
+    #[uper(ia5)] pub place: Option<String>,
+    pub compartment_details: Option<CompartmentDetailsType>,
+    #[uper(ia5)] pub number_plate: String,
+    #[uper(ia5)] pub trailer_plate: Option<String>,
+    #[uper(min = 0, max = 9)] pub car_category: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 6)] pub boat_category: Option<crate::asn1_uper::Integer>,
+    pub textile_roof: bool,
+    #[uper(default = RoofRackType::Norack)] pub roof_rack_type: RoofRackType,
+    #[uper(min = 0, max = 99)] pub roof_rack_height: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 0, max = 2)] pub attached_boats: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 0, max = 4)] pub attached_bicycles: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 0, max = 5)] pub attached_surfboards: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 0, max = 999)] pub loading_list_entry: Option<crate::asn1_uper::Integer>,
+    #[uper(default = LoadingDeckType::Upper)] pub loading_deck: LoadingDeckType,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub carrier_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "carrierIA5", optional, exclusive_with = carrier_num, element(ia5))] #[serde(default)] pub carrier_ia_5: Vec<String>,
+    pub tariff: TariffType,
+    #[uper(default = PriceTypeType::TravelPrice)] pub price_type: PriceTypeType,
+    pub price: Option<crate::asn1_uper::Integer>,
+    #[uper(optional)] #[serde(default)] pub vat_detail: Vec<VatDetailType>,
+    #[uper(utf8)] pub info_text: Option<String>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct OpenTicketData {
+    pub reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "referenceIA5", exclusive_with = reference_num, ia5)] pub reference_ia_5: Option<String>,
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(min = 0, max = 65535)] pub product_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productIdIA5", exclusive_with = product_id_num, ia5)] pub product_id_ia_5: Option<String>,
+    pub ext_issuer_id: Option<crate::asn1_uper::Integer>,
+    pub issuer_autorization_id: Option<crate::asn1_uper::Integer>,
+    pub return_included: bool,
+    #[uper(default = CodeTableType::StationUic)] pub station_code_table: CodeTableType,
+    #[uper(min = 1, max = 9999999)] pub from_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "fromStationIA5", exclusive_with = from_station_num, ia5)] pub from_station_ia_5: Option<String>,
+    #[uper(min = 1, max = 9999999)] pub to_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "toStationIA5", exclusive_with = to_station_num, ia5)] pub to_station_ia_5: Option<String>,
+    #[uper(name = "fromStationNameUTF8", utf8)] pub from_station_name_utf_8: Option<String>,
+    #[uper(name = "toStationNameUTF8", utf8)] pub to_station_name_utf_8: Option<String>,
+    #[uper(utf8)] pub valid_region_desc: Option<String>,
+    #[uper(optional)] #[serde(default)] pub valid_region: Vec<RegionalValidityType>,
+    pub return_description: Option<ReturnRouteDescriptionType>,
+    #[uper(default = 0, min = -367, max = 700)] pub valid_from_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub valid_from_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub valid_from_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(default = 0, min = -1, max = 500)] pub valid_until_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub valid_until_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub valid_until_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 0, max = 500))] #[serde(default)] pub activated_day: Vec<crate::asn1_uper::Integer>,
+    #[uper(default = TravelClassType::Second)] pub class_code: TravelClassType,
+    #[uper(ia5, size(min = 1, max = 2))] pub service_level: Option<String>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub carrier_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "carrierIA5", optional, exclusive_with = carrier_num, element(ia5))] #[serde(default)] pub carrier_ia_5: Vec<String>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub included_service_brands: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub excluded_service_brands: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional)] #[serde(default)] pub tariffs: Vec<TariffType>,
+    pub price: Option<crate::asn1_uper::Integer>,
+    #[uper(optional)] #[serde(default)] pub vat_detail: Vec<VatDetailType>,
+    #[uper(utf8)] pub info_text: Option<String>,
+    #[uper(optional)] #[serde(default)] pub included_add_ons: Vec<IncludedOpenTicketType>,
+    pub luggage: Option<LuggageRestrictionType>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct PassData {
+    pub reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "referenceIA5", exclusive_with = reference_num, ia5)] pub reference_ia_5: Option<String>,
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(min = 0, max = 65535)] pub product_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productIdIA5", exclusive_with = product_id_num, ia5)] pub product_id_ia_5: Option<String>,
+    #[uper(min = 1, max = 250)] pub pass_type: Option<crate::asn1_uper::Integer>,
+    #[uper(utf8)] pub pass_description: Option<String>,
+    #[uper(default = TravelClassType::Second)] pub class_code: TravelClassType,
+    #[uper(default = 0, min = -367, max = 700)] pub valid_from_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub valid_from_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub valid_from_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(default = 0, min = -1, max = 500)] pub valid_until_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub valid_until_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub valid_until_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 0, max = 500)] pub number_of_validity_days: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 250)] pub number_of_possible_trips: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 250)] pub number_of_days_of_travel: Option<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 0, max = 500))] #[serde(default)] pub activated_day: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 250))] #[serde(default)] pub countries: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub included_carrier_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "includedCarrierIA5", optional, exclusive_with = included_carrier_num, element(ia5))] #[serde(default)] pub included_carrier_ia_5: Vec<String>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub excluded_carrier_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "excludedCarrierIA5", optional, exclusive_with = excluded_carrier_num, element(ia5))] #[serde(default)] pub excluded_carrier_ia_5: Vec<String>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub included_service_brands: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub excluded_service_brands: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional)] #[serde(default)] pub valid_region: Vec<RegionalValidityType>,
+    #[uper(optional)] #[serde(default)] pub tariffs: Vec<TariffType>,
+    pub price: Option<crate::asn1_uper::Integer>,
+    #[uper(optional)] #[serde(default)] pub vat_detail: Vec<VatDetailType>,
+    #[uper(utf8)] pub info_text: Option<String>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct VoucherData {
+    #[uper(name = "referenceIA5", exclusive_with = reference_num, ia5)] pub reference_ia_5: Option<String>,
+    pub reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(min = 0, max = 65535)] pub product_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productIdIA5", exclusive_with = product_id_num, ia5)] pub product_id_ia_5: Option<String>,
+    #[uper(min = 2016, max = 2269)] pub valid_from_year: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 500)] pub valid_from_day: crate::asn1_uper::Integer,
+    #[uper(min = 2016, max = 2269)] pub valid_until_year: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 500)] pub valid_until_day: crate::asn1_uper::Integer,
+    #[uper(default = 0)] pub value: crate::asn1_uper::Integer,
+    #[uper(min = 1, max = 32000)] pub type_: Option<crate::asn1_uper::Integer>,
+    #[uper(utf8)] pub info_text: Option<String>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct FipTicketData {
+    #[uper(name = "referenceIA5", exclusive_with = reference_num, ia5)] pub reference_ia_5: Option<String>,
+    pub reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(min = 0, max = 65535)] pub product_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productIdIA5", exclusive_with = product_id_num, ia5)] pub product_id_ia_5: Option<String>,
+    #[uper(default = 0, min = -367, max = 700)] pub valid_from_day: crate::asn1_uper::Integer,
+    #[uper(default = 0, min = -1, max = 500)] pub valid_until_day: crate::asn1_uper::Integer,
+    #[uper(optional, element(min = 0, max = 500))] #[serde(default)] pub activated_day: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub carrier_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "carrierIA5", optional, exclusive_with = carrier_num, element(ia5))] #[serde(default)] pub carrier_ia_5: Vec<String>,
+    #[uper(min = 1, max = 200)] pub number_of_travel_days: crate::asn1_uper::Integer,
+    pub includes_supplements: bool,
+    #[uper(default = TravelClassType::Second)] pub class_code: TravelClassType,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct StationPassageData {
+    #[uper(name = "referenceIA5", exclusive_with = reference_num, ia5)] pub reference_ia_5: Option<String>,
+    pub reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(min = 0, max = 65535)] pub product_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productIdIA5", exclusive_with = product_id_num, ia5)] pub product_id_ia_5: Option<String>,
+    #[uper(utf8)] pub product_name: Option<String>,
+    #[uper(default = CodeTableType::StationUic)] pub station_code_table: CodeTableType,
+    #[uper(optional)] #[serde(default)] pub station_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "stationIA5", optional, exclusive_with = station_num, element(ia5))] #[serde(default)] pub station_ia_5: Vec<String>,
+    #[uper(name = "stationNameUTF8", optional, element(utf8))] #[serde(default)] pub station_name_utf_8: Vec<String>,
+    #[uper(optional)] #[serde(default)] pub area_code_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "areaCodeIA5", optional, exclusive_with = area_code_num, element(ia5))] #[serde(default)] pub area_code_ia_5: Vec<String>,
+    #[uper(name = "areaNameUTF8", optional, element(utf8))] #[serde(default)] pub area_name_utf_8: Vec<String>,
+    #[uper(min = -367, max = 700)] pub valid_from_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub valid_from_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub valid_from_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(default = 0, min = -1, max = 500)] pub valid_until_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub valid_until_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub valid_until_utc_offset: Option<crate::asn1_uper::Integer>,
+    pub number_of_days_valid: Option<crate::asn1_uper::Integer>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct CustomerCardData {
+    pub customer: Option<TravelerType>,
+    #[uper(name = "cardIdIA5", exclusive_with = card_id_num, ia5)] pub card_id_ia_5: Option<String>,
+    pub card_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 2016, max = 2269)] pub valid_from_year: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 500)] pub valid_from_day: Option<crate::asn1_uper::Integer>,
+    #[uper(default = 0, min = 0, max = 250)] pub valid_until_year: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 500)] pub valid_until_day: Option<crate::asn1_uper::Integer>,
+    pub class_code: Option<TravelClassType>,
+    #[uper(min = 1, max = 1000)] pub card_type: Option<crate::asn1_uper::Integer>,
+    #[uper(utf8)] pub card_type_descr: Option<String>,
+    pub customer_status: Option<crate::asn1_uper::Integer>,
+    #[uper(ia5)] pub customer_status_descr: Option<String>,
+    #[uper(optional)] #[serde(default)] pub included_services: Vec<crate::asn1_uper::Integer>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct ParkingGroundData {
+    #[uper(name = "referenceIA5", exclusive_with = reference_num, ia5)] pub reference_ia_5: Option<String>,
+    pub reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(ia5)] pub parking_ground_id: String,
+    #[uper(min = -367, max = 370)] pub from_parking_date: crate::asn1_uper::Integer,
+    #[uper(default = 0, min = 0, max = 500)] pub until_parking_date: crate::asn1_uper::Integer,
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(min = 0, max = 65535)] pub product_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productIdIA5", exclusive_with = product_id_num, ia5)] pub product_id_ia_5: Option<String>,
+    #[uper(ia5)] pub access_code: Option<String>,
+    #[uper(utf8)] pub location: String,
+    #[uper(default = CodeTableType::StationUic)] pub station_code_table: CodeTableType,
+    pub station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "stationIA5", exclusive_with = station_num, utf8)] pub station_ia_5: Option<String>,
+    #[uper(utf8)] pub special_information: Option<String>,
+    #[uper(utf8)] pub entry_track: Option<String>,
+    #[uper(ia5)] pub number_plate: Option<String>,
+    pub price: Option<crate::asn1_uper::Integer>,
+    #[uper(optional)] #[serde(default)] pub vat_detail: Vec<VatDetailType>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct CountermarkData {
+    #[uper(name = "referenceIA5", exclusive_with = reference_num, ia5)] pub reference_ia_5: Option<String>,
+    pub reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(min = 0, max = 65535)] pub product_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productIdIA5", exclusive_with = product_id_num, ia5)] pub product_id_ia_5: Option<String>,
+    #[uper(name = "ticketReferenceIA5", exclusive_with = ticket_reference_num, ia5)] pub ticket_reference_ia_5: Option<String>,
+    pub ticket_reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 200)] pub number_of_countermark: crate::asn1_uper::Integer,
+    #[uper(min = 1, max = 200)] pub total_of_countermarks: crate::asn1_uper::Integer,
+    #[uper(utf8)] pub group_name: String,
+    #[uper(default = CodeTableType::StationUic)] pub station_code_table: CodeTableType,
+    #[uper(min = 1, max = 9999999)] pub from_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "fromStationIA5", exclusive_with = from_station_num, ia5)] pub from_station_ia_5: Option<String>,
+    #[uper(min = 1, max = 9999999)] pub to_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "toStationIA5", exclusive_with = to_station_num, ia5)] pub to_station_ia_5: Option<String>,
+    #[uper(name = "fromStationNameUTF8", utf8)] pub from_station_name_utf_8: Option<String>,
+    #[uper(name = "toStationNameUTF8", utf8)] pub to_station_name_utf_8: Option<String>,
+    #[uper(utf8)] pub valid_region_desc: Option<String>,
+    #[uper(optional)] #[serde(default)] pub valid_region: Vec<RegionalValidityType>,
+    pub return_included: bool,
+    pub return_description: Option<ReturnRouteDescriptionType>,
+    #[uper(default = 0, min = -367, max = 700)] pub valid_from_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub valid_from_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub valid_from_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(default = 0, min = -1, max = 500)] pub valid_until_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub valid_until_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub valid_until_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(default = TravelClassType::Second)] pub class_code: TravelClassType,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub carrier_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "carrierIA5", optional, exclusive_with = carrier_num, element(ia5))] #[serde(default)] pub carrier_ia_5: Vec<String>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub included_service_brands: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub excluded_service_brands: Vec<crate::asn1_uper::Integer>,
+    #[uper(utf8)] pub info_text: Option<String>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct ExtensionData {
+    #[uper(ia5)] pub extension_id: String,
+    pub extension_data: Vec<u8>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct IncludedOpenTicketType {
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(min = 0, max = 65535)] pub product_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productIdIA5", exclusive_with = product_id_num, ia5)] pub product_id_ia_5: Option<String>,
+    pub external_issuer_id: Option<crate::asn1_uper::Integer>,
+    pub issuer_autorization_id: Option<crate::asn1_uper::Integer>,
+    #[uper(default = CodeTableType::StationUic)] pub station_code_table: CodeTableType,
+    #[uper(optional)] #[serde(default)] pub valid_region: Vec<RegionalValidityType>,
+    #[uper(default = 0, min = -367, max = 700)] pub valid_from_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub valid_from_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub valid_from_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(default = 0, min = -1, max = 500)] pub valid_until_day: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub valid_until_time: Option<crate::asn1_uper::Integer>,
+    #[uper(min = -60, max = 60)] pub valid_until_utc_offset: Option<crate::asn1_uper::Integer>,
+    pub class_code: Option<TravelClassType>,
+    #[uper(ia5, size(min = 1, max = 2))] pub service_level: Option<String>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub carrier_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "carrierIA5", optional, exclusive_with = carrier_num, element(ia5))] #[serde(default)] pub carrier_ia_5: Vec<String>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub included_service_brands: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub excluded_service_brands: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional)] #[serde(default)] pub tariffs: Vec<TariffType>,
+    #[uper(utf8)] pub info_text: Option<String>,
+    pub extension: Option<ExtensionData>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct TariffType {
+    #[uper(default = 1, min = 1, max = 200)] pub number_of_passengers: crate::asn1_uper::Integer,
+    pub passenger_type: Option<PassengerType>,
+    #[uper(min = 1, max = 64)] pub age_below: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 128)] pub age_above: Option<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 254))] #[serde(default)] pub travelerid: Vec<crate::asn1_uper::Integer>,
+    pub restricted_to_country_of_residence: bool,
+    pub restricted_to_route_section: Option<RouteSectionType>,
+    pub series_data_details: Option<SeriesDetailType>,
+    pub tariff_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "tariffIdIA5", exclusive_with = tariff_id_num, ia5)] pub tariff_id_ia_5: Option<String>,
+    #[uper(utf8)] pub tariff_desc: Option<String>,
+    #[uper(optional)] #[serde(default)] pub reduction_card: Vec<CardReferenceType>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct SeriesDetailType {
+    #[uper(min = 1, max = 32000)] pub supplying_carrier: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 99)] pub offer_identification: Option<crate::asn1_uper::Integer>,
+    pub series: Option<crate::asn1_uper::Integer>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct RouteSectionType {
+    #[uper(default = CodeTableType::StationUic)] pub station_code_table: CodeTableType,
+    #[uper(min = 1, max = 9999999)] pub from_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "fromStationIA5", exclusive_with = from_station_num, ia5)] pub from_station_ia_5: Option<String>,
+    #[uper(min = 1, max = 9999999)] pub to_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "toStationIA5", exclusive_with = to_station_num, ia5)] pub to_station_ia_5: Option<String>,
+    #[uper(name = "fromStationNameUTF8", utf8)] pub from_station_name_utf_8: Option<String>,
+    #[uper(name = "toStationNameUTF8", utf8)] pub to_station_name_utf_8: Option<String>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct CardReferenceType {
+    #[uper(min = 1, max = 32000)] pub card_issuer_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "cardIssuerIA5", exclusive_with = card_issuer_num, ia5)] pub card_issuer_ia_5: Option<String>,
+    pub card_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "cardIdIA5", exclusive_with = card_id_num, ia5)] pub card_id_ia_5: Option<String>,
+    #[uper(utf8)] pub card_name: Option<String>,
+    pub card_type: Option<crate::asn1_uper::Integer>,
+    pub leading_card_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "leadingCardIdIA5", exclusive_with = leading_card_id_num, ia5)] pub leading_card_id_ia_5: Option<String>,
+    pub trailing_card_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "trailingCardIdIA5", exclusive_with = trailing_card_id_num, ia5)] pub trailing_card_id_ia_5: Option<String>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct TravelerType {
+    #[uper(utf8)] pub first_name: Option<String>,
+    #[uper(utf8)] pub second_name: Option<String>,
+    #[uper(utf8)] pub last_name: Option<String>,
+    #[uper(ia5)] pub id_card: Option<String>,
+    #[uper(ia5)] pub passport_id: Option<String>,
+    #[uper(ia5, size(min = 1, max = 3))] pub title: Option<String>,
+    pub gender: Option<GenderType>,
+    #[uper(name = "customerIdIA5", exclusive_with = customer_id_num, ia5)] pub customer_id_ia_5: Option<String>,
+    pub customer_id_num: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1901, max = 2155)] pub year_of_birth: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 0, max = 370)] pub day_of_birth: Option<crate::asn1_uper::Integer>,
+    pub ticket_holder: bool,
+    pub passenger_type: Option<PassengerType>,
+    pub passenger_with_reduced_mobility: Option<bool>,
+    #[uper(min = 1, max = 999)] pub country_of_residence: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 999)] pub country_of_passport: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 999)] pub country_of_id_card: Option<crate::asn1_uper::Integer>,
+    #[uper(optional)] #[serde(default)] pub status: Vec<CustomerStatusType>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct CustomerStatusType {
+    #[uper(min = 1, max = 32000)] pub status_provider_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "statusProviderIA5", exclusive_with = status_provider_num, ia5)] pub status_provider_ia_5: Option<String>,
+    pub customer_status: Option<crate::asn1_uper::Integer>,
+    #[uper(ia5)] pub customer_status_descr: Option<String>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct ReturnRouteDescriptionType {
+    #[uper(min = 1, max = 9999999)] pub from_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "fromStationIA5", exclusive_with = from_station_num, ia5)] pub from_station_ia_5: Option<String>,
+    #[uper(min = 1, max = 9999999)] pub to_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "toStationIA5", exclusive_with = to_station_num, ia5)] pub to_station_ia_5: Option<String>,
+    #[uper(name = "fromStationNameUTF8", utf8)] pub from_station_name_utf_8: Option<String>,
+    #[uper(name = "toStationNameUTF8", utf8)] pub to_station_name_utf_8: Option<String>,
+    #[uper(utf8)] pub valid_return_region_desc: Option<String>,
+    #[uper(optional)] #[serde(default)] pub valid_return_region: Vec<RegionalValidityType>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub enum RegionalValidityType {
+    TrainLink(TrainLinkType),
+    ViaStations(ViaStationType),
+    Zones(ZoneType),
+    Lines(LineType),
+    Polygone(PolygoneType),
+    /// An alternative added by an extension that is unknown to this version of the schema, along
+    /// with its extension index and its encoded value.
+    #[uper(unknown)]
+    Unknown { index: usize, contents: Vec<u8> },
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct TrainLinkType {
+    pub train_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "trainIA5", exclusive_with = train_num, ia5)] pub train_ia_5: Option<String>,
+    #[uper(min = -1, max = 500)] pub travel_date: crate::asn1_uper::Integer,
+    #[uper(min = 0, max = 1439)] pub departure_time: crate::asn1_uper::Integer,
+    #[uper(min = -60, max = 60)] pub departure_utc_offset: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 9999999)] pub from_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "fromStationIA5", exclusive_with = from_station_num, ia5)] pub from_station_ia_5: Option<String>,
+    #[uper(min = 1, max = 9999999)] pub to_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "toStationIA5", exclusive_with = to_station_num, ia5)] pub to_station_ia_5: Option<String>,
+    #[uper(name = "fromStationNameUTF8", utf8)] pub from_station_name_utf_8: Option<String>,
+    #[uper(name = "toStationNameUTF8", utf8)] pub to_station_name_utf_8: Option<String>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct LineType {
+    #[uper(min = 1, max = 32000)] pub carrier_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "carrierIA5", exclusive_with = carrier_num, ia5)] pub carrier_ia_5: Option<String>,
+    #[uper(optional)] #[serde(default)] pub line_id: Vec<crate::asn1_uper::Integer>,
+    #[uper(default = CodeTableType::StationUic)] pub station_code_table: CodeTableType,
+    #[uper(min = 1, max = 9999999)] pub entry_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "entryStationIA5", exclusive_with = entry_station_num, ia5)] pub entry_station_ia_5: Option<String>,
+    #[uper(min = 1, max = 9999999)] pub terminating_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "terminatingStationIA5", exclusive_with = terminating_station_num, ia5)] pub terminating_station_ia_5: Option<String>,
+    pub city: Option<crate::asn1_uper::Integer>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct ZoneType {
+    #[uper(min = 1, max = 32000)] pub carrier_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "carrierIA5", exclusive_with = carrier_num, ia5)] pub carrier_ia_5: Option<String>,
+    #[uper(default = CodeTableType::StationUic)] pub station_code_table: CodeTableType,
+    #[uper(min = 1, max = 9999999)] pub entry_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "entryStationIA5", exclusive_with = entry_station_num, ia5)] pub entry_station_ia_5: Option<String>,
+    #[uper(min = 1, max = 9999999)] pub terminating_station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "terminatingStationIA5", exclusive_with = terminating_station_num, ia5)] pub terminating_station_ia_5: Option<String>,
+    pub city: Option<crate::asn1_uper::Integer>,
+    #[uper(optional)] #[serde(default)] pub zone_id: Vec<crate::asn1_uper::Integer>,
+    pub binary_zone_id: Option<Vec<u8>>,
+    #[uper(ia5)] pub nuts_code: Option<String>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct ViaStationType {
+    #[uper(default = CodeTableType::StationUic)] pub station_code_table: CodeTableType,
+    #[uper(min = 1, max = 9999999)] pub station_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "stationIA5", exclusive_with = station_num, ia5)] pub station_ia_5: Option<String>,
+    #[uper(optional)] #[serde(default)] pub alternative_routes: Vec<ViaStationType>,
+    #[uper(optional)] #[serde(default)] pub route: Vec<ViaStationType>,
+    pub border: bool,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub carrier_num: Vec<crate::asn1_uper::Integer>,
+    #[uper(name = "carrierIA5", optional, exclusive_with = carrier_num, element(ia5))] #[serde(default)] pub carrier_ia_5: Vec<String>,
+    pub series_id: Option<crate::asn1_uper::Integer>,
+    pub route_id: Option<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub included_service_brands: Vec<crate::asn1_uper::Integer>,
+    #[uper(optional, element(min = 1, max = 32000))] #[serde(default)] pub excluded_service_brands: Vec<crate::asn1_uper::Integer>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct PolygoneType {
+    pub first_edge: GeoCoordinateType,
+    #[serde(default)] pub edges: Vec<DeltaCoordinates>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct TokenType {
+    pub token_provider_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "tokenProviderIA5", exclusive_with = token_provider_num, ia5)] pub token_provider_ia_5: Option<String>,
+    #[uper(ia5)] pub token_specification: Option<String>,
+    pub token: Vec<u8>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct TicketLinkType {
+    #[uper(name = "referenceIA5", exclusive_with = reference_num, ia5)] pub reference_ia_5: Option<String>,
+    pub reference_num: Option<crate::asn1_uper::Integer>,
+    #[uper(utf8)] pub issuer_name: Option<String>,
+    #[uper(ia5)] pub issuer_pnr: Option<String>,
+    #[uper(min = 1, max = 32000)] pub product_owner_num: Option<crate::asn1_uper::Integer>,
+    #[uper(name = "productOwnerIA5", exclusive_with = product_owner_num, ia5)] pub product_owner_ia_5: Option<String>,
+    #[uper(default = TicketType::OpenTicket)] pub ticket_type: TicketType,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum CodeTableType {
+    StationUic = 0,
+    StationUicReservation = 1,
+    StationEra = 2,
+    LocalCarrierStationCodeTable = 3,
+    ProprietaryIssuerStationCodeTable = 4,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum ServiceType {
+    Seat = 0,
+    Couchette = 1,
+    Berth = 2,
+    Carcarriage = 3,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum PassengerType {
+    Adult = 0,
+    Senior = 1,
+    Child = 2,
+    Youth = 3,
+    Dog = 4,
+    Bicycle = 5,
+    FreeAddonPassenger = 6,
+    FreeAddonChild = 7,
+    /// A value added by an extension that is unknown to this version of the schema, along with
+    /// its extension index.
+    #[uper(unknown)]
+    Unknown(usize),
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum TicketType {
+    OpenTicket = 0,
+    Pass = 1,
+    Reservation = 2,
+    CarCarriageReservation = 3,
+    /// A value added by an extension that is unknown to this version of the schema, along with
+    /// its extension index.
+    #[uper(unknown)]
+    Unknown(usize),
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct PlacesType {
+    #[uper(ia5)] pub coach: Option<String>,
+    #[uper(ia5)] pub place_string: Option<String>,
+    #[uper(utf8)] pub place_description: Option<String>,
+    #[uper(name = "placeIA5", optional, exclusive_with = place_num, element(ia5))] #[serde(default)] pub place_ia_5: Vec<String>,
+    #[uper(optional, element(min = 1, max = 254))] #[serde(default)] pub place_num: Vec<crate::asn1_uper::Integer>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum PriceTypeType {
+    NoPrice = 0,
+    ReservationFee = 1,
+    Supplement = 2,
+    TravelPrice = 3,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum BerthTypeType {
+    Single = 0,
+    Special = 1,
+    Double = 2,
+    T2 = 3,
+    T3 = 4,
+    T4 = 5,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum CompartmentGenderType {
+    Unspecified = 0,
+    Family = 1,
+    Female = 2,
+    Male = 3,
+    Mixed = 4,
+    /// A value added by an extension that is unknown to this version of the schema, along with
+    /// its extension index.
+    #[uper(unknown)]
+    Unknown(usize),
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum GenderType {
+    Unspecified = 0,
+    Female = 1,
+    Male = 2,
+    Other = 3,
+    /// A value added by an extension that is unknown to this version of the schema, along with
+    /// its extension index.
+    #[uper(unknown)]
+    Unknown(usize),
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum TravelClassType {
+    NotApplicable = 0,
+    First = 1,
+    Second = 2,
+    Tourist = 3,
+    Comfort = 4,
+    Premium = 5,
+    Business = 6,
+    All = 7,
+    /// A value added by an extension that is unknown to this version of the schema, along with
+    /// its extension index.
+    #[uper(unknown)]
+    Unknown(usize),
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct BerthDetailData {
+    pub berth_type: BerthTypeType,
+    #[uper(min = 1, max = 999)] pub number_of_berths: crate::asn1_uper::Integer,
+    #[uper(default = CompartmentGenderType::Family)] pub gender: CompartmentGenderType,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct CompartmentDetailsType {
+    #[uper(min = 1, max = 99)] pub coach_type: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 99)] pub compartment_type: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 99)] pub special_allocation: Option<crate::asn1_uper::Integer>,
+    #[uper(utf8)] pub coach_type_descr: Option<String>,
+    #[uper(utf8)] pub compartment_type_descr: Option<String>,
+    #[uper(utf8)] pub special_allocation_descr: Option<String>,
+    #[uper(default = CompartmentPositionType::Unspecified)] pub position: CompartmentPositionType,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct LuggageRestrictionType {
+    #[uper(default = 3, min = 0, max = 99)] pub max_hand_luggage_pieces: crate::asn1_uper::Integer,
+    #[uper(default = 1, min = 0, max = 99)] pub max_non_hand_luggage_pieces: crate::asn1_uper::Integer,
+    #[uper(optional)] #[serde(default)] pub registered_luggage: Vec<RegisteredLuggageType>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct RegisteredLuggageType {
+    #[uper(ia5)] pub registration_id: Option<String>,
+    #[uper(min = 1, max = 99)] pub max_weight: Option<crate::asn1_uper::Integer>,
+    #[uper(min = 1, max = 300)] pub max_size: Option<crate::asn1_uper::Integer>,
+    #[uper(unknown)] #[serde(default, skip_serializing_if = "crate::asn1_uper::UnknownExtensions::is_empty")] pub unknown_extensions: crate::asn1_uper::UnknownExtensions,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct GeoCoordinateType {
+    #[uper(default = GeoUnitType::MilliDegree)] pub geo_unit: GeoUnitType,
+    #[uper(default = GeoCoordinateSystemType::Wgs84)] pub coordinate_system: GeoCoordinateSystemType,
+    #[uper(default = HemisphereLongitudeType::North)] pub hemisphere_longitude: HemisphereLongitudeType,
+    #[uper(default = HemisphereLatitudeType::East)] pub hemisphere_latitude: HemisphereLatitudeType,
+    pub longitude: crate::asn1_uper::Integer,
+    pub latitude: crate::asn1_uper::Integer,
+    pub accuracy: Option<GeoUnitType>,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+pub struct DeltaCoordinates {
+    pub longitude: crate::asn1_uper::Integer,
+    pub latitude: crate::asn1_uper::Integer,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum GeoCoordinateSystemType {
+    Wgs84 = 0,
+    Grs80 = 1,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum GeoUnitType {
+    MicroDegree = 0,
+    TenthmilliDegree = 1,
+    MilliDegree = 2,
+    CentiDegree = 3,
+    DeciDegree = 4,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum HemisphereLongitudeType {
+    North = 0,
+    South = 1,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum HemisphereLatitudeType {
+    East = 0,
+    West = 1,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum LoadingDeckType {
+    Unspecified = 0,
+    Upper = 1,
+    Lower = 2,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum CompartmentPositionType {
+    Unspecified = 0,
+    UpperLevel = 1,
+    LowerLevel = 2,
+}
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, UperDecode, UperEncode, UperVisit)]
+#[repr(u8)]
+pub enum RoofRackType {
+    Norack = 0,
+    RoofRailing = 1,
+    LuggageRack = 2,
+    SkiRack = 3,
+    BoxRack = 4,
+    RackWithOneBox = 5,
+    RackWithTwoBoxes = 6,
+    BicycleRack = 7,
+    OtherRack = 8,
+    /// A value added by an extension that is unknown to this version of the schema, along with
+    /// its extension index.
+    #[uper(unknown)]
+    Unknown(usize),
+}
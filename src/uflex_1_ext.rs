@@ -0,0 +1,44 @@
+//! Extensions for handling UFLEX version 1 tickets.
+
+
+use crate::records::impl_uflex_record_value;
+
+
+// https://github.com/UnionInternationalCheminsdeFer/UIC-barcode/blob/master/misc/uicRailTicketData_v1.3.4.asn
+impl_uflex_record_value!(uflex_1, 1);
+
+
+#[cfg(test)]
+mod tests {
+    use crate::records::{RecordDecoderRegistry, RecordValue};
+    use crate::uflex_1;
+
+    #[test]
+    fn test_decode_version_1() {
+        // version 1 stores the day of birth as the day of the year; version 2 replaced it with the
+        // month and the day of the month, so the presence bits of TravelerType no longer line up
+        let data: uflex_1::UicRailTicketData = serde_json::from_value(serde_json::json!({
+            "issuing_detail": {
+                "security_provider_num": "1181", "issuer_num": "1181", "issuing_year": "2023",
+                "issuing_day": "150", "issuing_time": "600", "specimen": true,
+                "secure_paper_ticket": false, "activated": true, "currency": "EUR",
+                "currency_fract": "2",
+            },
+            "traveler_detail": {
+                "traveler": [{ "last_name": "Mustermann", "year_of_birth": "1980", "day_of_birth": "100", "ticket_holder": true }],
+            },
+        })).unwrap();
+        let record_data = data.to_record_data().unwrap();
+
+        let registry = RecordDecoderRegistry::default();
+        let decoded = registry.decode("U_FLEX", "01", &record_data).unwrap().unwrap();
+        assert_eq!(decoded.downcast_ref::<uflex_1::UicRailTicketData>(), Some(&data));
+        assert_eq!(decoded.to_record_data().unwrap(), record_data);
+
+        let misread = registry.decode("U_FLEX", "02", &record_data).unwrap().unwrap_err();
+        assert_eq!(
+            misread.to_string(),
+            "failed to decode U_FLEX record: end of file while decoding UicRailTicketData.travelerDetail.traveler[0].lastName at bit 119",
+        );
+    }
+}
@@ -0,0 +1,51 @@
+//! Extensions for handling UFLEX version 2 tickets.
+
+
+use crate::records::impl_uflex_record_value;
+
+
+// https://github.com/UnionInternationalCheminsdeFer/UIC-barcode/blob/master/misc/uicRailTicketData_v2.0.5.asn
+impl_uflex_record_value!(uflex_2, 2);
+
+
+#[cfg(test)]
+mod tests {
+    use crate::records::{RecordDecoderRegistry, RecordValue};
+    use crate::uflex_2;
+
+    #[test]
+    fn test_decode_version_2() {
+        // version 3 added the included and excluded transport types to OpenTicketData, so the
+        // presence bits of an open ticket no longer line up
+        let data: uflex_2::UicRailTicketData = serde_json::from_value(serde_json::json!({
+            "issuing_detail": {
+                "security_provider_num": "1181", "issuer_num": "1181", "issuing_year": "2021",
+                "issuing_day": "30", "issuing_time": "720", "specimen": false, "secure_paper_ticket": false,
+                "activated": true, "currency": "EUR", "currency_fract": "2",
+            },
+            "traveler_detail": {
+                "traveler": [{ "last_name": "Mustermann", "year_of_birth": "1980", "month_of_birth": "4", "day_of_birth_in_month": "10", "ticket_holder": true }],
+            },
+            "transport_document": [{
+                "ticket": { "OpenTicket": {
+                    "return_included": false, "station_code_table": "StationUic",
+                    "from_station_num": "8100002", "to_station_num": "8101001",
+                    "valid_from_day": "0", "valid_until_day": "1", "class_code": "Second",
+                    "reference_ia_5": "REF001",
+                }},
+            }],
+        })).unwrap();
+        let record_data = data.to_record_data().unwrap();
+
+        let registry = RecordDecoderRegistry::default();
+        let decoded = registry.decode("U_FLEX", "02", &record_data).unwrap().unwrap();
+        assert_eq!(decoded.downcast_ref::<uflex_2::UicRailTicketData>(), Some(&data));
+        assert_eq!(decoded.to_record_data().unwrap(), record_data);
+
+        let misread = registry.decode("U_FLEX", "03", &record_data).unwrap().unwrap_err();
+        assert_eq!(
+            misread.to_string(),
+            "failed to decode U_FLEX record: end of file while decoding UicRailTicketData.transportDocument[0].ticket.openTicket.referenceIA5 at bit 281",
+        );
+    }
+}
@@ -7,7 +7,10 @@ use chrono::{
     DateTime, Days, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
 };
 
+use serde::Serialize;
+
 use crate::asn1_uper::Integer;
+use crate::records::impl_uflex_record_value;
 use crate::uflex_3::{
     CountermarkData, DocumentData, DocumentDataTicket, FipTicketData, IssuingData, OpenTicketData,
     ParkingGroundData, PassData, ReservationData, StationPassageData,
@@ -25,6 +28,13 @@ fn add_subtract_days(date: NaiveDate, days: i64) -> Option<NaiveDate> {
 }
 
 
+/// Returns a duration of the given number of minutes, or `None` if it is out of range.
+fn duration_minutes(minutes: i64) -> Option<Duration> {
+    minutes.checked_mul(60_000)
+        .map(Duration::milliseconds)
+}
+
+
 fn uic_offset_to_timezone(offset: i32) -> Option<FixedOffset> {
     // UTC = local + offset * 15min
     // the offset is given as local-to-UTC (UTC-to-local is more common)
@@ -34,7 +44,8 @@ fn uic_offset_to_timezone(offset: i32) -> Option<FixedOffset> {
 }
 
 
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(untagged)]
 pub enum DateTimeOptionTz {
     Date(NaiveDate),
     DateTime(NaiveDateTime),
@@ -60,76 +71,57 @@ impl fmt::Display for DateTimeOptionTz {
 }
 
 
-fn uic_date_time_triplet(base_date: NaiveDate, day: &Integer, time_opt: Option<&Integer>, utc_offset_opt: Option<&Integer>) -> (NaiveDate, DateTimeOptionTz) {
-    let day_i64: i64 = day.try_into().expect("failed to convert day to i64");
-    let naive_date = add_subtract_days(base_date, day_i64).expect("failed to add days to base date");
-    if let Some(time) = time_opt {
-        let time_i64: i64 = time.try_into().expect("failed to convert time to i64");
-        let naive_date_time = naive_date
-            .and_hms_opt(0, 0, 0).expect("date did not have midnight?!")
-            .checked_add_signed(Duration::minutes(time_i64)).expect("failed to add/subtract minutes to/from date");
-        if let Some(offset) = utc_offset_opt {
-            let offset_i32: i32 = offset.try_into().expect("failed to convert offset to i32");
-            let timezone = uic_offset_to_timezone(offset_i32)
-                .expect("failed to convert offset to timezone");
-            let date_time = timezone.from_local_datetime(&naive_date_time)
-                .single().expect("ambiguous point in time with fixed offset?!");
-            (naive_date, DateTimeOptionTz::DateTimeTz(date_time))
-        } else {
-            (naive_date, DateTimeOptionTz::DateTime(naive_date_time))
-        }
-    } else {
-        (naive_date, DateTimeOptionTz::Date(naive_date))
-    }
+/// Combines a date with an optional time in minutes after midnight and an optional UTC offset.
+/// Returns `None` if any value is out of range.
+fn date_with_time(date: NaiveDate, time_opt: Option<&Integer>, utc_offset_opt: Option<&Integer>) -> Option<DateTimeOptionTz> {
+    let Some(time) = time_opt else {
+        return Some(DateTimeOptionTz::Date(date));
+    };
+    let minutes: i64 = time.try_into().ok()?;
+    let naive_date_time = date
+        .and_hms_opt(0, 0, 0)?
+        .checked_add_signed(duration_minutes(minutes)?)?;
+    let Some(offset) = utc_offset_opt else {
+        return Some(DateTimeOptionTz::DateTime(naive_date_time));
+    };
+    let offset_i32: i32 = offset.try_into().ok()?;
+    let timezone = uic_offset_to_timezone(offset_i32)?;
+    let date_time = timezone.from_local_datetime(&naive_date_time).single()?;
+    Some(DateTimeOptionTz::DateTimeTz(date_time))
 }
 
-fn uic_date_time_quadruplet(year: &Integer, day: &Integer, time_opt: Option<&Integer>, utc_offset_opt: Option<&Integer>) -> DateTimeOptionTz {
-    let year_i32: i32 = year
-        .try_into().expect("failed to convert issuing year to i32");
-    let day_u32: u32 = day
-        .try_into().expect("failed to convert issuing day to u32");
-    let date = NaiveDate::from_yo_opt(year_i32, day_u32)
-            .expect("failed to convert issuing year and day to date");
-    if let Some(time) = time_opt {
-        let minute: i64 = time
-            .try_into().expect("failed to convert issuing minute to i64");
-        let naive_date_time = date
-            .and_hms_opt(0, 0, 0).expect("date did not have midnight?!")
-            .checked_add_signed(Duration::minutes(minute)).expect("failed to add minutes to date");
-        if let Some(offset) = utc_offset_opt {
-            let offset_i32: i32 = offset.try_into().expect("failed to convert offset to i32");
-            let timezone = uic_offset_to_timezone(offset_i32)
-                .expect("failed to convert offset to timezone");
-            let date_time = timezone.from_local_datetime(&naive_date_time)
-                .single().expect("ambiguous point in time with fixed offset?!");
-            DateTimeOptionTz::DateTimeTz(date_time)
-        } else {
-            DateTimeOptionTz::DateTime(naive_date_time)
-        }
-    } else {
-        DateTimeOptionTz::Date(date)
-    }
+fn uic_date_time_triplet(base_date: NaiveDate, day: &Integer, time_opt: Option<&Integer>, utc_offset_opt: Option<&Integer>) -> Option<(NaiveDate, DateTimeOptionTz)> {
+    let day_i64: i64 = day.try_into().ok()?;
+    let naive_date = add_subtract_days(base_date, day_i64)?;
+    let date_time = date_with_time(naive_date, time_opt, utc_offset_opt)?;
+    Some((naive_date, date_time))
+}
+
+fn uic_date_time_quadruplet(year: &Integer, day: &Integer, time_opt: Option<&Integer>, utc_offset_opt: Option<&Integer>) -> Option<DateTimeOptionTz> {
+    let date = uic_year_day(year, day)?;
+    date_with_time(date, time_opt, utc_offset_opt)
+}
+
+fn uic_year_day(year: &Integer, day: &Integer) -> Option<NaiveDate> {
+    let year_i32: i32 = year.try_into().ok()?;
+    let day_u32: u32 = day.try_into().ok()?;
+    NaiveDate::from_yo_opt(year_i32, day_u32)
 }
 
 
 /// Date calculations for the issuance of a ticket.
 pub trait IssuanceExt {
-    fn issuance_date(&self) -> DateTime<Utc>;
+    /// Returns the time of issuance, or `None` if it is out of range.
+    fn issuance_date(&self) -> Option<DateTime<Utc>>;
 }
 impl IssuanceExt for IssuingData {
-    fn issuance_date(&self) -> DateTime<Utc> {
-        let year: i32 = (&self.issuing_year)
-            .try_into().expect("failed to convert issuing year to i32");
-        let day: u32 = (&self.issuing_day)
-            .try_into().expect("failed to convert issuing day to u32");
-        let minute: i64 = (&self.issuing_time)
-            .try_into().expect("failed to convert issuing minute to i64");
-        let date = NaiveDate::from_yo_opt(year, day)
-            .expect("failed to convert issuing year and day to date");
+    fn issuance_date(&self) -> Option<DateTime<Utc>> {
+        let date = uic_year_day(&self.issuing_year, &self.issuing_day)?;
+        let minute: i64 = (&self.issuing_time).try_into().ok()?;
         let date_time = date
-            .and_hms_opt(0, 0, 0).expect("issuing date did not have midnight?!")
-            .checked_add_signed(Duration::minutes(minute)).expect("failed to add minutes to issuing date");
-        Utc.from_utc_datetime(&date_time)
+            .and_hms_opt(0, 0, 0)?
+            .checked_add_signed(duration_minutes(minute)?)?;
+        Some(Utc.from_utc_datetime(&date_time))
     }
 }
 
@@ -157,7 +149,7 @@ pub trait ValidityDepartureArrival {
             self.uic_departure_days_from_issuance(),
             self.uic_departure_time(),
             self.uic_departure_offset(),
-        );
+        )?;
         Some(departure_time)
     }
 
@@ -174,7 +166,7 @@ pub trait ValidityDepartureArrival {
             self.uic_arrival_days_from_departure(),
             self.uic_arrival_time(),
             self.uic_arrival_offset().or(self.uic_departure_offset()),
-        );
+        )?;
         Some(arrival_time)
     }
 }
@@ -240,174 +232,418 @@ impl ValidityDepartureArrival for StationPassageData {
 }
 
 
-pub(crate) fn output_ticket_validity(issuing_data: &IssuingData, documents: &[DocumentData]) {
-    let issuance_date = issuing_data.issuance_date();
-    println!("issued: {}", issuance_date);
+/// A single piece of interpreted validity information of a ticket.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum ValidityItem {
+    Departure { at: DateTimeOptionTz },
+    Arrival { at: DateTimeOptionTz },
+    BeginLoading { at: DateTimeOptionTz },
+    EndLoading { at: DateTimeOptionTz },
+    ValidFrom { at: DateTimeOptionTz },
+    ValidTo { at: DateTimeOptionTz },
+    ValidFromYear { year: i32 },
+    ValidToYear { year: i32 },
+    ParkingFrom { at: DateTimeOptionTz },
+    ParkingTo { at: DateTimeOptionTz },
+    ActivatedOn { date: NaiveDate },
+    ValidPeriod { from: DateTimeOptionTz, to: DateTimeOptionTz },
+    InvalidBetween { from: NaiveTime, to: NaiveTime },
+    DepartureTime { at: DateTimeOptionTz },
+}
+impl ValidityItem {
+    /// Whether this item is part of the detailed validity periods of a pass.
+    pub fn is_detail(&self) -> bool {
+        matches!(self, Self::ValidPeriod { .. } | Self::InvalidBetween { .. })
+    }
+}
+impl fmt::Display for ValidityItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Departure { at } => write!(f, "departure: {}", at),
+            Self::Arrival { at } => write!(f, "arrival: {}", at),
+            Self::BeginLoading { at } => write!(f, "begin loading: {}", at),
+            Self::EndLoading { at } => write!(f, "end loading: {}", at),
+            Self::ValidFrom { at } => write!(f, "valid from: {}", at),
+            Self::ValidTo { at } => write!(f, "valid to: {}", at),
+            Self::ValidFromYear { year } => write!(f, "valid from: {}", year),
+            Self::ValidToYear { year } => write!(f, "valid to: {}", year),
+            Self::ParkingFrom { at } => write!(f, "parking from: {}", at),
+            Self::ParkingTo { at } => write!(f, "parking to: {}", at),
+            Self::ActivatedOn { date } => write!(f, "activated on {}", date),
+            Self::ValidPeriod { from, to } => write!(f, "valid from {} to {}", from, to),
+            Self::InvalidBetween { from, to } => write!(f, "invalid between {} and {}", from, to),
+            Self::DepartureTime { at } => write!(f, "departure time: {}", at),
+        }
+    }
+}
+
+
+/// The interpreted validity information of a ticket.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct TicketValidity {
+    /// The time at which the ticket was issued.
+    pub issued: DateTime<Utc>,
 
-    for (i, document) in documents.iter().enumerate() {
-        println!("ticket {}:", i + 1);
-        match &document.ticket {
-            DocumentDataTicket::Reservation(ticket) => {
-                let valid_from = ticket.departure(issuance_date).unwrap();
-                let valid_to = ticket.arrival(issuance_date).unwrap();
-                println!("  departure: {}", valid_from);
-                println!("  arrival: {}", valid_to);
-            },
-            DocumentDataTicket::CarCarriageReservation(ticket) => {
-                let (_, begin_loading) = uic_date_time_triplet(
-                    issuance_date.date_naive(),
-                    &ticket.begin_loading_date,
-                    ticket.begin_loading_time.as_ref(),
-                    ticket.loading_utc_offset.as_ref(),
-                );
-                let (_, end_loading) = uic_date_time_triplet(
-                    issuance_date.date_naive(),
-                    &ticket.begin_loading_date,
-                    ticket.end_loading_time.as_ref(),
-                    ticket.loading_utc_offset.as_ref(),
-                );
-                println!("  begin loading: {}", begin_loading);
-                println!("  end loading: {}", end_loading);
-            },
-            DocumentDataTicket::OpenTicket(ticket) => {
-                let valid_from = ticket.departure(issuance_date).unwrap();
-                let valid_to = ticket.arrival(issuance_date).unwrap();
-                println!("  valid from: {}", valid_from);
-                println!("  valid to: {}", valid_to);
-
-                for activated_day in &ticket.activated_day {
-                    let days_i64: i64 = activated_day.try_into().unwrap();
-                    let day = add_subtract_days(valid_from.naive_date(), days_i64).unwrap();
-                    println!("  activated on {}", day);
-                }
-            },
-            DocumentDataTicket::Pass(ticket) => {
-                let valid_from = ticket.departure(issuance_date).unwrap();
-                let valid_to = ticket.arrival(issuance_date).unwrap();
-                println!("  valid from: {}", valid_from);
-                println!("  valid to: {}", valid_to);
-
-                if let Some(details) = &ticket.validity_period_details {
-                    println!("  in detail:");
-                    for period in &details.validity_period {
-                        let (valid_from_date, valid_from_time) = uic_date_time_triplet(
-                            issuance_date.date_naive(),
-                            &period.valid_from_day,
-                            period.valid_from_time.as_ref(),
-                            period.valid_from_utc_offset.as_ref(),
-                        );
-                        let (_, valid_to_time) = uic_date_time_triplet(
-                            valid_from_date,
-                            &period.valid_until_day,
-                            period.valid_until_time.as_ref(),
-                            period.valid_until_utc_offset.as_ref().or(period.valid_from_utc_offset.as_ref()),
-                        );
-                        println!("    valid from {} to {}", valid_from_time, valid_to_time);
-                    }
-                    for exclusion in &details.excluded_time_range {
-                        let from_minutes: i64 = (&exclusion.from_time)
-                            .try_into().expect("failed to convert from_time to i64");
-                        let to_minutes: i64 = (&exclusion.until_time)
-                            .try_into().expect("failed to convert until_time to i64");
-                        let (from_time, _overflow_days) = NaiveTime::from_hms_opt(0, 0, 0)
-                            .expect("no midnight?!")
-                            .overflowing_add_signed(Duration::minutes(from_minutes));
-                        let (to_time, _overflow_days) = NaiveTime::from_hms_opt(0, 0, 0)
-                            .expect("no midnight?!")
-                            .overflowing_add_signed(Duration::minutes(to_minutes));
-                        println!("    invalid between {} and {}", from_time, to_time);
-                    }
-                }
+    /// The validity information of each document of the ticket.
+    pub documents: Vec<Vec<ValidityItem>>,
+}
+
+
+/// An error that occurs when the validity information of a ticket cannot be interpreted.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ValidityError {
+    /// The index of the document whose validity information cannot be interpreted, or `None` if
+    /// the time of issuance cannot be interpreted.
+    pub document_index: Option<usize>,
+
+    /// The value which does not describe a valid date or time.
+    pub field: &'static str,
+}
+impl fmt::Display for ValidityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.document_index {
+            Some(index) => write!(f, "ticket {}: {} is not a valid date or time", index + 1, self.field),
+            None => write!(f, "{} is not a valid date or time", self.field),
+        }
+    }
+}
+impl std::error::Error for ValidityError {
+}
+
+
+fn activated_days(valid_from: DateTimeOptionTz, activated_day: &[Integer]) -> Result<Vec<ValidityItem>, &'static str> {
+    activated_day.iter()
+        .map(|activated_day| {
+            let date = activated_day.try_into().ok()
+                .and_then(|days: i64| add_subtract_days(valid_from.naive_date(), days))
+                .ok_or("activated day")?;
+            Ok(ValidityItem::ActivatedOn { date })
+        })
+        .collect()
+}
+
+
+/// Interprets the validity information of the given documents.
+pub fn ticket_validity(issuing_data: &IssuingData, documents: &[DocumentData]) -> Result<TicketValidity, ValidityError> {
+    let issuance_date = issuing_data.issuance_date()
+        .ok_or(ValidityError { document_index: None, field: "time of issuance" })?;
 
-                for activated_day in &ticket.activated_day {
-                    let days_i64: i64 = activated_day.try_into().unwrap();
-                    let day = add_subtract_days(valid_from.naive_date(), days_i64).unwrap();
-                    println!("  activated on {}", day);
+    let mut document_validities = Vec::with_capacity(documents.len());
+    for (index, document) in documents.iter().enumerate() {
+        let items = document_validity(&document.ticket, issuance_date)
+            .map_err(|field| ValidityError { document_index: Some(index), field })?;
+        document_validities.push(items);
+    }
+
+    Ok(TicketValidity {
+        issued: issuance_date,
+        documents: document_validities,
+    })
+}
+
+/// Interprets the validity information of a single document. On failure, returns the name of the
+/// value which cannot be interpreted.
+fn document_validity(ticket: &DocumentDataTicket, issuance_date: DateTime<Utc>) -> Result<Vec<ValidityItem>, &'static str> {
+    let mut items = Vec::new();
+    match ticket {
+        DocumentDataTicket::Reservation(ticket) => {
+            let valid_from = ticket.departure(issuance_date).ok_or("departure")?;
+            let valid_to = ticket.arrival(issuance_date).ok_or("arrival")?;
+            items.push(ValidityItem::Departure { at: valid_from });
+            items.push(ValidityItem::Arrival { at: valid_to });
+        },
+        DocumentDataTicket::CarCarriageReservation(ticket) => {
+            let (_, begin_loading) = uic_date_time_triplet(
+                issuance_date.date_naive(),
+                &ticket.begin_loading_date,
+                ticket.begin_loading_time.as_ref(),
+                ticket.loading_utc_offset.as_ref(),
+            ).ok_or("begin of loading")?;
+            let (_, end_loading) = uic_date_time_triplet(
+                issuance_date.date_naive(),
+                &ticket.begin_loading_date,
+                ticket.end_loading_time.as_ref(),
+                ticket.loading_utc_offset.as_ref(),
+            ).ok_or("end of loading")?;
+            items.push(ValidityItem::BeginLoading { at: begin_loading });
+            items.push(ValidityItem::EndLoading { at: end_loading });
+        },
+        DocumentDataTicket::OpenTicket(ticket) => {
+            let valid_from = ticket.departure(issuance_date).ok_or("start of validity")?;
+            let valid_to = ticket.arrival(issuance_date).ok_or("end of validity")?;
+            items.push(ValidityItem::ValidFrom { at: valid_from });
+            items.push(ValidityItem::ValidTo { at: valid_to });
+            items.extend(activated_days(valid_from, &ticket.activated_day)?);
+        },
+        DocumentDataTicket::Pass(ticket) => {
+            let valid_from = ticket.departure(issuance_date).ok_or("start of validity")?;
+            let valid_to = ticket.arrival(issuance_date).ok_or("end of validity")?;
+            items.push(ValidityItem::ValidFrom { at: valid_from });
+            items.push(ValidityItem::ValidTo { at: valid_to });
+
+            if let Some(details) = &ticket.validity_period_details {
+                for period in &details.validity_period {
+                    let (valid_from_date, valid_from_time) = uic_date_time_triplet(
+                        issuance_date.date_naive(),
+                        &period.valid_from_day,
+                        period.valid_from_time.as_ref(),
+                        period.valid_from_utc_offset.as_ref(),
+                    ).ok_or("start of validity period")?;
+                    let (_, valid_to_time) = uic_date_time_triplet(
+                        valid_from_date,
+                        &period.valid_until_day,
+                        period.valid_until_time.as_ref(),
+                        period.valid_until_utc_offset.as_ref().or(period.valid_from_utc_offset.as_ref()),
+                    ).ok_or("end of validity period")?;
+                    items.push(ValidityItem::ValidPeriod { from: valid_from_time, to: valid_to_time });
+                }
+                for exclusion in &details.excluded_time_range {
+                    let from_time = time_of_day(&exclusion.from_time).ok_or("start of excluded time range")?;
+                    let to_time = time_of_day(&exclusion.until_time).ok_or("end of excluded time range")?;
+                    items.push(ValidityItem::InvalidBetween { from: from_time, to: to_time });
                 }
-            },
-            DocumentDataTicket::Voucher(ticket) => {
+            }
+
+            items.extend(activated_days(valid_from, &ticket.activated_day)?);
+        },
+        DocumentDataTicket::Voucher(ticket) => {
+            let from_date = uic_year_day(&ticket.valid_from_year, &ticket.valid_from_day)
+                .ok_or("start of validity")?;
+            let to_date = uic_year_day(&ticket.valid_until_year, &ticket.valid_until_day)
+                .ok_or("end of validity")?;
+            items.push(ValidityItem::ValidFrom { at: DateTimeOptionTz::Date(from_date) });
+            items.push(ValidityItem::ValidTo { at: DateTimeOptionTz::Date(to_date) });
+        },
+        DocumentDataTicket::CustomerCard(ticket) => {
+            if let Some(from_day) = ticket.valid_from_day.as_ref() {
+                let from_date = uic_year_day(&ticket.valid_from_year, from_day)
+                    .ok_or("start of validity")?;
+                items.push(ValidityItem::ValidFrom { at: DateTimeOptionTz::Date(from_date) });
+            } else {
                 let from_year: i32 = (&ticket.valid_from_year)
-                    .try_into().expect("failed to convert from-year to i32");
-                let from_day: u32 = (&ticket.valid_from_day)
-                    .try_into().expect("failed to convert from-day to u32");
-                let from_date = NaiveDate::from_yo_opt(from_year, from_day)
-                    .expect("failed to convert from-date to date");
-
+                    .try_into().map_err(|_| "start of validity")?;
+                items.push(ValidityItem::ValidFromYear { year: from_year });
+            }
+
+            if let Some(to_day) = ticket.valid_until_day.as_ref() {
+                let to_date = uic_year_day(&ticket.valid_until_year, to_day)
+                    .ok_or("end of validity")?;
+                items.push(ValidityItem::ValidTo { at: DateTimeOptionTz::Date(to_date) });
+            } else {
                 let to_year: i32 = (&ticket.valid_until_year)
-                    .try_into().expect("failed to convert to-year to i32");
-                let to_day: u32 = (&ticket.valid_until_day)
-                    .try_into().expect("failed to convert to-day to u32");
-                let to_date = NaiveDate::from_yo_opt(to_year, to_day)
-                    .expect("failed to convert to-date to date");
-
-                println!("  valid from: {}", from_date);
-                println!("  valid to: {}", to_date);
-            },
-            DocumentDataTicket::CustomerCard(ticket) => {
-                let from_year: i32 = (&ticket.valid_from_year)
-                    .try_into().expect("failed to convert from-year to i32");
-                if let Some(from_day) = ticket.valid_from_day.as_ref() {
-                    let from_day_u32: u32 = from_day
-                        .try_into().expect("failed to convert from-day to u32");
-                    let from_date = NaiveDate::from_yo_opt(from_year, from_day_u32)
-                        .expect("failed to convert from-date to date");
-                    println!("  valid from: {}", from_date);
-                } else {
-                    println!("  valid from: {}", from_year);
+                    .try_into().map_err(|_| "end of validity")?;
+                items.push(ValidityItem::ValidToYear { year: to_year });
+            }
+        },
+        DocumentDataTicket::CounterMark(ticket) => {
+            let valid_from = ticket.departure(issuance_date).ok_or("start of validity")?;
+            let valid_to = ticket.arrival(issuance_date).ok_or("end of validity")?;
+            items.push(ValidityItem::ValidFrom { at: valid_from });
+            items.push(ValidityItem::ValidTo { at: valid_to });
+        },
+        DocumentDataTicket::ParkingGround(ticket) => {
+            let valid_from = ticket.departure(issuance_date).ok_or("start of parking")?;
+            let valid_to = ticket.arrival(issuance_date).ok_or("end of parking")?;
+            items.push(ValidityItem::ParkingFrom { at: valid_from });
+            items.push(ValidityItem::ParkingTo { at: valid_to });
+        },
+        DocumentDataTicket::FipTicket(ticket) => {
+            let valid_from = ticket.departure(issuance_date).ok_or("start of validity")?;
+            let valid_to = ticket.arrival(issuance_date).ok_or("end of validity")?;
+            items.push(ValidityItem::ValidFrom { at: valid_from });
+            items.push(ValidityItem::ValidTo { at: valid_to });
+        },
+        DocumentDataTicket::StationPassage(ticket) => {
+            let valid_from = ticket.departure(issuance_date).ok_or("start of validity")?;
+            let valid_to = ticket.arrival(issuance_date).ok_or("end of validity")?;
+            items.push(ValidityItem::ValidFrom { at: valid_from });
+            items.push(ValidityItem::ValidTo { at: valid_to });
+        },
+        DocumentDataTicket::Extension(_ticket) => {
+            // shrug
+        },
+        DocumentDataTicket::DelayConfirmation(ticket) => {
+            if let Some(year) = ticket.departure_year.as_ref() {
+                if let Some(day) = ticket.departure_day.as_ref() {
+                    let departure_time = uic_date_time_quadruplet(
+                        year,
+                        day,
+                        ticket.departure_time.as_ref(),
+                        ticket.departure_utc_offset.as_ref(),
+                    ).ok_or("departure")?;
+                    items.push(ValidityItem::DepartureTime { at: departure_time });
                 }
+            }
+        },
+        DocumentDataTicket::Unknown { .. } => {
+            // document type from a newer version of the schema
+        },
+    }
+    Ok(items)
+}
 
-                let to_year: i32 = (&ticket.valid_until_year)
-                    .try_into().expect("failed to convert to-year to i32");
-                if let Some(to_day) = ticket.valid_until_day.as_ref() {
-                    let to_day_u32: u32 = to_day
-                        .try_into().expect("failed to convert to-day to u32");
-                    let to_date = NaiveDate::from_yo_opt(to_year, to_day_u32)
-                        .expect("failed to convert to-date to date");
-                    println!("  valid from: {}", to_date);
-                } else {
-                    println!("  valid from: {}", to_year);
+/// Converts minutes after midnight into a time of day, wrapping around at midnight.
+fn time_of_day(minutes: &Integer) -> Option<NaiveTime> {
+    let minutes_i64: i64 = minutes.try_into().ok()?;
+    let (time, _overflow_days) = NaiveTime::MIN
+        .overflowing_add_signed(duration_minutes(minutes_i64)?);
+    Some(time)
+}
+
+
+pub fn output_ticket_validity(issuing_data: &IssuingData, documents: &[DocumentData]) {
+    let validity = match ticket_validity(issuing_data, documents) {
+        Ok(v) => v,
+        Err(e) => {
+            println!("cannot interpret validity: {}", e);
+            return;
+        },
+    };
+    println!("issued: {}", validity.issued);
+
+    for (i, items) in validity.documents.iter().enumerate() {
+        println!("ticket {}:", i + 1);
+        let mut in_detail = false;
+        for item in items {
+            if item.is_detail() {
+                if !in_detail {
+                    println!("  in detail:");
+                    in_detail = true;
                 }
-            },
-            DocumentDataTicket::CounterMark(ticket) => {
-                let valid_from = ticket.departure(issuance_date).unwrap();
-                let valid_to = ticket.arrival(issuance_date).unwrap();
-                println!("  valid from: {}", valid_from);
-                println!("  valid to: {}", valid_to);
-            },
-            DocumentDataTicket::ParkingGround(ticket) => {
-                let valid_from = ticket.departure(issuance_date).unwrap();
-                let valid_to = ticket.arrival(issuance_date).unwrap();
-                println!("  parking from: {}", valid_from);
-                println!("  parking to: {}", valid_to);
-            },
-            DocumentDataTicket::FipTicket(ticket) => {
-                let valid_from = ticket.departure(issuance_date).unwrap();
-                let valid_to = ticket.arrival(issuance_date).unwrap();
-                println!("  valid from: {}", valid_from);
-                println!("  valid to: {}", valid_to);
-            },
-            DocumentDataTicket::StationPassage(ticket) => {
-                let valid_from = ticket.departure(issuance_date).unwrap();
-                let valid_to = ticket.arrival(issuance_date).unwrap();
-                println!("  valid from: {}", valid_from);
-                println!("  valid to: {}", valid_to);
-            },
-            DocumentDataTicket::Extension(_ticket) => {
-                // shrug
-            },
-            DocumentDataTicket::DelayConfirmation(ticket) => {
-                if let Some(year) = ticket.departure_year.as_ref() {
-                    if let Some(day) = ticket.departure_day.as_ref() {
-                        let departure_time = uic_date_time_quadruplet(
-                            year,
-                            day,
-                            ticket.departure_time.as_ref(),
-                            ticket.departure_utc_offset.as_ref(),
-                        );
-                        println!("  departure time: {}", departure_time);
+                println!("    {}", item);
+            } else {
+                println!("  {}", item);
+            }
+        }
+    }
+}
+
+
+// https://github.com/UnionInternationalCheminsdeFer/UIC-barcode/blob/master/misc/uicRailTicketData_v3.0.3.asn
+impl_uflex_record_value!(uflex_3, 3);
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asn1_uper::{with_variant, BitSlice, BitVec, PerVariant};
+    use crate::uflex_3::UicRailTicketData;
+
+    /// The U_FLEX payload of a specimen open ticket with one traveler and one transport document.
+    const OPEN_TICKET: &[u8] = include_bytes!("../tests/data/open_ticket.uper");
+
+    #[test]
+    fn test_decode_does_not_panic() {
+        let (_rest, data) = UicRailTicketData::try_from_uper(BitSlice::new(OPEN_TICKET)).unwrap();
+        assert_eq!(data.transport_document.len(), 1);
+
+        // xorshift, to obtain the same inputs on every run
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next_random = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for i in 0..20_000 {
+            let mut payload = OPEN_TICKET.to_vec();
+            let random = next_random() as usize;
+            match i % 4 {
+                0 => {
+                    // flip a few bits
+                    for _ in 0..(random % 8 + 1) {
+                        let bit_index = next_random() as usize % (8 * payload.len());
+                        payload[bit_index / 8] ^= 0x80 >> (bit_index % 8);
                     }
-                }
-            },
+                },
+                1 => {
+                    // truncate
+                    payload.truncate(random % payload.len());
+                },
+                2 => {
+                    // replace the tail with garbage
+                    for b in &mut payload[random % OPEN_TICKET.len()..] {
+                        *b = next_random() as u8;
+                    }
+                },
+                _ => {
+                    // garbage throughout
+                    payload = (0..random % 256).map(|_| next_random() as u8).collect();
+                },
+            }
+
+            let bits = BitSlice::new(&payload);
+            if let Ok((_rest, data)) = UicRailTicketData::try_from_uper(bits) {
+                // whatever is decoded can be reencoded
+                let mut encoded = BitVec::new();
+                data.write_uper(&mut encoded).unwrap();
+            }
+            let _ = UicRailTicketData::try_from_per(bits, PerVariant::Aligned);
+            let _ = with_variant(PerVariant::Aligned, || UicRailTicketData::check_canonical_uper(bits));
         }
     }
+
+    #[test]
+    fn test_visit() {
+        use crate::asn1_uper::trace::path_to_string;
+        use crate::asn1_uper::visit::{field_name, Value, ValueMut, Visit};
+
+        let (_rest, mut data) = UicRailTicketData::try_from_uper(BitSlice::new(OPEN_TICKET)).unwrap();
+
+        let mut fields = Vec::new();
+        data.visit(&mut |path: &[_], value: Value<'_>| fields.push((path_to_string(path), format!("{:?}", value))));
+        assert_eq!(fields.len(), 26);
+        assert_eq!(fields[0], ("issuingDetail.securityProviderNum".to_owned(), "Integer(Integer { inner: Short(1181) })".to_owned()));
+        assert!(fields.contains(&("travelerDetail.traveler[0].lastName".to_owned(), "String(\"Mustermann\")".to_owned())));
+        assert!(fields.contains(&("transportDocument[0].ticket.openTicket.classCode".to_owned(), "Enumerated(Some(\"second\"))".to_owned())));
+
+        // redact the names of the travelers and upgrade the class
+        data.visit_mut(&mut |path: &[_], value: ValueMut<'_>| match (field_name(path), value) {
+            (Some("firstName" | "lastName"), ValueMut::String(name)) => *name = "X".to_owned(),
+            (Some("classCode"), ValueMut::Enumerated(class)) => assert!(class.set_name("first")),
+            (_, ValueMut::Enumerated(class)) => assert!(!class.set_name("nonexistent")),
+            _ => {},
+        });
+        let mut encoded = BitVec::new();
+        data.write_uper(&mut encoded).unwrap();
+        let (_rest, rewritten) = UicRailTicketData::try_from_uper(encoded.as_slice()).unwrap();
+        let traveler = &rewritten.traveler_detail.as_ref().unwrap().traveler[0];
+        assert_eq!(traveler.first_name.as_deref(), Some("X"));
+        assert_eq!(traveler.last_name.as_deref(), Some("X"));
+        let DocumentDataTicket::OpenTicket(open_ticket) = &rewritten.transport_document[0].ticket else {
+            panic!("expected an open ticket");
+        };
+        assert_eq!(open_ticket.class_code, crate::uflex_3::TravelClassType::First);
+        assert_eq!(open_ticket.station_code_table, crate::uflex_3::CodeTableType::StationUic);
+    }
+
+    #[test]
+    fn test_validate() {
+        let (_rest, mut data) = UicRailTicketData::try_from_uper(BitSlice::new(OPEN_TICKET)).unwrap();
+        assert_eq!(data.validate(), Ok(()));
+
+        data.issuing_detail.issuer_ia_5 = Some("1181".to_owned());
+        data.issuing_detail.issuing_day = Integer::from_short(367);
+        data.issuing_detail.currency = "EURO".to_owned();
+        data.issuing_detail.issuer_pnr = Some("\u{00C4}BC123".to_owned());
+        let DocumentDataTicket::OpenTicket(open_ticket) = &mut data.transport_document[0].ticket else {
+            panic!("expected an open ticket");
+        };
+        open_ticket.reference_num = Some(Integer::from_short(1));
+        open_ticket.class_code = crate::uflex_3::TravelClassType::Unknown(20);
+        assert!(data.write_uper(&mut BitVec::new()).is_err());
+
+        let messages: Vec<String> = data.validate().unwrap_err().iter()
+            .map(|violation| violation.to_string())
+            .collect();
+        assert_eq!(messages, [
+            "issuingDetail.issuerIA5: member must not be present at the same time as issuerNum",
+            "issuingDetail.issuingDay: integer 367 is outside the range 1..366",
+            "issuingDetail.currency: length 4 is outside the permitted size range 3..3",
+            "issuingDetail.issuerPnr: IA5 string \"\u{00C4}BC123\" byte 0 (0xC3) has top bit set",
+            "transportDocument[0].ticket.openTicket.referenceIA5: member must not be present at the same time as referenceNum",
+        ]);
+    }
 }
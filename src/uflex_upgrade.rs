@@ -0,0 +1,945 @@
+//! Upgrading of UFLEX version 1 and 2 ticket data to the version 3 model.
+//!
+//! Each type of an earlier version is converted member by member into the corresponding type of the
+//! next version, so a change to either schema that is not reflected here fails to compile. Values
+//! added by extensions unknown to the earlier version cannot be carried over, as the same extension
+//! index may stand for a different value in a later version; they are dropped and reported as a
+//! [`DataLoss`]. If such a value is not optional, the upgrade fails with an [`UpgradeError`].
+
+
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::asn1_uper::{Integer, UnknownExtensions};
+use crate::asn1_uper::trace::{path_to_string, PathSegment};
+use crate::asn1_uper::visit::{Unknown, Value, Visit};
+use crate::records::RecordValue;
+use crate::{uflex_1, uflex_2, uflex_3};
+
+
+/// A value which cannot be represented in version 3 and has been dropped during the upgrade.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DataLoss {
+    /// The path to the value in the original ticket data.
+    pub path: Vec<PathSegment>,
+
+    /// The dropped value in ASN.1 value notation.
+    pub value: String,
+}
+impl DataLoss {
+    /// Returns the path to the value in ASN.1 notation.
+    pub fn path_string(&self) -> String {
+        path_to_string(&self.path)
+    }
+}
+impl fmt::Display for DataLoss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: value {} cannot be represented in version 3 and has been dropped", self.path_string(), self.value)
+    }
+}
+
+
+/// An error that may occur when upgrading ticket data.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum UpgradeError {
+    /// A value that is not optional has been added by an extension unknown to the earlier version
+    /// and cannot be represented in the later version.
+    UnknownValue { type_name: &'static str },
+}
+impl fmt::Display for UpgradeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownValue { type_name }
+                => write!(f, "a mandatory {} value is an unknown extension value and cannot be upgraded", type_name),
+        }
+    }
+}
+impl std::error::Error for UpgradeError {
+}
+
+
+/// Ticket data upgraded to version 3.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Upgraded {
+    pub ticket_data: uflex_3::UicRailTicketData,
+
+    /// The values of the original ticket data which have been dropped.
+    pub losses: Vec<DataLoss>,
+}
+
+
+/// Ticket data of an earlier version which can be upgraded to version 3.
+pub trait UpgradeToV3 {
+    /// Converts the ticket data into the version 3 model, reporting each value that has been lost.
+    fn upgrade_to_v3(&self) -> Result<Upgraded, UpgradeError>;
+}
+
+impl UpgradeToV3 for uflex_2::UicRailTicketData {
+    fn upgrade_to_v3(&self) -> Result<Upgraded, UpgradeError> {
+        let losses = unknown_value_losses(self);
+        let ticket_data = self.upgrade()?;
+        Ok(Upgraded {
+            ticket_data,
+            losses,
+        })
+    }
+}
+
+impl UpgradeToV3 for uflex_1::UicRailTicketData {
+    fn upgrade_to_v3(&self) -> Result<Upgraded, UpgradeError> {
+        let mut losses = unknown_value_losses(self);
+
+        // days of the year of birth which cannot be converted into a month and a day of the month
+        let travelers = self.traveler_detail.iter()
+            .flat_map(|traveler_detail| traveler_detail.traveler.iter());
+        for (index, traveler) in travelers.enumerate() {
+            let Some(day_of_birth) = &traveler.day_of_birth else { continue };
+            if birth_month_and_day(traveler.year_of_birth.as_ref(), day_of_birth).is_none() {
+                losses.push(DataLoss {
+                    path: vec![
+                        PathSegment::Member("travelerDetail"),
+                        PathSegment::Member("traveler"),
+                        PathSegment::Index(index),
+                        PathSegment::Member("dayOfBirth"),
+                    ],
+                    value: day_of_birth.to_string(),
+                });
+            }
+        }
+
+        let ticket_data = self.upgrade()?.upgrade()?;
+        Ok(Upgraded {
+            ticket_data,
+            losses,
+        })
+    }
+}
+
+
+/// Upgrades the value of a U_FLEX record of version 1 or 2. Returns `None` for any other value.
+pub fn upgrade_record_value(value: &dyn RecordValue) -> Option<Result<Upgraded, UpgradeError>> {
+    if let Some(data) = value.downcast_ref::<uflex_1::UicRailTicketData>() {
+        Some(data.upgrade_to_v3())
+    } else {
+        value.downcast_ref::<uflex_2::UicRailTicketData>()
+            .map(|data| data.upgrade_to_v3())
+    }
+}
+
+
+/// Converts the day of the year of birth into the month and the day of the month.
+///
+/// Without the year of birth, only days before the 29th of February can be converted.
+fn birth_month_and_day(year_of_birth: Option<&Integer>, day_of_birth: &Integer) -> Option<(u32, u32)> {
+    let ordinal = u32::try_from(day_of_birth).ok()?;
+    let year = match year_of_birth {
+        Some(year) => i32::try_from(year).ok()?,
+        None if ordinal < 60 => 2001,
+        None => return None,
+    };
+    let date = NaiveDate::from_yo_opt(year, ordinal)?;
+    Some((date.month(), date.day()))
+}
+
+
+/// Reports each value of the original ticket data which has been added by an unknown extension.
+/// None of them survive the upgrade.
+fn unknown_value_losses<T: Visit>(original: &T) -> Vec<DataLoss> {
+    struct Collector(Vec<DataLoss>);
+    impl crate::asn1_uper::visit::Visitor for Collector {
+        fn visit(&mut self, _path: &[PathSegment], _value: Value<'_>) {}
+
+        fn visit_unknown(&mut self, path: &[PathSegment], unknown: Unknown<'_>) {
+            self.0.push(DataLoss {
+                path: path.to_vec(),
+                value: unknown.to_string(),
+            });
+        }
+    }
+
+    let mut collector = Collector(Vec::new());
+    original.visit(&mut collector);
+    collector.0
+}
+
+
+/// A value of one version of the schema that can be converted into the corresponding value of the
+/// next version.
+trait Upgrade {
+    type Upgraded;
+
+    /// Converts the value. Fails if the value, or a value within it that is not optional, has been
+    /// added by an unknown extension.
+    fn upgrade(&self) -> Result<Self::Upgraded, UpgradeError>;
+
+    /// Returns whether the value has been added by an unknown extension. Such values are dropped
+    /// from OPTIONAL members and SEQUENCE OF values.
+    fn is_unknown(&self) -> bool {
+        false
+    }
+}
+
+impl<T: Upgrade> Upgrade for Option<T> {
+    type Upgraded = Option<T::Upgraded>;
+    fn upgrade(&self) -> Result<Self::Upgraded, UpgradeError> {
+        match self {
+            Some(value) if !value.is_unknown() => value.upgrade().map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<T: Upgrade> Upgrade for Vec<T> {
+    type Upgraded = Vec<T::Upgraded>;
+    fn upgrade(&self) -> Result<Self::Upgraded, UpgradeError> {
+        self.iter()
+            .filter(|value| !value.is_unknown())
+            .map(|value| value.upgrade())
+            .collect()
+    }
+}
+
+impl Upgrade for UnknownExtensions {
+    type Upgraded = UnknownExtensions;
+    fn upgrade(&self) -> Result<Self::Upgraded, UpgradeError> {
+        Ok(UnknownExtensions::default())
+    }
+}
+
+macro_rules! upgrade_unchanged {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl Upgrade for $type {
+                type Upgraded = $type;
+                fn upgrade(&self) -> Result<Self::Upgraded, UpgradeError> {
+                    Ok(self.clone())
+                }
+            }
+        )*
+    };
+}
+upgrade_unchanged!(Integer, bool, u8, String);
+
+/// Upgrades a SEQUENCE type member by member. Members added in the next version are listed after
+/// `with` along with their values.
+macro_rules! upgrade_sequence {
+    (
+        $from:ident => $to:ident, $type:ident { $($member:ident),* $(,)? }
+        $(with { $($added:ident : $added_value:expr),* $(,)? })?
+    ) => {
+        impl Upgrade for $from::$type {
+            type Upgraded = $to::$type;
+            fn upgrade(&self) -> Result<Self::Upgraded, UpgradeError> {
+                let Self { $($member),* } = self;
+                Ok($to::$type {
+                    $($member: $member.upgrade()?,)*
+                    $($($added: $added_value,)*)?
+                })
+            }
+        }
+    };
+}
+
+/// Upgrades an ENUMERATED type value by value. A trailing `..` marks an extensible type.
+macro_rules! upgrade_enumerated {
+    ($from:ident => $to:ident, $type:ident { $($value:ident,)* .. $(,)? }) => {
+        impl Upgrade for $from::$type {
+            type Upgraded = $to::$type;
+            fn upgrade(&self) -> Result<Self::Upgraded, UpgradeError> {
+                match self {
+                    $(Self::$value => Ok($to::$type::$value),)*
+                    Self::Unknown(_) => Err(UpgradeError::UnknownValue { type_name: stringify!($type) }),
+                }
+            }
+
+            fn is_unknown(&self) -> bool {
+                matches!(self, Self::Unknown(_))
+            }
+        }
+    };
+    ($from:ident => $to:ident, $type:ident { $($value:ident),* $(,)? }) => {
+        impl Upgrade for $from::$type {
+            type Upgraded = $to::$type;
+            fn upgrade(&self) -> Result<Self::Upgraded, UpgradeError> {
+                match self {
+                    $(Self::$value => Ok($to::$type::$value),)*
+                }
+            }
+        }
+    };
+}
+
+/// Upgrades a CHOICE type alternative by alternative. A trailing `..` marks an extensible type.
+macro_rules! upgrade_choice {
+    ($from:ident => $to:ident, $type:ident { $($alternative:ident,)* .. $(,)? }) => {
+        impl Upgrade for $from::$type {
+            type Upgraded = $to::$type;
+            fn upgrade(&self) -> Result<Self::Upgraded, UpgradeError> {
+                match self {
+                    $(Self::$alternative(value) => Ok($to::$type::$alternative(value.upgrade()?)),)*
+                    Self::Unknown { .. } => Err(UpgradeError::UnknownValue { type_name: stringify!($type) }),
+                }
+            }
+
+            fn is_unknown(&self) -> bool {
+                matches!(self, Self::Unknown { .. })
+            }
+        }
+    };
+}
+
+
+// version 1 to version 2
+
+upgrade_sequence!(uflex_1 => uflex_2, UicRailTicketData {
+    issuing_detail, traveler_detail, transport_document, control_detail, extension,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, DocumentData { token, ticket, unknown_extensions });
+upgrade_choice!(uflex_1 => uflex_2, DocumentDataTicket {
+    Reservation, CarCarriageReservation, OpenTicket, Pass, Voucher, CustomerCard, CounterMark,
+    ParkingGround, FipTicket, StationPassage, Extension, ..,
+});
+upgrade_sequence!(uflex_1 => uflex_2, IssuingData {
+    security_provider_num, security_provider_ia_5, issuer_num, issuer_ia_5, issuing_year,
+    issuing_day, issuing_time, issuer_name, specimen, secure_paper_ticket, activated, currency,
+    currency_fract, issuer_pnr, extension, issued_on_train_num, issued_on_train_ia_5,
+    issued_on_line, point_of_sale, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, ControlData {
+    identification_by_card_reference, identification_by_id_card, identification_by_passport_id,
+    identification_item, passport_validation_required, online_validation_required,
+    random_detailed_validation_required, age_check_required, reduction_card_check_required,
+    info_text, included_tickets, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, TravelerData {
+    traveler, preferred_language, group_name, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, ReservationData {
+    train_num, train_ia_5, departure_date, reference_ia_5, reference_num, product_owner_num,
+    product_owner_ia_5, product_id_num, product_id_ia_5, service_brand, service_brand_abr_utf_8,
+    service_brand_name_utf_8, service, station_code_table, from_station_num, from_station_ia_5,
+    to_station_num, to_station_ia_5, from_station_name_utf_8, to_station_name_utf_8,
+    departure_time, departure_utc_offset, arrival_date, arrival_time, arrival_utc_offset,
+    carrier_num, carrier_ia_5, class_code, service_level, places, additional_places,
+    bicycle_places, compartment_details, number_of_overbooked, berth, tariff, price_type, price,
+    vat_detail, type_of_supplement, number_of_supplements, luggage, info_text, extension,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, VatDetailType { country, percentage, amount, vat_id });
+upgrade_sequence!(uflex_1 => uflex_2, CarCarriageReservationData {
+    train_num, train_ia_5, begin_loading_date, begin_loading_time, end_loading_time,
+    loading_utc_offset, reference_ia_5, reference_num, product_owner_num, product_owner_ia_5,
+    product_id_num, product_id_ia_5, service_brand, service_brand_abr_utf_8,
+    service_brand_name_utf_8, station_code_table, from_station_num, from_station_ia_5,
+    to_station_num, to_station_ia_5, from_station_name_utf_8, to_station_name_utf_8, coach,
+    place, compartment_details, number_plate, trailer_plate, car_category, boat_category,
+    textile_roof, roof_rack_type, roof_rack_height, attached_boats, attached_bicycles,
+    attached_surfboards, loading_list_entry, loading_deck, carrier_num, carrier_ia_5, tariff,
+    price_type, price, vat_detail, info_text, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, OpenTicketData {
+    reference_num, reference_ia_5, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, ext_issuer_id, issuer_autorization_id, return_included, station_code_table,
+    from_station_num, from_station_ia_5, to_station_num, to_station_ia_5,
+    from_station_name_utf_8, to_station_name_utf_8, valid_region_desc, valid_region,
+    return_description, valid_from_day, valid_from_time, valid_from_utc_offset, valid_until_day,
+    valid_until_time, valid_until_utc_offset, activated_day, class_code, service_level,
+    carrier_num, carrier_ia_5, included_service_brands, excluded_service_brands, tariffs, price,
+    vat_detail, info_text, included_add_ons, luggage, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, PassData {
+    reference_num, reference_ia_5, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, pass_type, pass_description, class_code, valid_from_day, valid_from_time,
+    valid_from_utc_offset, valid_until_day, valid_until_time, valid_until_utc_offset,
+    number_of_validity_days, number_of_possible_trips, number_of_days_of_travel, activated_day,
+    countries, included_carrier_num, included_carrier_ia_5, excluded_carrier_num,
+    excluded_carrier_ia_5, included_service_brands, excluded_service_brands, valid_region,
+    tariffs, price, vat_detail, info_text, extension, unknown_extensions,
+} with {
+    validity_period_details: None,
+});
+upgrade_sequence!(uflex_1 => uflex_2, VoucherData {
+    reference_ia_5, reference_num, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, valid_from_year, valid_from_day, valid_until_year, valid_until_day, value,
+    type_, info_text, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, FipTicketData {
+    reference_ia_5, reference_num, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, valid_from_day, valid_until_day, activated_day, carrier_num, carrier_ia_5,
+    number_of_travel_days, includes_supplements, class_code, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, StationPassageData {
+    reference_ia_5, reference_num, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, product_name, station_code_table, station_num, station_ia_5,
+    station_name_utf_8, area_code_num, area_code_ia_5, area_name_utf_8, valid_from_day,
+    valid_from_time, valid_from_utc_offset, valid_until_day, valid_until_time,
+    valid_until_utc_offset, number_of_days_valid, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, CustomerCardData {
+    customer, card_id_ia_5, card_id_num, valid_from_year, valid_from_day, valid_until_year,
+    valid_until_day, class_code, card_type, card_type_descr, customer_status,
+    customer_status_descr, included_services, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, ParkingGroundData {
+    reference_ia_5, reference_num, parking_ground_id, from_parking_date, until_parking_date,
+    product_owner_num, product_owner_ia_5, product_id_num, product_id_ia_5, access_code,
+    location, station_code_table, station_num, station_ia_5, special_information, entry_track,
+    number_plate, price, vat_detail, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, CountermarkData {
+    reference_ia_5, reference_num, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, ticket_reference_ia_5, ticket_reference_num, number_of_countermark,
+    total_of_countermarks, group_name, station_code_table, from_station_num, from_station_ia_5,
+    to_station_num, to_station_ia_5, from_station_name_utf_8, to_station_name_utf_8,
+    valid_region_desc, valid_region, return_included, return_description, valid_from_day,
+    valid_from_time, valid_from_utc_offset, valid_until_day, valid_until_time,
+    valid_until_utc_offset, class_code, carrier_num, carrier_ia_5, included_service_brands,
+    excluded_service_brands, info_text, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, ExtensionData { extension_id, extension_data });
+upgrade_sequence!(uflex_1 => uflex_2, IncludedOpenTicketType {
+    product_owner_num, product_owner_ia_5, product_id_num, product_id_ia_5, external_issuer_id,
+    issuer_autorization_id, station_code_table, valid_region, valid_from_day, valid_from_time,
+    valid_from_utc_offset, valid_until_day, valid_until_time, valid_until_utc_offset,
+    class_code, service_level, carrier_num, carrier_ia_5, included_service_brands,
+    excluded_service_brands, tariffs, info_text, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, TariffType {
+    number_of_passengers, passenger_type, age_below, age_above, travelerid,
+    restricted_to_country_of_residence, restricted_to_route_section, series_data_details,
+    tariff_id_num, tariff_id_ia_5, tariff_desc, reduction_card, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, SeriesDetailType {
+    supplying_carrier, offer_identification, series,
+});
+upgrade_sequence!(uflex_1 => uflex_2, RouteSectionType {
+    station_code_table, from_station_num, from_station_ia_5, to_station_num, to_station_ia_5,
+    from_station_name_utf_8, to_station_name_utf_8,
+});
+upgrade_sequence!(uflex_1 => uflex_2, CardReferenceType {
+    card_issuer_num, card_issuer_ia_5, card_id_num, card_id_ia_5, card_name, card_type,
+    leading_card_id_num, leading_card_id_ia_5, trailing_card_id_num, trailing_card_id_ia_5,
+    unknown_extensions,
+});
+impl Upgrade for uflex_1::TravelerType {
+    type Upgraded = uflex_2::TravelerType;
+    fn upgrade(&self) -> Result<Self::Upgraded, UpgradeError> {
+        let Self {
+            first_name, second_name, last_name, id_card, passport_id, title, gender, customer_id_ia_5,
+            customer_id_num, year_of_birth, day_of_birth, ticket_holder, passenger_type,
+            passenger_with_reduced_mobility, country_of_residence, country_of_passport,
+            country_of_id_card, status, unknown_extensions,
+        } = self;
+
+        // version 2 replaced the day of the year of birth with the month and the day of the month
+        let month_and_day = day_of_birth.as_ref()
+            .and_then(|day_of_birth| birth_month_and_day(year_of_birth.as_ref(), day_of_birth));
+        let (month_of_birth, day_of_birth_in_month) = match month_and_day {
+            Some((month, day)) => (Some(Integer::from_short(month.into())), Some(Integer::from_short(day.into()))),
+            None => (None, None),
+        };
+
+        Ok(uflex_2::TravelerType {
+            first_name: first_name.upgrade()?,
+            second_name: second_name.upgrade()?,
+            last_name: last_name.upgrade()?,
+            id_card: id_card.upgrade()?,
+            passport_id: passport_id.upgrade()?,
+            title: title.upgrade()?,
+            gender: gender.upgrade()?,
+            customer_id_ia_5: customer_id_ia_5.upgrade()?,
+            customer_id_num: customer_id_num.upgrade()?,
+            year_of_birth: year_of_birth.upgrade()?,
+            month_of_birth,
+            day_of_birth_in_month,
+            ticket_holder: ticket_holder.upgrade()?,
+            passenger_type: passenger_type.upgrade()?,
+            passenger_with_reduced_mobility: passenger_with_reduced_mobility.upgrade()?,
+            country_of_residence: country_of_residence.upgrade()?,
+            country_of_passport: country_of_passport.upgrade()?,
+            country_of_id_card: country_of_id_card.upgrade()?,
+            status: status.upgrade()?,
+            unknown_extensions: unknown_extensions.upgrade()?,
+        })
+    }
+}
+upgrade_sequence!(uflex_1 => uflex_2, CustomerStatusType {
+    status_provider_num, status_provider_ia_5, customer_status, customer_status_descr,
+});
+upgrade_sequence!(uflex_1 => uflex_2, ReturnRouteDescriptionType {
+    from_station_num, from_station_ia_5, to_station_num, to_station_ia_5,
+    from_station_name_utf_8, to_station_name_utf_8, valid_return_region_desc,
+    valid_return_region, unknown_extensions,
+});
+upgrade_choice!(uflex_1 => uflex_2, RegionalValidityType {
+    TrainLink, ViaStations, Zones, Lines, Polygone, ..,
+});
+upgrade_sequence!(uflex_1 => uflex_2, TrainLinkType {
+    train_num, train_ia_5, travel_date, departure_time, departure_utc_offset, from_station_num,
+    from_station_ia_5, to_station_num, to_station_ia_5, from_station_name_utf_8,
+    to_station_name_utf_8,
+});
+upgrade_sequence!(uflex_1 => uflex_2, LineType {
+    carrier_num, carrier_ia_5, line_id, station_code_table, entry_station_num,
+    entry_station_ia_5, terminating_station_num, terminating_station_ia_5, city,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, ZoneType {
+    carrier_num, carrier_ia_5, station_code_table, entry_station_num, entry_station_ia_5,
+    terminating_station_num, terminating_station_ia_5, city, zone_id, binary_zone_id, nuts_code,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, ViaStationType {
+    station_code_table, station_num, station_ia_5, alternative_routes, route, border,
+    carrier_num, carrier_ia_5, series_id, route_id, included_service_brands,
+    excluded_service_brands, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, PolygoneType { first_edge, edges });
+upgrade_sequence!(uflex_1 => uflex_2, TokenType {
+    token_provider_num, token_provider_ia_5, token_specification, token,
+});
+upgrade_sequence!(uflex_1 => uflex_2, TicketLinkType {
+    reference_ia_5, reference_num, issuer_name, issuer_pnr, product_owner_num,
+    product_owner_ia_5, ticket_type, unknown_extensions,
+} with {
+    link_mode: uflex_2::LinkMode::IssuedTogether,
+});
+upgrade_enumerated!(uflex_1 => uflex_2, CodeTableType {
+    StationUic, StationUicReservation, StationEra, LocalCarrierStationCodeTable,
+    ProprietaryIssuerStationCodeTable,
+});
+upgrade_enumerated!(uflex_1 => uflex_2, ServiceType { Seat, Couchette, Berth, Carcarriage });
+upgrade_enumerated!(uflex_1 => uflex_2, PassengerType {
+    Adult, Senior, Child, Youth, Dog, Bicycle, FreeAddonPassenger, FreeAddonChild, ..,
+});
+upgrade_enumerated!(uflex_1 => uflex_2, TicketType {
+    OpenTicket, Pass, Reservation, CarCarriageReservation, ..,
+});
+upgrade_sequence!(uflex_1 => uflex_2, PlacesType {
+    coach, place_string, place_description, place_ia_5, place_num,
+});
+upgrade_enumerated!(uflex_1 => uflex_2, PriceTypeType {
+    NoPrice, ReservationFee, Supplement, TravelPrice,
+});
+upgrade_enumerated!(uflex_1 => uflex_2, BerthTypeType { Single, Special, Double, T2, T3, T4 });
+upgrade_enumerated!(uflex_1 => uflex_2, CompartmentGenderType {
+    Unspecified, Family, Female, Male, Mixed, ..,
+});
+upgrade_enumerated!(uflex_1 => uflex_2, GenderType { Unspecified, Female, Male, Other, .. });
+upgrade_enumerated!(uflex_1 => uflex_2, TravelClassType {
+    NotApplicable, First, Second, Tourist, Comfort, Premium, Business, All, ..,
+});
+upgrade_sequence!(uflex_1 => uflex_2, BerthDetailData {
+    berth_type, number_of_berths, gender, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, CompartmentDetailsType {
+    coach_type, compartment_type, special_allocation, coach_type_descr, compartment_type_descr,
+    special_allocation_descr, position, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, LuggageRestrictionType {
+    max_hand_luggage_pieces, max_non_hand_luggage_pieces, registered_luggage,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, RegisteredLuggageType {
+    registration_id, max_weight, max_size, unknown_extensions,
+});
+upgrade_sequence!(uflex_1 => uflex_2, GeoCoordinateType {
+    geo_unit, coordinate_system, hemisphere_longitude, hemisphere_latitude, longitude, latitude,
+    accuracy,
+});
+upgrade_sequence!(uflex_1 => uflex_2, DeltaCoordinates { longitude, latitude });
+upgrade_enumerated!(uflex_1 => uflex_2, GeoCoordinateSystemType { Wgs84, Grs80 });
+upgrade_enumerated!(uflex_1 => uflex_2, GeoUnitType {
+    MicroDegree, TenthmilliDegree, MilliDegree, CentiDegree, DeciDegree,
+});
+upgrade_enumerated!(uflex_1 => uflex_2, HemisphereLongitudeType { North, South });
+upgrade_enumerated!(uflex_1 => uflex_2, HemisphereLatitudeType { East, West });
+upgrade_enumerated!(uflex_1 => uflex_2, LoadingDeckType { Unspecified, Upper, Lower });
+upgrade_enumerated!(uflex_1 => uflex_2, CompartmentPositionType {
+    Unspecified, UpperLevel, LowerLevel,
+});
+upgrade_enumerated!(uflex_1 => uflex_2, RoofRackType {
+    Norack, RoofRailing, LuggageRack, SkiRack, BoxRack, RackWithOneBox, RackWithTwoBoxes,
+    BicycleRack, OtherRack, ..,
+});
+
+
+// version 2 to version 3
+
+upgrade_sequence!(uflex_2 => uflex_3, UicRailTicketData {
+    issuing_detail, traveler_detail, transport_document, control_detail, extension,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, DocumentData { token, ticket, unknown_extensions });
+upgrade_choice!(uflex_2 => uflex_3, DocumentDataTicket {
+    Reservation, CarCarriageReservation, OpenTicket, Pass, Voucher, CustomerCard, CounterMark,
+    ParkingGround, FipTicket, StationPassage, Extension, DelayConfirmation, ..,
+});
+upgrade_sequence!(uflex_2 => uflex_3, DelayConfirmation {
+    reference_ia_5, reference_num, train_num, train_ia_5, departure_year, departure_day,
+    departure_time, departure_utc_offset, station_code_table, station_num, station_ia_5, delay,
+    train_cancelled, confirmation_type, affected_tickets, info_text, extension,
+    unknown_extensions,
+});
+upgrade_enumerated!(uflex_2 => uflex_3, ConfirmationType {
+    TrainDelayConfirmation, TravelerDelayConfirmation, TrainLinkedTicketDelay, ..,
+});
+upgrade_sequence!(uflex_2 => uflex_3, IssuingData {
+    security_provider_num, security_provider_ia_5, issuer_num, issuer_ia_5, issuing_year,
+    issuing_day, issuing_time, issuer_name, specimen, secure_paper_ticket, activated, currency,
+    currency_fract, issuer_pnr, extension, issued_on_train_num, issued_on_train_ia_5,
+    issued_on_line, point_of_sale, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, ControlData {
+    identification_by_card_reference, identification_by_id_card, identification_by_passport_id,
+    identification_item, passport_validation_required, online_validation_required,
+    random_detailed_validation_required, age_check_required, reduction_card_check_required,
+    info_text, included_tickets, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, TravelerData {
+    traveler, preferred_language, group_name, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, ReservationData {
+    train_num, train_ia_5, departure_date, reference_ia_5, reference_num, product_owner_num,
+    product_owner_ia_5, product_id_num, product_id_ia_5, service_brand, service_brand_abr_utf_8,
+    service_brand_name_utf_8, service, station_code_table, from_station_num, from_station_ia_5,
+    to_station_num, to_station_ia_5, from_station_name_utf_8, to_station_name_utf_8,
+    departure_time, departure_utc_offset, arrival_date, arrival_time, arrival_utc_offset,
+    carrier_num, carrier_ia_5, class_code, service_level, places, additional_places,
+    bicycle_places, compartment_details, number_of_overbooked, berth, tariff, price_type, price,
+    vat_detail, type_of_supplement, number_of_supplements, luggage, info_text, extension,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, VatDetailType { country, percentage, amount, vat_id });
+upgrade_sequence!(uflex_2 => uflex_3, CarCarriageReservationData {
+    train_num, train_ia_5, begin_loading_date, begin_loading_time, end_loading_time,
+    loading_utc_offset, reference_ia_5, reference_num, product_owner_num, product_owner_ia_5,
+    product_id_num, product_id_ia_5, service_brand, service_brand_abr_utf_8,
+    service_brand_name_utf_8, station_code_table, from_station_num, from_station_ia_5,
+    to_station_num, to_station_ia_5, from_station_name_utf_8, to_station_name_utf_8, coach,
+    place, compartment_details, number_plate, trailer_plate, car_category, boat_category,
+    textile_roof, roof_rack_type, roof_rack_height, attached_boats, attached_bicycles,
+    attached_surfboards, loading_list_entry, loading_deck, carrier_num, carrier_ia_5, tariff,
+    price_type, price, vat_detail, info_text, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, OpenTicketData {
+    reference_num, reference_ia_5, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, ext_issuer_id, issuer_autorization_id, return_included, station_code_table,
+    from_station_num, from_station_ia_5, to_station_num, to_station_ia_5,
+    from_station_name_utf_8, to_station_name_utf_8, valid_region_desc, valid_region,
+    return_description, valid_from_day, valid_from_time, valid_from_utc_offset, valid_until_day,
+    valid_until_time, valid_until_utc_offset, activated_day, class_code, service_level,
+    carrier_num, carrier_ia_5, included_service_brands, excluded_service_brands, tariffs, price,
+    vat_detail, info_text, included_add_ons, luggage, extension, unknown_extensions,
+} with {
+    included_transport_type: Vec::new(),
+    excluded_transport_type: Vec::new(),
+});
+upgrade_sequence!(uflex_2 => uflex_3, PassData {
+    reference_num, reference_ia_5, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, pass_type, pass_description, class_code, valid_from_day, valid_from_time,
+    valid_from_utc_offset, valid_until_day, valid_until_time, valid_until_utc_offset,
+    validity_period_details, number_of_validity_days, number_of_possible_trips,
+    number_of_days_of_travel, activated_day, countries, included_carrier_num,
+    included_carrier_ia_5, excluded_carrier_num, excluded_carrier_ia_5, included_service_brands,
+    excluded_service_brands, valid_region, tariffs, price, vat_detail, info_text, extension,
+    unknown_extensions,
+} with {
+    train_validity: None,
+});
+upgrade_sequence!(uflex_2 => uflex_3, ValidityPeriodDetailType {
+    validity_period, excluded_time_range,
+});
+upgrade_sequence!(uflex_2 => uflex_3, ValidityPeriodType {
+    valid_from_day, valid_from_time, valid_from_utc_offset, valid_until_day, valid_until_time,
+    valid_until_utc_offset,
+});
+upgrade_sequence!(uflex_2 => uflex_3, TimeRangeType { from_time, until_time });
+upgrade_sequence!(uflex_2 => uflex_3, VoucherData {
+    reference_ia_5, reference_num, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, valid_from_year, valid_from_day, valid_until_year, valid_until_day, value,
+    type_, info_text, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, FipTicketData {
+    reference_ia_5, reference_num, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, valid_from_day, valid_until_day, activated_day, carrier_num, carrier_ia_5,
+    number_of_travel_days, includes_supplements, class_code, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, StationPassageData {
+    reference_ia_5, reference_num, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, product_name, station_code_table, station_num, station_ia_5,
+    station_name_utf_8, area_code_num, area_code_ia_5, area_name_utf_8, valid_from_day,
+    valid_from_time, valid_from_utc_offset, valid_until_day, valid_until_time,
+    valid_until_utc_offset, number_of_days_valid, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, CustomerCardData {
+    customer, card_id_ia_5, card_id_num, valid_from_year, valid_from_day, valid_until_year,
+    valid_until_day, class_code, card_type, card_type_descr, customer_status,
+    customer_status_descr, included_services, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, ParkingGroundData {
+    reference_ia_5, reference_num, parking_ground_id, from_parking_date, until_parking_date,
+    product_owner_num, product_owner_ia_5, product_id_num, product_id_ia_5, access_code,
+    location, station_code_table, station_num, station_ia_5, special_information, entry_track,
+    number_plate, price, vat_detail, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, CountermarkData {
+    reference_ia_5, reference_num, product_owner_num, product_owner_ia_5, product_id_num,
+    product_id_ia_5, ticket_reference_ia_5, ticket_reference_num, number_of_countermark,
+    total_of_countermarks, group_name, station_code_table, from_station_num, from_station_ia_5,
+    to_station_num, to_station_ia_5, from_station_name_utf_8, to_station_name_utf_8,
+    valid_region_desc, valid_region, return_included, return_description, valid_from_day,
+    valid_from_time, valid_from_utc_offset, valid_until_day, valid_until_time,
+    valid_until_utc_offset, class_code, carrier_num, carrier_ia_5, included_service_brands,
+    excluded_service_brands, info_text, extension, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, ExtensionData { extension_id, extension_data });
+upgrade_sequence!(uflex_2 => uflex_3, IncludedOpenTicketType {
+    product_owner_num, product_owner_ia_5, product_id_num, product_id_ia_5, external_issuer_id,
+    issuer_autorization_id, station_code_table, valid_region, valid_from_day, valid_from_time,
+    valid_from_utc_offset, valid_until_day, valid_until_time, valid_until_utc_offset,
+    class_code, service_level, carrier_num, carrier_ia_5, included_service_brands,
+    excluded_service_brands, tariffs, info_text, extension, unknown_extensions,
+} with {
+    included_transport_type: Vec::new(),
+    excluded_transport_type: Vec::new(),
+});
+upgrade_sequence!(uflex_2 => uflex_3, TariffType {
+    number_of_passengers, passenger_type, age_below, age_above, travelerid,
+    restricted_to_country_of_residence, restricted_to_route_section, series_data_details,
+    tariff_id_num, tariff_id_ia_5, tariff_desc, reduction_card, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, SeriesDetailType {
+    supplying_carrier, offer_identification, series,
+});
+upgrade_sequence!(uflex_2 => uflex_3, RouteSectionType {
+    station_code_table, from_station_num, from_station_ia_5, to_station_num, to_station_ia_5,
+    from_station_name_utf_8, to_station_name_utf_8,
+});
+upgrade_sequence!(uflex_2 => uflex_3, CardReferenceType {
+    card_issuer_num, card_issuer_ia_5, card_id_num, card_id_ia_5, card_name, card_type,
+    leading_card_id_num, leading_card_id_ia_5, trailing_card_id_num, trailing_card_id_ia_5,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, TravelerType {
+    first_name, second_name, last_name, id_card, passport_id, title, gender, customer_id_ia_5,
+    customer_id_num, year_of_birth, month_of_birth, day_of_birth_in_month, ticket_holder,
+    passenger_type, passenger_with_reduced_mobility, country_of_residence, country_of_passport,
+    country_of_id_card, status, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, CustomerStatusType {
+    status_provider_num, status_provider_ia_5, customer_status, customer_status_descr,
+});
+upgrade_sequence!(uflex_2 => uflex_3, ReturnRouteDescriptionType {
+    from_station_num, from_station_ia_5, to_station_num, to_station_ia_5,
+    from_station_name_utf_8, to_station_name_utf_8, valid_return_region_desc,
+    valid_return_region, unknown_extensions,
+});
+upgrade_choice!(uflex_2 => uflex_3, RegionalValidityType {
+    TrainLink, ViaStations, Zones, Lines, Polygone, ..,
+});
+upgrade_sequence!(uflex_2 => uflex_3, TrainLinkType {
+    train_num, train_ia_5, travel_date, departure_time, departure_utc_offset, from_station_num,
+    from_station_ia_5, to_station_num, to_station_ia_5, from_station_name_utf_8,
+    to_station_name_utf_8,
+});
+upgrade_sequence!(uflex_2 => uflex_3, LineType {
+    carrier_num, carrier_ia_5, line_id, station_code_table, entry_station_num,
+    entry_station_ia_5, terminating_station_num, terminating_station_ia_5, city,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, ZoneType {
+    carrier_num, carrier_ia_5, station_code_table, entry_station_num, entry_station_ia_5,
+    terminating_station_num, terminating_station_ia_5, city, zone_id, binary_zone_id, nuts_code,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, ViaStationType {
+    station_code_table, station_num, station_ia_5, alternative_routes, route, border,
+    carrier_num, carrier_ia_5, series_id, route_id, included_service_brands,
+    excluded_service_brands, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, PolygoneType { first_edge, edges });
+upgrade_sequence!(uflex_2 => uflex_3, TokenType {
+    token_provider_num, token_provider_ia_5, token_specification, token,
+});
+upgrade_sequence!(uflex_2 => uflex_3, TicketLinkType {
+    reference_ia_5, reference_num, issuer_name, issuer_pnr, product_owner_num,
+    product_owner_ia_5, ticket_type, link_mode, unknown_extensions,
+});
+upgrade_enumerated!(uflex_2 => uflex_3, CodeTableType {
+    StationUic, StationUicReservation, StationEra, LocalCarrierStationCodeTable,
+    ProprietaryIssuerStationCodeTable,
+});
+upgrade_enumerated!(uflex_2 => uflex_3, ServiceType { Seat, Couchette, Berth, Carcarriage });
+upgrade_enumerated!(uflex_2 => uflex_3, PassengerType {
+    Adult, Senior, Child, Youth, Dog, Bicycle, FreeAddonPassenger, FreeAddonChild, ..,
+});
+upgrade_enumerated!(uflex_2 => uflex_3, TicketType {
+    OpenTicket, Pass, Reservation, CarCarriageReservation, ..,
+});
+upgrade_enumerated!(uflex_2 => uflex_3, LinkMode { IssuedTogether, OnlyValidInCombination, .. });
+upgrade_sequence!(uflex_2 => uflex_3, PlacesType {
+    coach, place_string, place_description, place_ia_5, place_num,
+});
+upgrade_enumerated!(uflex_2 => uflex_3, PriceTypeType {
+    NoPrice, ReservationFee, Supplement, TravelPrice,
+});
+upgrade_enumerated!(uflex_2 => uflex_3, BerthTypeType { Single, Special, Double, T2, T3, T4 });
+upgrade_enumerated!(uflex_2 => uflex_3, CompartmentGenderType {
+    Unspecified, Family, Female, Male, Mixed, ..,
+});
+upgrade_enumerated!(uflex_2 => uflex_3, GenderType { Unspecified, Female, Male, Other, .. });
+upgrade_enumerated!(uflex_2 => uflex_3, TravelClassType {
+    NotApplicable, First, Second, Tourist, Comfort, Premium, Business, All, ..,
+});
+upgrade_sequence!(uflex_2 => uflex_3, BerthDetailData {
+    berth_type, number_of_berths, gender, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, CompartmentDetailsType {
+    coach_type, compartment_type, special_allocation, coach_type_descr, compartment_type_descr,
+    special_allocation_descr, position, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, LuggageRestrictionType {
+    max_hand_luggage_pieces, max_non_hand_luggage_pieces, registered_luggage,
+    unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, RegisteredLuggageType {
+    registration_id, max_weight, max_size, unknown_extensions,
+});
+upgrade_sequence!(uflex_2 => uflex_3, GeoCoordinateType {
+    geo_unit, coordinate_system, hemisphere_longitude, hemisphere_latitude, longitude, latitude,
+    accuracy,
+});
+upgrade_sequence!(uflex_2 => uflex_3, DeltaCoordinates { longitude, latitude });
+upgrade_enumerated!(uflex_2 => uflex_3, GeoCoordinateSystemType { Wgs84, Grs80 });
+upgrade_enumerated!(uflex_2 => uflex_3, GeoUnitType {
+    MicroDegree, TenthmilliDegree, MilliDegree, CentiDegree, DeciDegree,
+});
+upgrade_enumerated!(uflex_2 => uflex_3, HemisphereLongitudeType { North, South });
+upgrade_enumerated!(uflex_2 => uflex_3, HemisphereLatitudeType { East, West });
+upgrade_enumerated!(uflex_2 => uflex_3, LoadingDeckType { Unspecified, Upper, Lower });
+upgrade_enumerated!(uflex_2 => uflex_3, CompartmentPositionType {
+    Unspecified, UpperLevel, LowerLevel,
+});
+upgrade_enumerated!(uflex_2 => uflex_3, RoofRackType {
+    Norack, RoofRailing, LuggageRack, SkiRack, BoxRack, RackWithOneBox, RackWithTwoBoxes,
+    BicycleRack, OtherRack, ..,
+});
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asn1_uper::{BitSlice, BitVec};
+
+    #[test]
+    fn test_upgrade_v1() {
+        let data: uflex_1::UicRailTicketData = serde_json::from_value(serde_json::json!({
+            "issuing_detail": {
+                "security_provider_num": "1181", "issuer_num": "1181", "issuing_year": "2019",
+                "issuing_day": "42", "issuing_time": "600", "specimen": true,
+                "secure_paper_ticket": false, "activated": true, "currency": "EUR",
+                "currency_fract": "2",
+            },
+            "traveler_detail": {
+                "traveler": [
+                    { "last_name": "Mustermann", "year_of_birth": "1980", "day_of_birth": "100", "ticket_holder": true },
+                    { "last_name": "Musterfrau", "day_of_birth": "45", "ticket_holder": false },
+                    { "last_name": "Musterkind", "day_of_birth": "200", "ticket_holder": false },
+                ],
+            },
+            "control_detail": {
+                "identification_by_id_card": false, "identification_by_passport_id": false,
+                "passport_validation_required": false, "online_validation_required": false,
+                "age_check_required": false, "reduction_card_check_required": false,
+                "included_tickets": [{ "reference_ia_5": "ABC123", "ticket_type": "OpenTicket" }],
+            },
+        })).unwrap();
+
+        let upgraded = data.upgrade_to_v3().unwrap();
+        let travelers = &upgraded.ticket_data.traveler_detail.as_ref().unwrap().traveler;
+        let birth_dates: Vec<_> = travelers.iter()
+            .map(|traveler| (traveler.year_of_birth.clone(), traveler.month_of_birth.clone(), traveler.day_of_birth_in_month.clone()))
+            .collect();
+        assert_eq!(birth_dates, [
+            (Some(Integer::from_short(1980)), Some(Integer::from_short(4)), Some(Integer::from_short(9))),
+            (None, Some(Integer::from_short(2)), Some(Integer::from_short(14))),
+            (None, None, None),
+        ]);
+        let included_tickets = &upgraded.ticket_data.control_detail.as_ref().unwrap().included_tickets;
+        assert_eq!(included_tickets[0].link_mode, uflex_3::LinkMode::IssuedTogether);
+        assert_eq!(included_tickets[0].reference_ia_5.as_deref(), Some("ABC123"));
+
+        let messages: Vec<String> = upgraded.losses.iter()
+            .map(|loss| loss.to_string())
+            .collect();
+        assert_eq!(messages, [
+            "travelerDetail.traveler[2].dayOfBirth: value 200 cannot be represented in version 3 and has been dropped",
+        ]);
+
+        // the result can be encoded as a U_FLEX version 3 record
+        let mut encoded = BitVec::new();
+        upgraded.ticket_data.write_uper(&mut encoded).unwrap();
+        let (_rest, decoded) = uflex_3::UicRailTicketData::try_from_uper(encoded.as_slice()).unwrap();
+        assert_eq!(decoded, upgraded.ticket_data);
+    }
+
+    #[test]
+    fn test_upgrade_v2() {
+        /// The U_FLEX payload of a specimen open ticket using no members added in version 3.
+        const OPEN_TICKET: &[u8] = include_bytes!("../tests/data/open_ticket.uper");
+
+        let (_rest, data_v3) = uflex_3::UicRailTicketData::try_from_uper(BitSlice::new(OPEN_TICKET)).unwrap();
+        let data: uflex_2::UicRailTicketData = serde_json::from_value(serde_json::to_value(&data_v3).unwrap()).unwrap();
+
+        let upgraded = data.upgrade_to_v3().unwrap();
+        assert_eq!(upgraded.losses, Vec::new());
+        assert_eq!(upgraded.ticket_data, data_v3);
+    }
+
+    #[test]
+    fn test_upgrade_unknown_values() {
+        let mut json = serde_json::json!({
+            "issuing_detail": {
+                "issuing_year": "2023", "issuing_day": "150", "issuing_time": "600", "specimen": true,
+                "secure_paper_ticket": false, "activated": true, "currency": "EUR",
+                "currency_fract": "2", "unknown_extensions": [null, [0xAB]],
+            },
+            "traveler_detail": {
+                "traveler": [
+                    { "last_name": "Mustermann", "gender": { "Unknown": 0 }, "ticket_holder": true },
+                ],
+            },
+            "control_detail": {
+                "identification_by_id_card": false, "identification_by_passport_id": false,
+                "passport_validation_required": false, "online_validation_required": false,
+                "age_check_required": false, "reduction_card_check_required": false,
+                "included_tickets": [{ "reference_ia_5": "ABC123", "ticket_type": "OpenTicket", "link_mode": "IssuedTogether" }],
+            },
+        });
+        let data: uflex_2::UicRailTicketData = serde_json::from_value(json.clone()).unwrap();
+
+        // unknown values are neither carried over nor passed off as a known value of version 3
+        let upgraded = data.upgrade_to_v3().unwrap();
+        assert_eq!(upgraded.ticket_data.issuing_detail.unknown_extensions, UnknownExtensions::default());
+        let traveler = &upgraded.ticket_data.traveler_detail.as_ref().unwrap().traveler[0];
+        assert_eq!(traveler.gender, None);
+        assert_eq!(traveler.last_name.as_deref(), Some("Mustermann"));
+
+        let messages: Vec<String> = upgraded.losses.iter()
+            .map(|loss| loss.to_string())
+            .collect();
+        assert_eq!(messages, [
+            "issuingDetail: value (unknown extension addition 1: 'AB'H) cannot be represented in version 3 and has been dropped",
+            "travelerDetail.traveler[0].gender: value (unknown extension value 0) cannot be represented in version 3 and has been dropped",
+        ]);
+
+        // an unknown value of a mandatory member cannot be dropped
+        json["control_detail"]["included_tickets"][0]["link_mode"] = serde_json::json!({ "Unknown": 2 });
+        let data: uflex_2::UicRailTicketData = serde_json::from_value(json).unwrap();
+        assert_eq!(data.upgrade_to_v3(), Err(UpgradeError::UnknownValue { type_name: "LinkMode" }));
+    }
+}
@@ -0,0 +1,341 @@
+//! U_HEAD (ticket header) records.
+//!
+//! Specified in ERA TAP TSI B.7 § 8.3:
+//! https://www.era.europa.eu/system/files/2022-11/era_technical_document_tap_b_7_v1.3.0.pdf
+
+
+use std::fmt::{self, Write as _};
+use std::ops::{BitAnd, BitOr};
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::records::{RecordError, RecordValue};
+
+
+/// The length of the data of a U_HEAD record, version 1.
+pub const RECORD_DATA_LENGTH: usize = 41;
+
+/// The format of the time of issuance (`DDMMYYYYHHMM`).
+const TIME_OF_ISSUANCE_FORMAT: &str = "%d%m%Y%H%M";
+
+
+/// An error that may occur when decoding or encoding a U_HEAD record.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum UHeadError {
+    /// The record data has the wrong length.
+    WrongLength { length: usize },
+
+    /// A field contains characters other than ASCII.
+    NonAsciiField { field: &'static str, bytes: Vec<u8> },
+
+    /// A field has the wrong length.
+    WrongFieldLength { field: &'static str, value: String, expected_length: usize },
+
+    /// The time of issuance is not a valid `DDMMYYYYHHMM` timestamp.
+    InvalidTimeOfIssuance { value: String },
+
+    /// The flags are not a single decimal digit between 0 and 7.
+    InvalidFlags { value: String },
+}
+impl fmt::Display for UHeadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongLength { length }
+                => write!(f, "U_HEAD record data is {} bytes long, expected {}", length, RECORD_DATA_LENGTH),
+            Self::NonAsciiField { field, bytes }
+                => write!(f, "U_HEAD {} field contains non-ASCII bytes {:?}", field, bytes),
+            Self::WrongFieldLength { field, value, expected_length }
+                => write!(f, "U_HEAD {} field {:?} is not {} characters long", field, value, expected_length),
+            Self::InvalidTimeOfIssuance { value }
+                => write!(f, "U_HEAD time of issuance {:?} is not a valid DDMMYYYYHHMM timestamp", value),
+            Self::InvalidFlags { value }
+                => write!(f, "U_HEAD flags {:?} are not a digit between 0 and 7", value),
+        }
+    }
+}
+impl std::error::Error for UHeadError {
+}
+
+
+/// The flags of a U_HEAD record.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub struct UHeadFlags(u8);
+impl UHeadFlags {
+    /// The ticket is valid internationally.
+    pub const INTERNATIONAL: Self = Self(0b001);
+
+    /// The ticket has been edited by an agent.
+    pub const EDITED_BY_AGENT: Self = Self(0b010);
+
+    /// The ticket is a specimen.
+    pub const SPECIMEN: Self = Self(0b100);
+
+    /// All known flags.
+    pub const ALL: Self = Self(0b111);
+
+    /// Returns a value with no flags set.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns the flags from their bit representation, or `None` if unknown bits are set.
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        if bits & !Self::ALL.0 == 0 {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the bit representation of the flags.
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether no flags are set.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns whether all flags in `other` are also set in `self`.
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Sets or clears the flags in `other`.
+    pub fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.0 |= other.0;
+        } else {
+            self.0 &= !other.0;
+        }
+    }
+}
+impl TryFrom<u8> for UHeadFlags {
+    type Error = UHeadError;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_bits(value)
+            .ok_or_else(|| UHeadError::InvalidFlags { value: value.to_string() })
+    }
+}
+impl From<UHeadFlags> for u8 {
+    fn from(value: UHeadFlags) -> Self { value.bits() }
+}
+impl BitOr for UHeadFlags {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output { Self(self.0 | rhs.0) }
+}
+impl BitAnd for UHeadFlags {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output { Self(self.0 & rhs.0) }
+}
+
+
+/// A U_HEAD record, version 1.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct UHead {
+    /// The RICS code of the distributing railway undertaking (4 characters).
+    pub distributing_ru: String,
+
+    /// The unique key of the ticket (20 characters).
+    pub ticket_key: String,
+
+    /// The time of issuance of the ticket (encoded in the format `DDMMYYYYHHMM`).
+    pub time_of_issuance: NaiveDateTime,
+
+    /// The flags of the ticket (encoded as a single decimal digit).
+    pub flags: UHeadFlags,
+
+    /// The language of the ticket as an ISO 639-1 code (2 characters).
+    pub language: String,
+
+    /// The second language of the ticket as an ISO 639-1 code (2 characters).
+    pub second_language: String,
+}
+impl UHead {
+    /// Decodes a U_HEAD record from its data (excluding the record ID, version and length).
+    pub fn from_bytes(record_data: &[u8]) -> Result<Self, UHeadError> {
+        if record_data.len() != RECORD_DATA_LENGTH {
+            return Err(UHeadError::WrongLength { length: record_data.len() });
+        }
+
+        let distributing_ru = ascii_field("distributing RU", &record_data[0..4])?;
+        let ticket_key = ascii_field("ticket key", &record_data[4..24])?;
+
+        let time_of_issuance_str = ascii_field("time of issuance", &record_data[24..36])?;
+        let time_of_issuance = parse_time_of_issuance(&time_of_issuance_str)
+            .ok_or(UHeadError::InvalidTimeOfIssuance { value: time_of_issuance_str })?;
+
+        let flags_str = ascii_field("flags", &record_data[36..37])?;
+        let flags = flags_str.parse::<u8>().ok()
+            .and_then(UHeadFlags::from_bits)
+            .ok_or(UHeadError::InvalidFlags { value: flags_str })?;
+
+        let language = ascii_field("language", &record_data[37..39])?;
+        let second_language = ascii_field("second language", &record_data[39..41])?;
+
+        Ok(Self {
+            distributing_ru,
+            ticket_key,
+            time_of_issuance,
+            flags,
+            language,
+            second_language,
+        })
+    }
+
+    /// Encodes this U_HEAD record into its data (excluding the record ID, version and length).
+    pub fn to_bytes(&self) -> Result<Vec<u8>, UHeadError> {
+        let time_of_issuance = self.time_of_issuance.format(TIME_OF_ISSUANCE_FORMAT).to_string();
+        if time_of_issuance.len() != 12 {
+            // years before 1000 or after 9999
+            return Err(UHeadError::InvalidTimeOfIssuance { value: time_of_issuance });
+        }
+
+        let mut ret = Vec::with_capacity(RECORD_DATA_LENGTH);
+        write_field(&mut ret, "distributing RU", &self.distributing_ru, 4)?;
+        write_field(&mut ret, "ticket key", &self.ticket_key, 20)?;
+        ret.extend(time_of_issuance.as_bytes());
+        ret.push(b'0' + self.flags.bits());
+        write_field(&mut ret, "language", &self.language, 2)?;
+        write_field(&mut ret, "second language", &self.second_language, 2)?;
+        Ok(ret)
+    }
+}
+impl RecordValue for UHead {
+    fn describe(&self) -> String {
+        let mut ret = String::new();
+        writeln!(ret, "distributing RU: {:?}", self.distributing_ru).unwrap();
+        writeln!(ret, "ticket key: {:?}", self.ticket_key).unwrap();
+        writeln!(ret, "time of issuance: {}", self.time_of_issuance).unwrap();
+
+        writeln!(ret, "flags: {}", self.flags.bits()).unwrap();
+        if self.flags.is_empty() {
+            writeln!(ret, "  no flags set").unwrap();
+        } else {
+            if self.flags.contains(UHeadFlags::INTERNATIONAL) {
+                writeln!(ret, "  international ticket").unwrap();
+            }
+            if self.flags.contains(UHeadFlags::EDITED_BY_AGENT) {
+                writeln!(ret, "  edited by agent").unwrap();
+            }
+            if self.flags.contains(UHeadFlags::SPECIMEN) {
+                writeln!(ret, "  specimen").unwrap();
+            }
+        }
+
+        writeln!(ret, "language: {:?}", self.language).unwrap();
+        writeln!(ret, "second language: {:?}", self.second_language).unwrap();
+        ret
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, RecordError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn to_record_data(&self) -> Result<Vec<u8>, RecordError> {
+        Ok(self.to_bytes()?)
+    }
+}
+
+
+/// Decodes a U_HEAD record, version 1, for the record decoder registry.
+pub fn decode_record(record_data: &[u8]) -> Result<Box<dyn RecordValue>, RecordError> {
+    Ok(Box::new(UHead::from_bytes(record_data)?))
+}
+
+
+fn ascii_field(field: &'static str, bytes: &[u8]) -> Result<String, UHeadError> {
+    if !bytes.is_ascii() {
+        return Err(UHeadError::NonAsciiField { field, bytes: bytes.to_vec() });
+    }
+    Ok(bytes.iter().map(|&b| char::from(b)).collect())
+}
+
+
+fn parse_time_of_issuance(value: &str) -> Option<NaiveDateTime> {
+    if !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    NaiveDateTime::parse_from_str(value, TIME_OF_ISSUANCE_FORMAT).ok()
+}
+
+
+fn write_field(buf: &mut Vec<u8>, field: &'static str, value: &str, expected_length: usize) -> Result<(), UHeadError> {
+    if !value.is_ascii() {
+        return Err(UHeadError::NonAsciiField { field, bytes: value.as_bytes().to_vec() });
+    }
+    if value.len() != expected_length {
+        return Err(UHeadError::WrongFieldLength { field, value: value.to_owned(), expected_length });
+    }
+    buf.extend(value.as_bytes());
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::NaiveDate;
+
+    const PREFIX: &[u8] = b"1181ABCDEFGHIJ0123456789300520231000";
+
+    #[test]
+    fn test_round_trip() {
+        let data = [PREFIX, b"5deen"].concat();
+
+        let uhead = UHead::from_bytes(&data).unwrap();
+        assert_eq!(uhead.distributing_ru, "1181");
+        assert_eq!(uhead.ticket_key, "ABCDEFGHIJ0123456789");
+        assert_eq!(
+            uhead.time_of_issuance,
+            NaiveDate::from_ymd_opt(2023, 5, 30).unwrap().and_hms_opt(10, 0, 0).unwrap(),
+        );
+        assert_eq!(uhead.flags, UHeadFlags::INTERNATIONAL | UHeadFlags::SPECIMEN);
+        assert!(!uhead.flags.contains(UHeadFlags::EDITED_BY_AGENT));
+        assert_eq!(uhead.language, "de");
+        assert_eq!(uhead.second_language, "en");
+
+        assert_eq!(uhead.to_bytes().unwrap(), data);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            UHead::from_bytes(PREFIX),
+            Err(UHeadError::WrongLength { length: 36 }),
+        ));
+        assert!(matches!(
+            UHead::from_bytes(&[PREFIX, b"8deen"].concat()),
+            Err(UHeadError::InvalidFlags { .. }),
+        ));
+        assert!(matches!(
+            UHead::from_bytes(&[&PREFIX[..24], b"310220231000", b"0deen"].concat()),
+            Err(UHeadError::InvalidTimeOfIssuance { .. }),
+        ));
+
+        let mut uhead = UHead::from_bytes(&[PREFIX, b"0deen"].concat()).unwrap();
+        uhead.ticket_key = "SHORT".to_owned();
+        assert!(matches!(
+            uhead.to_bytes(),
+            Err(UHeadError::WrongFieldLength { field: "ticket key", expected_length: 20, .. }),
+        ));
+    }
+
+    #[test]
+    fn test_json_flags() {
+        let uhead = UHead::from_bytes(&[PREFIX, b"5deen"].concat()).unwrap();
+        let mut json = serde_json::to_value(&uhead).unwrap();
+        assert_eq!(json["flags"], serde_json::json!(5));
+        assert_eq!(serde_json::from_value::<UHead>(json.clone()).unwrap(), uhead);
+
+        for unknown_flags in [8, 9, 208, 255] {
+            json["flags"] = serde_json::json!(unknown_flags);
+            assert!(serde_json::from_value::<UHead>(json.clone()).is_err());
+        }
+    }
+}
@@ -0,0 +1,316 @@
+//! U_TLAY (ticket layout) records.
+//!
+//! Specified in ERA TAP TSI B.7 § 8.4:
+//! https://www.era.europa.eu/system/files/2022-11/era_technical_document_tap_b_7_v1.3.0.pdf
+
+
+use std::fmt::{self, Write as _};
+
+use serde::{Deserialize, Serialize};
+
+use crate::records::{RecordError, RecordValue};
+use crate::utlay_painter::{Canvas, CanvasInABox, Record};
+
+
+/// An error that may occur when decoding or encoding a U_TLAY record.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum UTlayError {
+    /// The record data ends within a field.
+    Truncated { offset: usize, field: &'static str, expected: usize, available: usize },
+
+    /// The layout standard contains characters other than ASCII.
+    NonAsciiLayoutStandard { offset: usize, bytes: Vec<u8> },
+
+    /// A numeric field contains characters other than decimal digits.
+    NonNumericField { offset: usize, field: &'static str, bytes: Vec<u8> },
+
+    /// The text of a field is not valid UTF-8.
+    InvalidUtf8Text { offset: usize, bytes: Vec<u8> },
+
+    /// The record data continues after the last field.
+    TrailingData { offset: usize, length: usize },
+
+    /// The layout standard is not four ASCII characters long.
+    InvalidLayoutStandard { value: String },
+
+    /// A value does not fit into its numeric field.
+    ValueTooLarge { field: &'static str, value: usize, digits: usize },
+}
+impl fmt::Display for UTlayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated { offset, field, expected, available }
+                => write!(f, "U_TLAY {} at offset {} requires {} bytes but only {} are available", field, offset, expected, available),
+            Self::NonAsciiLayoutStandard { offset, bytes }
+                => write!(f, "U_TLAY layout standard at offset {} contains non-ASCII bytes {:?}", offset, bytes),
+            Self::NonNumericField { offset, field, bytes }
+                => write!(f, "U_TLAY {} at offset {} is not a decimal number: {:?}", field, offset, bytes),
+            Self::InvalidUtf8Text { offset, bytes }
+                => write!(f, "U_TLAY field text at offset {} is not valid UTF-8: {:?}", offset, bytes),
+            Self::TrailingData { offset, length }
+                => write!(f, "U_TLAY record has {} bytes of trailing data at offset {}", length, offset),
+            Self::InvalidLayoutStandard { value }
+                => write!(f, "U_TLAY layout standard {:?} is not four ASCII characters", value),
+            Self::ValueTooLarge { field, value, digits }
+                => write!(f, "U_TLAY {} {} does not fit into {} digits", field, value, digits),
+        }
+    }
+}
+impl std::error::Error for UTlayError {
+}
+
+
+/// A U_TLAY record, version 1.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct UTlay {
+    /// The layout standard, e.g. `RCT2` (4 characters).
+    pub layout_standard: String,
+
+    /// The fields of the layout (encoded with their count as a 4-digit number).
+    pub fields: Vec<UTlayField>,
+}
+
+/// A single field of a U_TLAY record.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct UTlayField {
+    /// The line at which the field starts (2 digits).
+    pub line: u8,
+
+    /// The column at which the field starts (2 digits).
+    pub column: u8,
+
+    /// The height of the field in lines (2 digits).
+    pub height: u8,
+
+    /// The width of the field in columns (2 digits).
+    pub width: u8,
+
+    /// The formatting of the field (1 digit).
+    pub formatting: u8,
+
+    /// The text of the field (encoded with its length in bytes as a 4-digit number).
+    pub text: String,
+}
+
+impl UTlay {
+    /// Decodes a U_TLAY record from its data (excluding the record ID, version and length).
+    pub fn from_bytes(record_data: &[u8]) -> Result<Self, UTlayError> {
+        let mut reader = Reader { data: record_data, offset: 0 };
+
+        let layout_standard_offset = reader.offset;
+        let layout_standard_bytes = reader.take("layout standard", 4)?;
+        if !layout_standard_bytes.is_ascii() {
+            return Err(UTlayError::NonAsciiLayoutStandard {
+                offset: layout_standard_offset,
+                bytes: layout_standard_bytes.to_vec(),
+            });
+        }
+        let layout_standard = layout_standard_bytes.iter().map(|&b| char::from(b)).collect();
+
+        let field_count = reader.take_number("number of fields", 4)?;
+        let mut fields = Vec::with_capacity(field_count.min(record_data.len() / 13));
+        for _ in 0..field_count {
+            let line = reader.take_number("field line", 2)?;
+            let column = reader.take_number("field column", 2)?;
+            let height = reader.take_number("field height", 2)?;
+            let width = reader.take_number("field width", 2)?;
+            let formatting = reader.take_number("field formatting", 1)?;
+            let text_length = reader.take_number("field text length", 4)?;
+
+            let text_offset = reader.offset;
+            let text_bytes = reader.take("field text", text_length)?;
+            let text = String::from_utf8(text_bytes.to_vec())
+                .map_err(|e| UTlayError::InvalidUtf8Text { offset: text_offset, bytes: e.into_bytes() })?;
+
+            // two-digit numbers always fit into a u8
+            fields.push(UTlayField {
+                line: line.try_into().unwrap(),
+                column: column.try_into().unwrap(),
+                height: height.try_into().unwrap(),
+                width: width.try_into().unwrap(),
+                formatting: formatting.try_into().unwrap(),
+                text,
+            });
+        }
+
+        if reader.offset < record_data.len() {
+            return Err(UTlayError::TrailingData {
+                offset: reader.offset,
+                length: record_data.len() - reader.offset,
+            });
+        }
+
+        Ok(Self {
+            layout_standard,
+            fields,
+        })
+    }
+
+    /// Encodes this U_TLAY record into its data (excluding the record ID, version and length).
+    pub fn to_bytes(&self) -> Result<Vec<u8>, UTlayError> {
+        if !self.layout_standard.is_ascii() || self.layout_standard.len() != 4 {
+            return Err(UTlayError::InvalidLayoutStandard { value: self.layout_standard.clone() });
+        }
+
+        let mut ret = Vec::new();
+        ret.extend(self.layout_standard.as_bytes());
+        write_number(&mut ret, "number of fields", self.fields.len(), 4)?;
+        for field in &self.fields {
+            write_number(&mut ret, "field line", field.line.into(), 2)?;
+            write_number(&mut ret, "field column", field.column.into(), 2)?;
+            write_number(&mut ret, "field height", field.height.into(), 2)?;
+            write_number(&mut ret, "field width", field.width.into(), 2)?;
+            write_number(&mut ret, "field formatting", field.formatting.into(), 1)?;
+            write_number(&mut ret, "field text length", field.text.len(), 4)?;
+            ret.extend(field.text.as_bytes());
+        }
+        Ok(ret)
+    }
+
+    /// Paints the fields of this layout onto a canvas.
+    pub fn paint(&self) -> Canvas {
+        let mut canvas = Canvas::new();
+        for field in &self.fields {
+            let record = Record {
+                line: field.line.into(),
+                column: field.column.into(),
+                width: field.width.into(),
+                height: field.height.into(),
+                text: field.text.clone(),
+            };
+            canvas.paint_record(&record);
+        }
+        canvas
+    }
+}
+impl RecordValue for UTlay {
+    fn describe(&self) -> String {
+        let mut ret = String::new();
+        writeln!(ret, "layout standard: {:?}", self.layout_standard).unwrap();
+        writeln!(ret, "number of fields: {}", self.fields.len()).unwrap();
+        for (i, field) in self.fields.iter().enumerate() {
+            writeln!(ret, "field {}:", i).unwrap();
+            writeln!(ret, "  line: {}", field.line).unwrap();
+            writeln!(ret, "  column: {}", field.column).unwrap();
+            writeln!(ret, "  height: {}", field.height).unwrap();
+            writeln!(ret, "  width: {}", field.width).unwrap();
+            writeln!(ret, "  formatting: {}", field.formatting).unwrap();
+            writeln!(ret, "  text: {:?}", field.text).unwrap();
+        }
+        if !self.fields.is_empty() {
+            let canvas = self.paint();
+            writeln!(ret, "{}", CanvasInABox(&canvas)).unwrap();
+        }
+        ret
+    }
+
+    fn to_json(&self) -> Result<serde_json::Value, RecordError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn to_record_data(&self) -> Result<Vec<u8>, RecordError> {
+        Ok(self.to_bytes()?)
+    }
+}
+
+
+/// Decodes a U_TLAY record, version 1, for the record decoder registry.
+pub fn decode_record(record_data: &[u8]) -> Result<Box<dyn RecordValue>, RecordError> {
+    Ok(Box::new(UTlay::from_bytes(record_data)?))
+}
+
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+impl<'a> Reader<'a> {
+    fn take(&mut self, field: &'static str, length: usize) -> Result<&'a [u8], UTlayError> {
+        let available = self.data.len() - self.offset;
+        if available < length {
+            return Err(UTlayError::Truncated { offset: self.offset, field, expected: length, available });
+        }
+        let ret = &self.data[self.offset..self.offset+length];
+        self.offset += length;
+        Ok(ret)
+    }
+
+    fn take_number(&mut self, field: &'static str, digits: usize) -> Result<usize, UTlayError> {
+        let offset = self.offset;
+        let bytes = self.take(field, digits)?;
+        if !bytes.iter().all(|b| b.is_ascii_digit()) {
+            return Err(UTlayError::NonNumericField { offset, field, bytes: bytes.to_vec() });
+        }
+        Ok(bytes.iter().fold(0, |acc, b| acc * 10 + usize::from(b - b'0')))
+    }
+}
+
+
+fn write_number(buf: &mut Vec<u8>, field: &'static str, value: usize, digits: usize) -> Result<(), UTlayError> {
+    let formatted = format!("{:0width$}", value, width = digits);
+    if formatted.len() != digits {
+        return Err(UTlayError::ValueTooLarge { field, value, digits });
+    }
+    buf.extend(formatted.as_bytes());
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let data = b"RCT200020001012000005Hello0101032010007Gr\xC3\xBC\xC3\x9Fe";
+        let utlay = UTlay::from_bytes(data).unwrap();
+        assert_eq!(utlay.layout_standard, "RCT2");
+        assert_eq!(utlay.fields.len(), 2);
+        assert_eq!(utlay.fields[0].line, 0);
+        assert_eq!(utlay.fields[0].column, 1);
+        assert_eq!(utlay.fields[0].height, 1);
+        assert_eq!(utlay.fields[0].width, 20);
+        assert_eq!(utlay.fields[0].formatting, 0);
+        assert_eq!(utlay.fields[0].text, "Hello");
+        assert_eq!(utlay.fields[1].formatting, 1);
+        assert_eq!(utlay.fields[1].text, "Grüße");
+        assert_eq!(utlay.to_bytes().unwrap(), data);
+        assert_eq!(utlay.paint().as_vec()[1], " Grüße".chars().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            UTlay::from_bytes(b"RCT2000"),
+            Err(UTlayError::Truncated { offset: 4, expected: 4, available: 3, .. }),
+        ));
+        assert!(matches!(
+            UTlay::from_bytes(b"RCT200010001012x00005Hello"),
+            Err(UTlayError::NonNumericField { offset: 14, field: "field width", .. }),
+        ));
+        assert!(matches!(
+            UTlay::from_bytes(b"RCT200010001012000006Hello"),
+            Err(UTlayError::Truncated { offset: 21, expected: 6, available: 5, .. }),
+        ));
+        assert!(matches!(
+            UTlay::from_bytes(b"RCT200010001012000002\xC3("),
+            Err(UTlayError::InvalidUtf8Text { offset: 21, .. }),
+        ));
+        assert!(matches!(
+            UTlay::from_bytes(b"RCT20000x"),
+            Err(UTlayError::TrailingData { offset: 8, length: 1 }),
+        ));
+
+        let utlay = UTlay {
+            layout_standard: "RCT2".to_owned(),
+            fields: vec![UTlayField {
+                line: 100, column: 0, height: 1, width: 1, formatting: 0, text: String::new(),
+            }],
+        };
+        assert!(matches!(
+            utlay.to_bytes(),
+            Err(UTlayError::ValueTooLarge { field: "field line", value: 100, digits: 2 }),
+        ));
+    }
+}
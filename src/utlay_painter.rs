@@ -5,7 +5,7 @@ use std::fmt;
 
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub(crate) struct Record {
+pub struct Record {
     pub line: usize,
     pub column: usize,
     pub width: usize,
@@ -15,7 +15,7 @@ pub(crate) struct Record {
 
 
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub(crate) struct Canvas {
+pub struct Canvas {
     canvas: Vec<Vec<char>>,
 }
 impl Canvas {
@@ -76,7 +76,7 @@ fn row_to_letter(row: usize) -> char {
 }
 
 
-pub(crate) struct CanvasInABox<'a>(pub &'a Canvas);
+pub struct CanvasInABox<'a>(pub &'a Canvas);
 impl<'a> fmt::Display for CanvasInABox<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "  ")?;